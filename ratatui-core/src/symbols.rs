@@ -94,6 +94,24 @@ pub mod bar {
         }
     }
 
+    impl Set {
+        /// Returns the symbol of a cell filled from the bottom up to the given number of eighths
+        /// of its height, or the full symbol for eight eighths or more.
+        pub const fn symbol_for_eighths(&self, eighths: u64) -> &'static str {
+            match eighths {
+                0 => self.empty,
+                1 => self.one_eighth,
+                2 => self.one_quarter,
+                3 => self.three_eighths,
+                4 => self.half,
+                5 => self.five_eighths,
+                6 => self.three_quarters,
+                7 => self.seven_eighths,
+                _ => self.full,
+            }
+        }
+    }
+
     pub const THREE_LEVELS: Set = Set {
         full: FULL,
         seven_eighths: FULL,
@@ -235,4 +253,12 @@ mod tests {
         assert_eq!("Braille".parse::<Marker>(), Ok(Marker::Braille));
         assert_eq!("".parse::<Marker>(), Err(ParseError::VariantNotFound));
     }
+
+    #[test]
+    fn bar_symbol_for_eighths() {
+        assert_eq!(bar::NINE_LEVELS.symbol_for_eighths(0), " ");
+        assert_eq!(bar::NINE_LEVELS.symbol_for_eighths(3), bar::THREE_EIGHTHS);
+        assert_eq!(bar::NINE_LEVELS.symbol_for_eighths(12), bar::FULL);
+        assert_eq!(bar::THREE_LEVELS.symbol_for_eighths(5), bar::HALF);
+    }
 }
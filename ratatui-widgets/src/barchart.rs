@@ -1,16 +1,23 @@
 //! The [`BarChart`] widget and its related types (e.g. [`Bar`], [`BarGroup`]).
 
+use std::ops::Range;
+
 use ratatui_core::{
     buffer::Buffer,
-    layout::{Direction, Rect},
+    layout::{Alignment, Direction, Rect},
     style::{Style, Styled},
     symbols::{self},
     text::Line,
     widgets::Widget,
 };
+use strum::{Display, EnumString};
+use unicode_width::UnicodeWidthStr;
 
 pub use self::{bar::Bar, bar_group::BarGroup};
-use crate::block::{Block, BlockExt};
+use crate::{
+    block::{Block, BlockExt},
+    signed::{set_depth_symbol, Scale},
+};
 
 mod bar;
 mod bar_group;
@@ -42,6 +49,11 @@ mod bar_group;
 /// The chart can have a [`Direction`] (by default the bars are [`Vertical`](Direction::Vertical)).
 /// This is set using [`BarChart::direction`].
 ///
+/// A [`Bar`] can be stacked from several [segments](Bar::segments), and its value can be negative
+/// (see [`Bar::signed_value`]), in which case the bar extends below (or left of) a zero axis. With
+/// [`StackMode::Percent`], every bar is scaled to the full length of the chart. See
+/// [`BarChart::stack_mode`].
+///
 /// Note: this is the only widget that doesn't implement `Widget` for `&T` because the current
 /// implementation modifies the internal state of self. This will be fixed in the future.
 ///
@@ -95,6 +107,21 @@ pub struct BarChart<'a> {
     max: Option<u64>,
     /// direction of the bars
     direction: Direction,
+    /// how the segments of stacked bars are scaled
+    stack_mode: StackMode,
+}
+
+/// Defines how the segments of stacked bars are scaled.
+///
+/// See [`BarChart::stack_mode`].
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum StackMode {
+    /// The length of each segment is proportional to its value
+    #[default]
+    Absolute,
+    /// Every bar takes the full length of the chart, and the length of each segment is
+    /// proportional to its share of the bar
+    Percent,
 }
 
 impl<'a> Default for BarChart<'a> {
@@ -112,6 +139,7 @@ impl<'a> Default for BarChart<'a> {
             bar_set: symbols::bar::NINE_LEVELS,
             style: Style::default(),
             direction: Direction::Vertical,
+            stack_mode: StackMode::Absolute,
         }
     }
 }
@@ -149,7 +177,8 @@ impl<'a> BarChart<'a> {
 
     /// Set the value necessary for a [`Bar`] to reach the maximum height.
     ///
-    /// If not set, the maximum value in the data is taken as reference.
+    /// If not set, the maximum value in the data is taken as reference. The maximum only applies
+    /// to positive values, and is ignored with [`StackMode::Percent`].
     ///
     /// # Examples
     ///
@@ -328,21 +357,108 @@ impl<'a> BarChart<'a> {
         self.direction = direction;
         self
     }
+
+    /// Set how the segments of stacked bars are scaled.
+    ///
+    /// [`StackMode::Absolute`] by default. With [`StackMode::Percent`], the segments of each bar
+    /// are scaled so that the bar takes the full length of the chart. The printed values of the
+    /// segments are not changed, use [`Bar::text_value`] to print a percentage instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{
+    ///     style::Color,
+    ///     widgets::{Bar, BarChart, BarGroup, StackMode},
+    /// };
+    ///
+    /// let bar = |a, b| {
+    ///     Bar::default().segments(&[
+    ///         Bar::default().value(a).label("a".into()).style(Color::Red),
+    ///         Bar::default().value(b).label("b".into()).style(Color::Blue),
+    ///     ])
+    /// };
+    /// BarChart::default()
+    ///     .data(BarGroup::default().bars(&[bar(1, 3), bar(5, 5)]))
+    ///     .stack_mode(StackMode::Percent);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn stack_mode(mut self, stack_mode: StackMode) -> Self {
+        self.stack_mode = stack_mode;
+        self
+    }
 }
 
 #[derive(Clone, Copy)]
 struct LabelInfo {
     group_label_visible: bool,
     bar_label_visible: bool,
+    legend_visible: bool,
     height: u16,
 }
 
+/// The number of ticks in a cell.
+const TICKS_PER_CELL: u64 = 8;
+
+/// The total a bar is scaled to with [`StackMode::Percent`].
+const PERCENT_SCALE: u128 = 10_000;
+
+/// The extent of a bar along the value axis, in ticks from the zero axis.
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+struct BarTicks {
+    /// The index of each part above (or right of) the zero axis, with the tick where it ends
+    positive: Vec<(usize, u64)>,
+    /// The index of each part below (or left of) the zero axis, with the tick where it ends
+    negative: Vec<(usize, u64)>,
+}
+
+impl BarTicks {
+    fn positive_end(&self) -> u64 {
+        self.positive.last().map_or(0, |&(_, end)| end)
+    }
+
+    fn negative_end(&self) -> u64 {
+        self.negative.last().map_or(0, |&(_, end)| end)
+    }
+
+    /// Returns the index of the part that covers most of the given cell, counted from the axis.
+    fn owner(parts: &[(usize, u64)], cell: u16) -> Option<usize> {
+        let cell_start = u64::from(cell) * TICKS_PER_CELL;
+        let cell_end = cell_start + TICKS_PER_CELL;
+        let mut start = 0;
+        let mut owner = None;
+        let mut covered = 0;
+        for &(index, end) in parts {
+            let overlap = end.min(cell_end).saturating_sub(start.max(cell_start));
+            if overlap > covered {
+                covered = overlap;
+                owner = Some(index);
+            }
+            start = end;
+        }
+        owner
+    }
+
+    /// Returns the cells each part covers most of, counted from the axis.
+    fn owned_cells(parts: &[(usize, u64)]) -> impl Iterator<Item = (usize, Range<u16>)> + '_ {
+        let mut start = 0;
+        parts.iter().map(move |&(index, end)| {
+            let cells = (start / TICKS_PER_CELL) as u16..end.div_ceil(TICKS_PER_CELL) as u16;
+            start = end;
+            let mut owned = cells.filter(|&cell| Self::owner(parts, cell) == Some(index));
+            let owned = owned.next().map_or(0..0, |first| {
+                let last = owned.next_back().unwrap_or(first);
+                first..last + 1
+            });
+            (index, owned)
+        })
+    }
+}
+
 impl BarChart<'_> {
-    /// Returns the visible bars length in ticks. A cell contains 8 ticks.
+    /// Returns the visible bars extent in ticks. A cell contains 8 ticks.
     /// `available_space` used to calculate how many bars can fit in the space
-    /// `bar_max_length` is the maximal length a bar can take.
-    fn group_ticks(&self, available_space: u16, bar_max_length: u16) -> Vec<Vec<u64>> {
-        let max: u64 = self.maximum_data_value();
+    fn group_ticks(&self, available_space: u16, scale: &Scale) -> Vec<Vec<BarTicks>> {
         self.data
             .iter()
             .scan(available_space, |space, group| {
@@ -370,26 +486,92 @@ impl BarChart<'_> {
                         .bars
                         .iter()
                         .take(n as usize)
-                        .map(|bar| bar.value * u64::from(bar_max_length) * 8 / max)
+                        .map(|bar| self.bar_ticks(bar, scale))
                         .collect()
                 })
             })
             .collect()
     }
 
+    /// Stacks the parts of a bar on each side of the zero axis.
+    fn bar_ticks(&self, bar: &Bar, scale: &Scale) -> BarTicks {
+        let (positive_total, negative_total) = bar.extents();
+        let mut ticks = BarTicks::default();
+        let (mut positive, mut negative) = (0, 0);
+        for (index, part) in bar.parts().iter().enumerate() {
+            let magnitude = u128::from(part.value.magnitude);
+            if magnitude == 0 {
+                continue;
+            }
+            let value = match self.stack_mode {
+                StackMode::Absolute => magnitude,
+                StackMode::Percent => magnitude * PERCENT_SCALE / (positive_total + negative_total),
+            };
+            if part.value.negative {
+                negative += value;
+                ticks.negative.push((index, scale.levels(negative, true)));
+            } else {
+                positive += value;
+                ticks.positive.push((index, scale.levels(positive, false)));
+            }
+        }
+        ticks
+    }
+
+    /// Splits the given length around the zero axis in proportion to the longest positive and
+    /// negative bars.
+    fn scale(&self, length: u16) -> Scale {
+        let (positive_max, negative_max) = self
+            .data
+            .iter()
+            .flat_map(|group| &group.bars)
+            .map(|bar| {
+                let (positive, negative) = bar.extents();
+                let total = positive + negative;
+                match self.stack_mode {
+                    StackMode::Absolute => (positive, negative),
+                    StackMode::Percent if total == 0 => (0, 0),
+                    StackMode::Percent => (
+                        positive * PERCENT_SCALE / total,
+                        negative * PERCENT_SCALE / total,
+                    ),
+                }
+            })
+            .fold(
+                (0, 0),
+                |(positive_max, negative_max), (positive, negative)| {
+                    (positive_max.max(positive), negative_max.max(negative))
+                },
+            );
+        // a maximum of zero draws the bars at their full length
+        let positive_max = match (self.stack_mode, self.max) {
+            (StackMode::Absolute, Some(max)) => u128::from(max).max(1),
+            _ => positive_max,
+        };
+        Scale::new(length, positive_max, negative_max, TICKS_PER_CELL as u16)
+    }
+
+    /// Returns the style of a cell of the bar, which is the style of the part covering most of it.
+    fn cell_style(&self, bar: &Bar, owner: Option<usize>) -> Style {
+        let style = self.bar_style.patch(bar.style);
+        owner.map_or(style, |index| style.patch(bar.parts()[index].style))
+    }
+
     /// Get label information.
     ///
     /// height is the number of lines, which depends on whether we need to print the bar
-    /// labels and/or the group labels.
+    /// labels, the group labels and/or the legends.
     /// - If there are no labels, height is 0.
     /// - If there are only bar labels, height is 1.
     /// - If there are only group labels, height is 1.
     /// - If there are both bar and group labels, height is 2.
+    /// - If there is a legend and enough space, it adds 1 to the height.
     fn label_info(&self, available_height: u16) -> LabelInfo {
         if available_height == 0 {
             return LabelInfo {
                 group_label_visible: false,
                 bar_label_visible: false,
+                legend_visible: false,
                 height: 0,
             };
         }
@@ -403,16 +585,21 @@ impl BarChart<'_> {
             return LabelInfo {
                 group_label_visible: false,
                 bar_label_visible: true,
+                legend_visible: false,
                 height: 1,
             };
         }
 
         let group_label_visible = self.data.iter().any(|e| e.label.is_some());
+        // convert true to 1 and false to 0 and add the two values
+        let height = u16::from(group_label_visible) + u16::from(bar_label_visible);
+        let legend_visible =
+            available_height > height && self.data.iter().any(BarGroup::has_legend);
         LabelInfo {
             group_label_visible,
             bar_label_visible,
-            // convert true to 1 and false to 0 and add the two values
-            height: u16::from(group_label_visible) + u16::from(bar_label_visible),
+            legend_visible,
+            height: height + u16::from(legend_visible),
         }
     }
 
@@ -437,46 +624,57 @@ impl BarChart<'_> {
             }
         };
 
-        let group_ticks = self.group_ticks(bars_area.height, bars_area.width);
+        let scale = self.scale(bars_area.width);
+        let axis_x = bars_area.left() + scale.negative_length;
+        let group_ticks = self.group_ticks(bars_area.height, &scale);
 
         // print all visible bars, label and values
         let mut bar_y = bars_area.top();
         for (ticks_vec, group) in group_ticks.into_iter().zip(self.data.iter()) {
             for (ticks, bar) in ticks_vec.into_iter().zip(group.bars.iter()) {
-                let bar_length = (ticks / 8) as u16;
-                let bar_style = self.bar_style.patch(bar.style);
+                let positive_length = (ticks.positive_end() / TICKS_PER_CELL) as u16;
+                let negative_length = (ticks.negative_end() / TICKS_PER_CELL) as u16;
 
                 for y in 0..self.bar_width {
-                    let bar_y = bar_y + y;
-                    for x in 0..bars_area.width {
-                        let symbol = if x < bar_length {
-                            self.bar_set.full
-                        } else {
-                            self.bar_set.empty
-                        };
-                        buf[(bars_area.left() + x, bar_y)]
-                            .set_symbol(symbol)
-                            .set_style(bar_style);
-                    }
+                    self.render_horizontal_bar(buf, bar, &ticks, axis_x, bar_y + y, &scale);
                 }
 
-                let bar_value_area = Rect {
-                    y: bar_y + (self.bar_width >> 1),
-                    ..bars_area
-                };
+                let bar_value_y = bar_y + (self.bar_width >> 1);
 
                 // label
                 if let Some(label) = &bar.label {
-                    buf.set_line(label_x, bar_value_area.top(), label, label_size);
+                    buf.set_line(label_x, bar_value_y, label, label_size);
                 }
 
-                bar.render_value_with_different_styles(
-                    buf,
-                    bar_value_area,
-                    bar_length as usize,
-                    self.value_style,
-                    self.bar_style,
-                );
+                if bar.segments.is_empty() {
+                    let (x, bar_length) = if bar.value.negative {
+                        (axis_x - negative_length, negative_length)
+                    } else {
+                        (axis_x, positive_length)
+                    };
+                    let bar_value_area = Rect {
+                        x,
+                        y: bar_value_y,
+                        width: bars_area.right() - x,
+                        ..bars_area
+                    };
+                    bar.render_value_with_different_styles(
+                        buf,
+                        bar_value_area,
+                        bar_length as usize,
+                        self.value_style,
+                        self.bar_style,
+                    );
+                } else {
+                    self.render_horizontal_segment_values(
+                        buf,
+                        bar,
+                        &ticks,
+                        axis_x,
+                        bar_value_y,
+                        bars_area,
+                    );
+                }
 
                 bar_y += self.bar_gap + self.bar_width;
             }
@@ -490,11 +688,107 @@ impl BarChart<'_> {
                     ..bars_area
                 };
                 group.render_label(buf, label_rect, self.label_style);
+                group.render_legend(
+                    buf,
+                    Rect {
+                        height: 1,
+                        ..label_rect
+                    },
+                    Alignment::Right,
+                    self.bar_set.full,
+                    self.bar_style,
+                    self.label_style,
+                );
                 bar_y += self.group_gap;
             }
         }
     }
 
+    /// Fills a line of a horizontal bar with full cells on each side of the zero axis.
+    fn render_horizontal_bar(
+        &self,
+        buf: &mut Buffer,
+        bar: &Bar,
+        ticks: &BarTicks,
+        axis_x: u16,
+        y: u16,
+        scale: &Scale,
+    ) {
+        let positive_length = (ticks.positive_end() / TICKS_PER_CELL) as u16;
+        let negative_length = (ticks.negative_end() / TICKS_PER_CELL) as u16;
+        for x in 0..scale.positive_length {
+            let (symbol, owner) = if x < positive_length {
+                (self.bar_set.full, BarTicks::owner(&ticks.positive, x))
+            } else {
+                (self.bar_set.empty, None)
+            };
+            buf[(axis_x + x, y)]
+                .set_symbol(symbol)
+                .set_style(self.cell_style(bar, owner));
+        }
+        for x in 0..scale.negative_length {
+            let (symbol, owner) = if x < negative_length {
+                (self.bar_set.full, BarTicks::owner(&ticks.negative, x))
+            } else {
+                (self.bar_set.empty, None)
+            };
+            buf[(axis_x - x - 1, y)]
+                .set_symbol(symbol)
+                .set_style(self.cell_style(bar, owner));
+        }
+    }
+
+    /// Prints the value of each segment of a horizontal bar in the cells it covers, if it fits,
+    /// and the total of the bar past its end.
+    fn render_horizontal_segment_values(
+        &self,
+        buf: &mut Buffer,
+        bar: &Bar,
+        ticks: &BarTicks,
+        axis_x: u16,
+        y: u16,
+        area: Rect,
+    ) {
+        let positive_length = (ticks.positive_end() / TICKS_PER_CELL) as u16;
+        let negative_length = (ticks.negative_end() / TICKS_PER_CELL) as u16;
+        for (parts, length, negative) in [
+            (&ticks.positive, positive_length, false),
+            (&ticks.negative, negative_length, true),
+        ] {
+            for (index, cells) in BarTicks::owned_cells(parts) {
+                let segment = &bar.parts()[index];
+                let text = segment.value_label();
+                let cells = cells.start..cells.end.min(length);
+                if cells.is_empty() || text.width() > cells.len() {
+                    continue;
+                }
+                let x = if negative {
+                    axis_x - cells.end
+                } else {
+                    axis_x + cells.start
+                };
+                let style = self.value_style.patch(segment.value_style);
+                buf.set_string(x, y, text, style);
+            }
+        }
+
+        if bar.total() == 0 {
+            return;
+        }
+        let text = bar.value_label();
+        let width = text.width() as u16;
+        let x = if ticks.positive_end() > 0 || ticks.negative_end() == 0 {
+            Some(axis_x + positive_length).filter(|x| x + width <= area.right())
+        } else {
+            (axis_x - negative_length)
+                .checked_sub(width)
+                .filter(|&x| x >= area.left())
+        };
+        if let Some(x) = x {
+            buf.set_string(x, y, text, self.value_style.patch(bar.value_style));
+        }
+    }
+
     fn render_vertical(&self, buf: &mut Buffer, area: Rect) {
         let label_info = self.label_info(area.height - 1);
 
@@ -503,39 +797,45 @@ impl BarChart<'_> {
             ..area
         };
 
-        let group_ticks = self.group_ticks(bars_area.width, bars_area.height);
-        self.render_vertical_bars(bars_area, buf, &group_ticks);
-        self.render_labels_and_values(area, buf, label_info, &group_ticks);
+        let scale = self.scale(bars_area.height);
+        let group_ticks = self.group_ticks(bars_area.width, &scale);
+        self.render_vertical_bars(bars_area, buf, &scale, &group_ticks);
+        self.render_labels_and_values(area, buf, label_info, &scale, &group_ticks);
     }
 
-    fn render_vertical_bars(&self, area: Rect, buf: &mut Buffer, group_ticks: &[Vec<u64>]) {
+    fn render_vertical_bars(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        scale: &Scale,
+        group_ticks: &[Vec<BarTicks>],
+    ) {
         // print all visible bars (without labels and values)
+        let axis_y = area.top() + scale.positive_length;
         let mut bar_x = area.left();
         for (ticks_vec, group) in group_ticks.iter().zip(&self.data) {
             for (ticks, bar) in ticks_vec.iter().zip(&group.bars) {
-                let mut ticks = *ticks;
-                for j in (0..area.height).rev() {
-                    let symbol = match ticks {
-                        0 => self.bar_set.empty,
-                        1 => self.bar_set.one_eighth,
-                        2 => self.bar_set.one_quarter,
-                        3 => self.bar_set.three_eighths,
-                        4 => self.bar_set.half,
-                        5 => self.bar_set.five_eighths,
-                        6 => self.bar_set.three_quarters,
-                        7 => self.bar_set.seven_eighths,
-                        _ => self.bar_set.full,
-                    };
-
-                    let bar_style = self.bar_style.patch(bar.style);
-
+                for row in 0..scale.positive_length {
+                    let remaining = ticks
+                        .positive_end()
+                        .saturating_sub(u64::from(row) * TICKS_PER_CELL);
+                    let symbol = self.bar_set.symbol_for_eighths(remaining);
+                    let style = self.cell_style(bar, BarTicks::owner(&ticks.positive, row));
                     for x in 0..self.bar_width {
-                        buf[(bar_x + x, area.top() + j)]
+                        buf[(bar_x + x, axis_y - row - 1)]
                             .set_symbol(symbol)
-                            .set_style(bar_style);
+                            .set_style(style);
+                    }
+                }
+                for row in 0..scale.negative_length {
+                    let remaining = ticks
+                        .negative_end()
+                        .saturating_sub(u64::from(row) * TICKS_PER_CELL);
+                    let style = self.cell_style(bar, BarTicks::owner(&ticks.negative, row));
+                    for x in 0..self.bar_width {
+                        let cell = &mut buf[(bar_x + x, axis_y + row)];
+                        set_depth_symbol(cell, &self.bar_set, remaining).set_style(style);
                     }
-
-                    ticks = ticks.saturating_sub(8);
                 }
                 bar_x += self.bar_gap + self.bar_width;
             }
@@ -543,59 +843,130 @@ impl BarChart<'_> {
         }
     }
 
-    /// get the maximum data value. the returned value is always greater equal 1
-    fn maximum_data_value(&self) -> u64 {
-        self.max
-            .unwrap_or_else(|| {
-                self.data
-                    .iter()
-                    .map(|group| group.max().unwrap_or_default())
-                    .max()
-                    .unwrap_or_default()
-            })
-            .max(1)
-    }
-
     fn render_labels_and_values(
         &self,
         area: Rect,
         buf: &mut Buffer,
         label_info: LabelInfo,
-        group_ticks: &[Vec<u64>],
+        scale: &Scale,
+        group_ticks: &[Vec<BarTicks>],
     ) {
         // print labels and values in one go
         let mut bar_x = area.left();
-        let bar_y = area.bottom() - label_info.height - 1;
+        let axis_y = area.top() + scale.positive_length;
+        let bar_label_y = area.bottom() - label_info.height;
+        let group_label_y = area.bottom() - 1 - u16::from(label_info.legend_visible);
         for (group, ticks_vec) in self.data.iter().zip(group_ticks) {
             if group.bars.is_empty() {
                 continue;
             }
+            let label_max_width =
+                ticks_vec.len() as u16 * (self.bar_width + self.bar_gap) - self.bar_gap;
             // print group labels under the bars or the previous labels
             if label_info.group_label_visible {
-                let label_max_width =
-                    ticks_vec.len() as u16 * (self.bar_width + self.bar_gap) - self.bar_gap;
                 let group_area = Rect {
                     x: bar_x,
-                    y: area.bottom() - 1,
+                    y: group_label_y,
                     width: label_max_width,
                     height: 1,
                 };
                 group.render_label(buf, group_area, self.label_style);
             }
+            // print the legend on the last line
+            if label_info.legend_visible {
+                let legend_area = Rect {
+                    x: bar_x,
+                    y: area.bottom() - 1,
+                    width: label_max_width,
+                    height: 1,
+                };
+                group.render_legend(
+                    buf,
+                    legend_area,
+                    Alignment::Left,
+                    self.bar_set.full,
+                    self.bar_style,
+                    self.label_style,
+                );
+            }
 
             // print the bar values and numbers
             for (bar, ticks) in group.bars.iter().zip(ticks_vec) {
                 if label_info.bar_label_visible {
-                    bar.render_label(buf, self.bar_width, bar_x, bar_y + 1, self.label_style);
+                    bar.render_label(buf, self.bar_width, bar_x, bar_label_y, self.label_style);
                 }
 
-                bar.render_value(buf, self.bar_width, bar_x, bar_y, self.value_style, *ticks);
+                self.render_vertical_values(buf, bar, ticks, bar_x, axis_y, scale);
 
                 bar_x += self.bar_gap + self.bar_width;
             }
             bar_x += self.group_gap;
         }
     }
+
+    /// Prints the value of a vertical bar in the cell next to the zero axis, or for a stacked bar,
+    /// the value of each segment in the first cell it covers and the total past the end of the bar.
+    fn render_vertical_values(
+        &self,
+        buf: &mut Buffer,
+        bar: &Bar,
+        ticks: &BarTicks,
+        x: u16,
+        axis_y: u16,
+        scale: &Scale,
+    ) {
+        let y = |negative: bool, row: u16| {
+            if negative {
+                axis_y + row
+            } else {
+                axis_y - row - 1
+            }
+        };
+        if bar.segments.is_empty() {
+            let (length, ticks) = if bar.value.negative {
+                (scale.negative_length, ticks.negative_end())
+            } else {
+                (scale.positive_length, ticks.positive_end())
+            };
+            if length > 0 {
+                let y = y(bar.value.negative, 0);
+                bar.render_value(buf, self.bar_width, x, y, self.value_style, ticks);
+            }
+            return;
+        }
+
+        for (parts, negative) in [(&ticks.positive, false), (&ticks.negative, true)] {
+            for (index, cells) in BarTicks::owned_cells(parts) {
+                if !cells.is_empty() {
+                    bar.parts()[index].render_value(
+                        buf,
+                        self.bar_width,
+                        x,
+                        y(negative, cells.start),
+                        self.value_style,
+                        TICKS_PER_CELL,
+                    );
+                }
+            }
+        }
+
+        let (negative, end, length) = if ticks.positive_end() > 0 || ticks.negative_end() == 0 {
+            (false, ticks.positive_end(), scale.positive_length)
+        } else {
+            (true, ticks.negative_end(), scale.negative_length)
+        };
+        let row = end.div_ceil(TICKS_PER_CELL) as u16;
+        if row < length {
+            bar.render_value(
+                buf,
+                self.bar_width,
+                x,
+                y(negative, row),
+                self.value_style,
+                TICKS_PER_CELL,
+            );
+        }
+    }
 }
 
 impl Widget for BarChart<'_> {
@@ -1354,4 +1725,174 @@ mod tests {
         ]);
         assert_eq!(buffer, expected);
     }
+
+    #[test]
+    fn stack_mode_to_string() {
+        assert_eq!(StackMode::Absolute.to_string(), "Absolute");
+        assert_eq!(StackMode::Percent.to_string(), "Percent");
+    }
+
+    #[test]
+    fn stack_mode_from_str() {
+        assert_eq!("Absolute".parse::<StackMode>(), Ok(StackMode::Absolute));
+        assert_eq!("Percent".parse::<StackMode>(), Ok(StackMode::Percent));
+        assert_eq!(
+            "".parse::<StackMode>(),
+            Err(strum::ParseError::VariantNotFound)
+        );
+    }
+
+    fn stacked_bar<'a>(a: u64, b: u64) -> Bar<'a> {
+        Bar::default().segments(&[
+            Bar::default().value(a).label("a".into()).style(Color::Red),
+            Bar::default().value(b).label("b".into()).style(Color::Blue),
+        ])
+    }
+
+    #[test]
+    fn stacked_bars() {
+        let chart = BarChart::default()
+            .data(BarGroup::default().bars(&[stacked_bar(2, 2), stacked_bar(1, 3)]))
+            .bar_width(3)
+            .max(5);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 7, 6));
+        chart.render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines([
+            " 4   4 ",
+            "███ ███",
+            "█2█ ███",
+            "███ █3█",
+            "█2█ █1█",
+            "█ a █ b",
+        ]);
+        expected.set_style(Rect::new(0, 1, 3, 2), Color::Blue);
+        expected.set_style(Rect::new(0, 3, 3, 2), Color::Red);
+        expected.set_style(Rect::new(4, 1, 3, 3), Color::Blue);
+        expected.set_style(Rect::new(4, 4, 3, 1), Color::Red);
+        expected.set_style(Rect::new(0, 5, 1, 1), Color::Red);
+        expected.set_style(Rect::new(4, 5, 1, 1), Color::Blue);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn stacked_bars_total_uses_text_value() {
+        let chart = BarChart::default()
+            .data(BarGroup::default().bars(&[stacked_bar(1, 1).text_value("T".to_string())]))
+            .max(3);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 4));
+        chart.render(buffer.area, &mut buffer);
+        assert_eq!(buffer.content[0].symbol(), "T");
+    }
+
+    #[test]
+    fn percent_stacked_bars() {
+        let chart = BarChart::default()
+            .data(BarGroup::default().bars(&[stacked_bar(1, 3), stacked_bar(5, 5)]))
+            .stack_mode(StackMode::Percent)
+            .bar_width(2);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 5));
+        chart.render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["██ ██", "██ 5█", "3█ ██", "1█ 5█", "█ a  "]);
+        expected.set_style(Rect::new(0, 0, 2, 3), Color::Blue);
+        expected.set_style(Rect::new(0, 3, 2, 1), Color::Red);
+        expected.set_style(Rect::new(3, 0, 2, 2), Color::Blue);
+        expected.set_style(Rect::new(3, 2, 2, 2), Color::Red);
+        expected.set_style(Rect::new(0, 4, 1, 1), Color::Red);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn negative_vertical_bars() {
+        let chart = BarChart::default()
+            .data(BarGroup::default().bars(&[
+                Bar::default().value(4),
+                Bar::default().signed_value(-4),
+                Bar::default().signed_value(-2),
+                Bar::default().signed_value(-1),
+            ]))
+            .bar_width(2);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 11, 4));
+        chart.render(buffer.area, &mut buffer);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "██         ",
+            "4█         ",
+            "   -4 -2 ▀▀",
+            "   ██      ",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn negative_horizontal_bars() {
+        let chart = BarChart::default()
+            .data(&[("a", 4), ("b", 2)])
+            .data(BarGroup::default().bars(&[Bar::default().label("c".into()).signed_value(-4)]))
+            .direction(Direction::Horizontal)
+            .bar_gap(0);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 3));
+        chart.render(buffer.area, &mut buffer);
+        let expected = Buffer::with_lines(["a     4███", "b     2█  ", "c -4██    "]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn mixed_stacked_bar() {
+        let bar = Bar::default().segments(&[
+            Bar::default().value(4),
+            Bar::default().signed_value(-2),
+            Bar::default().value(4),
+        ]);
+        let chart = BarChart::default()
+            .data(BarGroup::default().bars(&[bar]))
+            .bar_width(2)
+            .max(10);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 2, 6));
+        chart.render(buffer.area, &mut buffer);
+        let expected = Buffer::with_lines(["6 ", "██", "4█", "██", "4█", "-2"]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn horizontal_stacked_bars_with_legend() {
+        let chart = BarChart::default()
+            .data(
+                BarGroup::default()
+                    .label("G".into())
+                    .bars(&[stacked_bar(2, 3), stacked_bar(4, 1)]),
+            )
+            .direction(Direction::Horizontal)
+            .group_gap(1)
+            .bar_gap(0)
+            .max(6);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 3));
+        chart.render(buffer.area, &mut buffer);
+        #[rustfmt::skip]
+        let mut expected = Buffer::with_lines([
+            "2███3█████5 ",
+            "4███████1█5 ",
+            "G    █ a █ b",
+        ]);
+        expected.set_style(Rect::new(0, 0, 4, 1), Color::Red);
+        expected.set_style(Rect::new(4, 0, 6, 1), Color::Blue);
+        expected.set_style(Rect::new(0, 1, 8, 1), Color::Red);
+        expected.set_style(Rect::new(8, 1, 2, 1), Color::Blue);
+        expected.set_style(Rect::new(5, 2, 1, 1), Color::Red);
+        expected.set_style(Rect::new(9, 2, 1, 1), Color::Blue);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn legend_is_truncated_to_the_group() {
+        let chart = BarChart::default()
+            .data(BarGroup::default().bars(&[stacked_bar(1, 1)]))
+            .bar_width(4);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 3));
+        chart.render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["█1██ ", "█1██ ", "█ a  "]);
+        expected.set_style(Rect::new(0, 0, 4, 1), Color::Blue);
+        expected.set_style(Rect::new(0, 1, 4, 1), Color::Red);
+        expected.set_style(Rect::new(0, 2, 1, 1), Color::Red);
+        assert_eq!(buffer, expected);
+    }
 }
@@ -1,6 +1,8 @@
 use ratatui_core::{buffer::Buffer, layout::Rect, style::Style, text::Line, widgets::Widget};
 use unicode_width::UnicodeWidthStr;
 
+use crate::signed::SignedValue;
+
 /// A bar to be shown by the [`BarChart`](crate::barchart::BarChart) widget.
 ///
 /// Here is an explanation of a `Bar`'s components.
@@ -11,6 +13,9 @@ use unicode_width::UnicodeWidthStr;
 /// ```
 /// Note that every element can be styled individually.
 ///
+/// A bar can also be stacked from several [segments](Bar::segments), each of which is itself a
+/// `Bar` with its own value and style.
+///
 /// # Example
 ///
 /// The following example creates a bar with the label "Bar 1", a value "10",
@@ -31,7 +36,7 @@ use unicode_width::UnicodeWidthStr;
 #[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
 pub struct Bar<'a> {
     /// Value to display on the bar (computed when the data is passed to the widget)
-    pub(super) value: u64,
+    pub(super) value: SignedValue,
     /// optional label to be printed under the bar
     pub(super) label: Option<Line<'a>>,
     /// style for the bar
@@ -40,6 +45,8 @@ pub struct Bar<'a> {
     pub(super) value_style: Style,
     /// optional `text_value` to be shown on the bar instead of the actual value
     pub(super) text_value: Option<String>,
+    /// segments stacked to make up the bar
+    pub(super) segments: Vec<Self>,
 }
 
 impl<'a> Bar<'a> {
@@ -53,7 +60,24 @@ impl<'a> Bar<'a> {
     /// [`Bar::text_value`] to set the displayed value.
     #[must_use = "method moves the value of self and returns the modified value"]
     pub const fn value(mut self, value: u64) -> Self {
-        self.value = value;
+        self.value = SignedValue::positive(value);
+        self
+    }
+
+    /// Set a value of this bar that may be negative.
+    ///
+    /// Negative values extend below the zero axis of [`Vertical`] bars and left of the zero axis of
+    /// [`Horizontal`] bars.
+    ///
+    /// # See also
+    ///
+    /// [`Bar::value`] to set a value that is never negative.
+    ///
+    /// [`Vertical`]: ratatui_core::layout::Direction::Vertical
+    /// [`Horizontal`]: ratatui_core::layout::Direction::Horizontal
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn signed_value(mut self, value: i64) -> Self {
+        self.value = SignedValue::new(value);
         self
     }
 
@@ -114,6 +138,72 @@ impl<'a> Bar<'a> {
         self
     }
 
+    /// Set the segments stacked to make up this bar.
+    ///
+    /// Each segment is a [`Bar`] of its own. The segments with a positive value are stacked away
+    /// from the zero axis in order, and so are the segments with a negative value on the other side
+    /// of the axis. The value of the bar is replaced by the sum of the values of its segments.
+    ///
+    /// - the style of a segment is patched over the style of the bar
+    /// - the value (or [`text_value`](Bar::text_value)) of a segment is printed inside the segment
+    ///   and the total of the bar is printed past its end
+    /// - the label of a segment is shown in the legend of the [`BarGroup`] the bar belongs to
+    ///
+    /// Segments of segments are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{style::Color, widgets::Bar};
+    ///
+    /// Bar::default().label("Q1".into()).segments(&[
+    ///     Bar::default().value(10).label("North".into()).style(Color::Red),
+    ///     Bar::default().value(5).label("South".into()).style(Color::Blue),
+    /// ]);
+    /// ```
+    ///
+    /// [`BarGroup`]: crate::barchart::BarGroup
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn segments(mut self, segments: &[Self]) -> Self {
+        self.segments = segments.to_vec();
+        self
+    }
+
+    /// The parts stacked to make up the bar: its segments, or the bar itself if there are none.
+    pub(super) fn parts(&self) -> &[Self] {
+        if self.segments.is_empty() {
+            std::slice::from_ref(self)
+        } else {
+            &self.segments
+        }
+    }
+
+    /// The sum of the values of the parts of the bar.
+    pub(super) fn total(&self) -> i128 {
+        self.parts().iter().map(|part| part.value.get()).sum()
+    }
+
+    /// The sums of the magnitudes of the positive and of the negative parts of the bar.
+    pub(super) fn extents(&self) -> (u128, u128) {
+        self.parts()
+            .iter()
+            .fold((0, 0), |(positive, negative), part| {
+                let magnitude = u128::from(part.value.magnitude);
+                if part.value.negative {
+                    (positive, negative + magnitude)
+                } else {
+                    (positive + magnitude, negative)
+                }
+            })
+    }
+
+    /// The text printed for the value of the bar.
+    pub(super) fn value_label(&self) -> String {
+        self.text_value
+            .clone()
+            .unwrap_or_else(|| self.total().to_string())
+    }
+
     /// Render the value of the bar.
     ///
     /// [`text_value`](Bar::text_value) is used if set, otherwise the value is converted to string.
@@ -128,14 +218,13 @@ impl<'a> Bar<'a> {
         default_value_style: Style,
         bar_style: Style,
     ) {
-        let value = self.value.to_string();
-        let text = self.text_value.as_ref().unwrap_or(&value);
+        let text = self.value_label();
 
         if !text.is_empty() {
             let style = default_value_style.patch(self.value_style);
             // Since the value may be longer than the bar itself, we need to use 2 different styles
             // while rendering. Render the first part with the default value style
-            buf.set_stringn(area.x, area.y, text, bar_length, style);
+            buf.set_stringn(area.x, area.y, &text, bar_length, style);
             // render the second part with the bar_style
             if text.len() > bar_length {
                 let (first, second) = text.split_at(bar_length);
@@ -161,10 +250,9 @@ impl<'a> Bar<'a> {
         default_value_style: Style,
         ticks: u64,
     ) {
-        if self.value != 0 {
+        if self.total() != 0 {
             const TICKS_PER_LINE: u64 = 8;
-            let value = self.value.to_string();
-            let value_label = self.text_value.as_ref().unwrap_or(&value);
+            let value_label = &self.value_label();
             let width = value_label.width() as u16;
             // if we have enough space or the ticks are greater equal than 1 cell (8)
             // then print the value
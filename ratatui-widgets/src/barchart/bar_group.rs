@@ -10,6 +10,9 @@ use crate::barchart::Bar;
 
 /// A group of bars to be shown by the Barchart.
 ///
+/// When the bars of a group are stacked from [segments](Bar::segments), the labels of the segments
+/// are shown in a legend of the group.
+///
 /// # Examples
 ///
 /// ```
@@ -42,9 +45,67 @@ impl<'a> BarGroup<'a> {
         self
     }
 
-    /// The maximum bar value of this group
-    pub(super) fn max(&self) -> Option<u64> {
-        self.bars.iter().max_by_key(|v| v.value).map(|v| v.value)
+    /// Whether any segment of the bars of this group has a label to show in the legend
+    pub(super) fn has_legend(&self) -> bool {
+        self.bars
+            .iter()
+            .any(|bar| bar.segments.iter().any(|segment| segment.label.is_some()))
+    }
+
+    /// Render the legend of the group: the first occurrence of each segment label, preceded by
+    /// the `symbol` in the style of the segment.
+    ///
+    /// Entries that do not fit in the area are left out.
+    pub(super) fn render_legend(
+        &self,
+        buf: &mut Buffer,
+        area: Rect,
+        alignment: Alignment,
+        symbol: &str,
+        default_bar_style: Style,
+        default_label_style: Style,
+    ) {
+        let mut entries: Vec<(&Line, Style)> = vec![];
+        for bar in &self.bars {
+            for segment in &bar.segments {
+                if let Some(label) = &segment.label {
+                    if !entries.iter().any(|(entry, _)| *entry == label) {
+                        let style = default_bar_style.patch(bar.style).patch(segment.style);
+                        entries.push((label, style));
+                    }
+                }
+            }
+        }
+
+        // an entry is the symbol, a space and the label, and entries are separated by a space
+        let mut width = 0;
+        let entries: Vec<_> = entries
+            .into_iter()
+            .take_while(|(label, _)| {
+                let entry_width = u16::from(width != 0) + 2 + label.width() as u16;
+                if width + entry_width > area.width {
+                    return false;
+                }
+                width += entry_width;
+                true
+            })
+            .collect();
+        let mut x = match alignment {
+            Alignment::Center => area.x + (area.width - width) / 2,
+            Alignment::Right => area.x + area.width - width,
+            Alignment::Left => area.x,
+        };
+        for (label, style) in entries {
+            buf.set_string(x, area.y, symbol, style);
+            let label_area = Rect {
+                x: x + 2,
+                width: label.width() as u16,
+                ..area
+            };
+            buf.set_style(label_area, default_label_style);
+            label.render(label_area, buf);
+            x = label_area.right() + 1;
+        }
     }
 
     pub(super) fn render_label(&self, buf: &mut Buffer, area: Rect, default_label_style: Style) {
@@ -0,0 +1,458 @@
+//! The [`BoxPlot`] widget and its related types (e.g. [`Distribution`]).
+
+use ratatui_core::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Modifier, Style, Styled},
+    symbols,
+    text::Line,
+    widgets::Widget,
+};
+
+use crate::{
+    block::{Block, BlockExt},
+    chart::{
+        scale::{data_bounds, ResolvedAxis},
+        Axis,
+    },
+    histogram::quantile,
+};
+
+/// A widget summarizing distributions of samples, each one on a row
+///
+/// Each [`Distribution`] is displayed as a box spanning its first and third quartiles, split by its
+/// median. The whiskers on both sides of the box extend to the furthest samples within 1.5 times
+/// the interquartile range of the box, and the samples further away are displayed as outliers.
+///
+/// The values of the samples are labelled with the [`Axis`] on the bottom row, the same way as the
+/// X axis of a [`Chart`]. The labels of the distributions are displayed on the left.
+///
+/// Here is a possible `BoxPlot` output.
+/// ```plain
+/// read  ├──███┃██────┤      •
+/// write    ├─█┃███──┤
+///       0       50       100
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use ratatui::{
+///     style::{Color, Style},
+///     widgets::{Axis, BoxPlot, Distribution},
+/// };
+///
+/// let reads = [12.0, 15.0, 18.0, 22.0, 25.0, 31.0, 95.0];
+/// let writes = [20.0, 24.0, 26.0, 30.0, 41.0];
+/// BoxPlot::new([
+///     Distribution::new(&reads).label("read").style(Color::Cyan),
+///     Distribution::new(&writes).label("write").style(Color::Yellow),
+/// ])
+/// .axis(Axis::default().bounds([0.0, 100.0]).ticks(3));
+/// ```
+///
+/// [`Chart`]: crate::chart::Chart
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoxPlot<'a> {
+    /// Block to wrap the widget in
+    block: Option<Block<'a>>,
+    /// The distributions to summarize
+    distributions: Vec<Distribution<'a>>,
+    /// The axis of the values of the samples
+    axis: Axis<'a>,
+    /// Style of the boxes
+    box_style: Style,
+    /// Style of the medians
+    median_style: Style,
+    /// Style for the widget
+    style: Style,
+}
+
+/// The samples of a distribution displayed in a [`BoxPlot`]
+///
+/// The samples which are not finite, e.g. `NaN`, are ignored.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Distribution<'a> {
+    /// The samples of the distribution
+    samples: &'a [f64],
+    /// The label displayed on the left of the distribution
+    label: Option<Line<'a>>,
+    /// Style of the distribution
+    style: Style,
+}
+
+/// The statistics of a distribution displayed in a box plot
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Summary {
+    /// The lowest sample within the lower fence
+    pub(crate) low: f64,
+    /// The first quartile
+    pub(crate) q1: f64,
+    /// The median
+    pub(crate) median: f64,
+    /// The third quartile
+    pub(crate) q3: f64,
+    /// The highest sample within the upper fence
+    pub(crate) high: f64,
+    /// The samples out of the fences
+    pub(crate) outliers: Vec<f64>,
+}
+
+impl<'a> Distribution<'a> {
+    /// Creates a distribution of the given samples
+    pub fn new(samples: &'a [f64]) -> Self {
+        Self {
+            samples,
+            ..Self::default()
+        }
+    }
+
+    /// Sets the label of the distribution, displayed on its left
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn label<T: Into<Line<'a>>>(mut self, label: T) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets the style of the distribution
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// The style is applied to the whiskers, the box and the outliers of the distribution, under
+    /// the [box](BoxPlot::box_style) and [median](BoxPlot::median_style) styles of the plot.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the statistics of the finite samples, or `None` if there is none.
+    pub(crate) fn summary(&self) -> Option<Summary> {
+        let mut sorted: Vec<f64> = self
+            .samples
+            .iter()
+            .copied()
+            .filter(|v| v.is_finite())
+            .collect();
+        if sorted.is_empty() {
+            return None;
+        }
+        sorted.sort_by(f64::total_cmp);
+        let q1 = quantile(&sorted, 0.25);
+        let q3 = quantile(&sorted, 0.75);
+        // Tukey's fences
+        let fence = (q3 - q1) * 1.5;
+        let inside = |value: &f64| (q1 - fence..=q3 + fence).contains(value);
+        let (inliers, outliers): (Vec<f64>, Vec<f64>) = sorted.iter().partition(|v| inside(v));
+        Some(Summary {
+            low: inliers.first().copied().unwrap_or(q1),
+            q1,
+            median: quantile(&sorted, 0.5),
+            q3,
+            high: inliers.last().copied().unwrap_or(q3),
+            outliers,
+        })
+    }
+}
+
+impl<'a> From<&'a [f64]> for Distribution<'a> {
+    fn from(samples: &'a [f64]) -> Self {
+        Self::new(samples)
+    }
+}
+
+impl Default for BoxPlot<'_> {
+    fn default() -> Self {
+        Self {
+            block: None,
+            distributions: Vec::new(),
+            axis: Axis::default(),
+            box_style: Style::default(),
+            median_style: Style::new().add_modifier(Modifier::REVERSED),
+            style: Style::default(),
+        }
+    }
+}
+
+impl<'a> BoxPlot<'a> {
+    /// Creates a box plot of the given distributions, from top to bottom
+    pub fn new<T>(distributions: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: Into<Distribution<'a>>,
+    {
+        Self {
+            distributions: distributions.into_iter().map(Into::into).collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Surrounds the `BoxPlot` with a [`Block`]
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the axis of the values of the samples
+    ///
+    /// If the bounds of the axis are not set, they are the range of all the samples. The labels of
+    /// the axis are generated with [`Axis::ticks`], or evenly spaced with [`Axis::labels`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn axis(mut self, axis: Axis<'a>) -> Self {
+        self.axis = axis;
+        self
+    }
+
+    /// Sets the style of the boxes, patched over the style of each distribution
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn box_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.box_style = style.into();
+        self
+    }
+
+    /// Sets the style of the medians, patched over the style of the boxes
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// Defaults to [`Modifier::REVERSED`], so that the median stands out of the box.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn median_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.median_style = style.into();
+        self
+    }
+
+    /// Sets the style of the entire widget
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// The style will be applied to everything that isn't styled (borders, boxes, labels, ...).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl BoxPlot<'_> {
+    /// Returns the bounds of the axis, or the range of all the samples if they are not set.
+    fn bounds(&self) -> Option<[f64; 2]> {
+        let [min, max] = self.axis.fixed_bounds().or_else(|| {
+            let samples = self.distributions.iter().flat_map(|d| d.samples.iter());
+            data_bounds(samples.copied().filter(|v| v.is_finite()))
+        })?;
+        if max <= min {
+            return Some([min - 0.5, max + 0.5]);
+        }
+        Some([min, max])
+    }
+
+    fn render_distribution(
+        &self,
+        buf: &mut Buffer,
+        distribution: &Distribution,
+        axis: &ResolvedAxis,
+        graph: Rect,
+        y: u16,
+    ) {
+        let Some(summary) = distribution.summary() else {
+            return;
+        };
+        let [min, max] = axis.bounds();
+        let column = |value: f64| {
+            let offset = axis.value_to_column(value.clamp(min, max), graph.width);
+            graph.x + offset.unwrap_or_default()
+        };
+        let whisker_style = distribution.style;
+        let box_style = whisker_style.patch(self.box_style);
+        let [low, q1, median, q3, high] = [
+            summary.low,
+            summary.q1,
+            summary.median,
+            summary.q3,
+            summary.high,
+        ]
+        .map(column);
+        for x in low..=high {
+            let (symbol, style) = if (q1..=q3).contains(&x) {
+                (symbols::block::FULL, box_style)
+            } else {
+                (symbols::line::HORIZONTAL, whisker_style)
+            };
+            buf[(x, y)].set_symbol(symbol).set_style(style);
+        }
+        if low < q1 {
+            buf[(low, y)].set_symbol(symbols::line::VERTICAL_RIGHT);
+        }
+        if high > q3 {
+            buf[(high, y)].set_symbol(symbols::line::VERTICAL_LEFT);
+        }
+        buf[(median, y)]
+            .set_symbol(symbols::line::THICK_VERTICAL)
+            .set_style(box_style.patch(self.median_style));
+        for outlier in summary.outliers {
+            if let Some(offset) = axis.value_to_column(outlier, graph.width) {
+                buf[(graph.x + offset, y)]
+                    .set_symbol(symbols::DOT)
+                    .set_style(whisker_style);
+            }
+        }
+    }
+}
+
+impl Widget for BoxPlot<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl Widget for &BoxPlot<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        buf.set_style(area, self.style);
+        self.block.as_ref().render(area, buf);
+        let inner = self.block.inner_if_some(area);
+        if inner.is_empty() {
+            return;
+        }
+        let label_width = self
+            .distributions
+            .iter()
+            .filter_map(|distribution| distribution.label.as_ref())
+            .map(Line::width)
+            .max()
+            .unwrap_or_default() as u16;
+        let label_width = label_width.min(inner.width / 3);
+        let margin = u16::from(label_width != 0);
+        let axis = self.bounds().map(|bounds| self.axis.with_bounds(bounds));
+        let label_height = u16::from(axis.as_ref().is_some_and(ResolvedAxis::has_labels));
+        let graph = Rect {
+            x: inner.x + label_width + margin,
+            width: inner.width - label_width - margin,
+            height: inner.height.saturating_sub(label_height),
+            ..inner
+        };
+        if let Some(axis) = axis.as_ref().filter(|_| label_height > 0) {
+            axis.render_column_labels(buf, inner.bottom() - 1, inner, graph);
+        }
+        for (distribution, y) in self.distributions.iter().zip(graph.top()..graph.bottom()) {
+            if let Some(label) = &distribution.label {
+                label.render(Rect::new(inner.x, y, label_width, 1), buf);
+            }
+            if let Some(axis) = &axis {
+                self.render_distribution(buf, distribution, axis, graph, y);
+            }
+        }
+    }
+}
+
+impl Styled for BoxPlot<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use ratatui_core::style::{Color, Stylize};
+
+    use super::*;
+
+    const SAMPLES: [f64; 10] = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 30.0];
+
+    #[allow(clippy::float_cmp)]
+    #[test]
+    fn summary() {
+        let summary = Distribution::new(&SAMPLES).summary().unwrap();
+        assert_eq!(
+            summary,
+            Summary {
+                low: 1.0,
+                q1: 3.25,
+                median: 5.5,
+                q3: 7.75,
+                high: 9.0,
+                outliers: vec![30.0],
+            }
+        );
+        assert_eq!(Distribution::new(&[f64::NAN]).summary(), None);
+    }
+
+    #[test]
+    fn render() {
+        let boxplot = BoxPlot::new([Distribution::new(&SAMPLES).label("a")]).axis(
+            Axis::default()
+                .bounds([0.0, 30.0])
+                .labels(["0", "15", "30"]),
+        );
+        let mut buf = Buffer::empty(Rect::new(0, 0, 33, 2));
+        boxplot.render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines([
+            "a  ├─██┃██─┤                    •",
+            "  0             15             30",
+        ]);
+        expected[(7, 0)].set_style(Style::new().reversed());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_styles() {
+        let boxplot = BoxPlot::new([Distribution::new(&[1.0, 2.0, 3.0]).style(Color::Red)])
+            .box_style(Color::Blue)
+            .median_style(Color::Green);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 1));
+        boxplot.render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(["├█┃█┤"]);
+        expected.set_style(Rect::new(0, 0, 5, 1), Style::new().red());
+        expected.set_style(Rect::new(1, 0, 3, 1), Style::new().blue());
+        expected[(2, 0)].set_style(Style::new().green());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_rows() {
+        let first = [0.0, 10.0];
+        let second = [5.0];
+        let boxplot = BoxPlot::new([&first[..], &second[..]]).median_style(Style::new());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 11, 3));
+        boxplot.render(buf.area, &mut buf);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["├─███┃██──┤", "     ┃     ", "           "])
+        );
+    }
+}
@@ -424,6 +424,16 @@ impl<'a, 'b> Painter<'a, 'b> {
     pub fn paint(&mut self, x: usize, y: usize, color: Color) {
         self.context.grid.paint(x, y, color);
     }
+
+    /// Returns the number of points of the grid, horizontally and vertically.
+    pub(crate) const fn resolution(&self) -> (f64, f64) {
+        self.resolution
+    }
+
+    /// Returns the `x` and `y` bounds of the canvas.
+    pub(crate) const fn bounds(&self) -> ([f64; 2], [f64; 2]) {
+        (self.context.x_bounds, self.context.y_bounds)
+    }
 }
 
 impl<'a, 'b> From<&'a mut Context<'b>> for Painter<'a, 'b> {
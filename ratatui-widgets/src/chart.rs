@@ -1,28 +1,42 @@
 //! The [`Chart`] widget is used to plot one or more [`Dataset`] in a cartesian coordinate system.
-use std::{cmp::max, ops::Not};
+use std::{borrow::Cow, cmp::max};
 
 use ratatui_core::{
     buffer::Buffer,
     layout::{Alignment, Constraint, Flex, Layout, Position, Rect},
-    style::{Color, Style, Styled},
+    style::{Color, Modifier, Style, Styled},
     symbols::{self},
     text::Line,
-    widgets::Widget,
+    widgets::{StatefulWidget, Widget},
 };
 use strum::{Display, EnumString};
 
+pub use self::{cursor::ChartState, format::LabelFormat, scale::AxisScale};
+use self::{
+    cursor::Transform,
+    scale::{value_to_cell, value_to_row, ChartAxes, ResolvedAxis},
+    series::{draw_lines, step_points, Fill, Series},
+};
 use crate::{
     block::{Block, BlockExt},
     canvas::{Canvas, Line as CanvasLine, Points},
 };
 
+mod cursor;
+mod format;
+pub(crate) mod scale;
+mod series;
+
 /// An X or Y axis for the [`Chart`] widget
 ///
 /// An axis can have a [title](Axis::title) which will be displayed at the end of the axis. For an
 /// X axis this is the right, for a Y axis, this is the top.
 ///
 /// You can also set the bounds and labels on this axis using respectively [`Axis::bounds`] and
-/// [`Axis::labels`].
+/// [`Axis::labels`]. Alternatively, the bounds can be computed from the data of the chart with
+/// [`Axis::auto_bounds`], and the labels generated at "nice" values with [`Axis::ticks`] and
+/// formatted with [`Axis::label_format`]. Grid lines can be drawn at the ticks with
+/// [`Axis::grid_style`].
 ///
 /// See [`Chart::x_axis`] and [`Chart::y_axis`] to set an axis on a chart.
 ///
@@ -40,6 +54,21 @@ use crate::{
 ///     .bounds([0.0, 50.0])
 ///     .labels(["0".bold(), "25".into(), "50".bold()]);
 /// ```
+///
+/// An axis with bounds and labels computed from the data:
+///
+/// ```rust
+/// use ratatui::{
+///     style::{Style, Stylize},
+///     widgets::{Axis, LabelFormat},
+/// };
+///
+/// let axis = Axis::default()
+///     .auto_bounds(true)
+///     .ticks(5)
+///     .label_format(LabelFormat::Si)
+///     .grid_style(Style::new().dark_gray());
+/// ```
 #[derive(Debug, Default, Clone, PartialEq)]
 pub struct Axis<'a> {
     /// Title displayed next to axis end
@@ -52,6 +81,18 @@ pub struct Axis<'a> {
     style: Style,
     /// The alignment of the labels of the Axis
     labels_alignment: Alignment,
+    /// Whether the bounds are computed from the data of the chart
+    auto_bounds: bool,
+    /// The space added on both sides of the automatic bounds, as a fraction of the data range
+    bounds_padding: f64,
+    /// The approximate number of ticks to generate, if the labels are generated
+    tick_count: Option<usize>,
+    /// The format of the generated labels
+    label_format: LabelFormat,
+    /// The style of the grid lines drawn at the ticks, if any
+    grid_style: Option<Style>,
+    /// How the values are mapped to positions along the axis
+    scale: AxisScale,
 }
 
 impl<'a> Axis<'a> {
@@ -153,8 +194,94 @@ impl<'a> Axis<'a> {
         self.labels_alignment = alignment;
         self
     }
+
+    /// Sets whether the bounds of the axis are computed from the data of the chart
+    ///
+    /// The bounds are set to the smallest and the largest values of the datasets on this axis,
+    /// extended by the [padding](Axis::bounds_padding). The bounds of the Y axis include zero if a
+    /// dataset is drawn as a [bar chart](GraphType::Bar). If [ticks](Axis::ticks) are generated,
+    /// the bounds are also extended to the ticks around the data. The bounds given with
+    /// [`Axis::bounds`] are used if there is no data.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn auto_bounds(mut self, auto_bounds: bool) -> Self {
+        self.auto_bounds = auto_bounds;
+        self
+    }
+
+    /// Sets the space added on both sides of the [automatic bounds](Axis::auto_bounds), as a
+    /// fraction of the range of the data
+    ///
+    /// For instance, a padding of `0.1` extends the bounds of data from 0 to 100 to -10 and 110.
+    /// Defaults to no padding.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn bounds_padding(mut self, padding: f64) -> Self {
+        self.bounds_padding = padding;
+        self
+    }
+
+    /// Generates the labels of the axis at about `count` "nice" values within its bounds
+    ///
+    /// The ticks are the multiples of a step of 1, 2 or 5 times a power of ten, e.g. 0, 20, 40, 60
+    /// for bounds of 0 and 60 and a count of 4. Each label is displayed at the position of its
+    /// value, and formatted with the [label format](Axis::label_format). If labels are given with
+    /// [`Axis::labels`], they are displayed instead of the generated ones, and the ticks are only
+    /// used for the [automatic bounds](Axis::auto_bounds) and the [grid](Axis::grid_style).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn ticks(mut self, count: usize) -> Self {
+        self.tick_count = Some(count);
+        self
+    }
+
+    /// Sets the format of the labels generated with [`Axis::ticks`]
+    ///
+    /// Defaults to [`LabelFormat::Number`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn label_format(mut self, format: LabelFormat) -> Self {
+        self.label_format = format;
+        self
+    }
+
+    /// Draws grid lines across the graph at the ticks generated with [`Axis::ticks`], with the
+    /// given style
+    ///
+    /// The grid lines of the X axis are vertical, and the ones of the Y axis are horizontal. They
+    /// are drawn behind the datasets.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn grid_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.grid_style = Some(style.into());
+        self
+    }
+
+    /// Sets the scale of the axis
+    ///
+    /// Defaults to [`AxisScale::Linear`]. The values of the datasets are transformed by the scale
+    /// before being drawn, and the ticks generated with [`Axis::ticks`] follow the scale, e.g.
+    /// powers of ten on a [`AxisScale::Log10`] axis, or calendar units on a [`AxisScale::Time`]
+    /// axis.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn scale(mut self, scale: AxisScale) -> Self {
+        self.scale = scale;
+        self
+    }
 }
 
+const DEFAULT_HIGHLIGHT_STYLE: Style = Style::new().add_modifier(Modifier::REVERSED);
+
 /// Used to determine which style of graphing to use
 #[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
 pub enum GraphType {
@@ -170,6 +297,34 @@ pub enum GraphType {
 
     /// Draw a bar chart. This will draw a bar for each point in the dataset.
     Bar,
+
+    /// Draw a line between each following point, and fill the area between the line and the
+    /// [baseline](Dataset::baseline) of the dataset, or the previous dataset when the chart is
+    /// [stacked](Chart::stacked).
+    ///
+    /// Use [`Marker::HalfBlock`] or [`Marker::Braille`] for a smooth fill.
+    ///
+    /// [`Marker::HalfBlock`]: symbols::Marker::HalfBlock
+    /// [`Marker::Braille`]: symbols::Marker::Braille
+    Area,
+
+    /// Draw horizontal and vertical lines between each following point, with the vertical line at
+    /// the given position between the points.
+    Step(StepPosition),
+}
+
+/// The position of the vertical lines of a [`GraphType::Step`] dataset
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum StepPosition {
+    /// The value of a point holds until the next point, i.e. the vertical line is at the next
+    /// point. This is the default.
+    #[default]
+    Post,
+    /// The value of a point holds from the previous point, i.e. the vertical line is at the
+    /// previous point.
+    Pre,
+    /// The vertical line is halfway between the points.
+    Mid,
 }
 
 /// Allow users to specify the position of a legend in a [`Chart`]
@@ -197,7 +352,7 @@ pub enum LegendPosition {
 }
 
 impl LegendPosition {
-    fn layout(
+    pub(crate) fn layout(
         self,
         area: Rect,
         legend_width: u16,
@@ -332,6 +487,10 @@ pub struct Dataset<'a> {
     graph_type: GraphType,
     /// Style used to plot this dataset
     style: Style,
+    /// The value the area of an [`GraphType::Area`] dataset is filled down to
+    baseline: f64,
+    /// Whether the dataset is plotted against the secondary Y axis of the chart
+    secondary_axis: bool,
 }
 
 impl<'a> Dataset<'a> {
@@ -387,10 +546,10 @@ impl<'a> Dataset<'a> {
 
     /// Sets how the dataset should be drawn
     ///
-    /// [`Chart`] can draw [scatter](GraphType::Scatter), [line](GraphType::Line) or
-    /// [bar](GraphType::Bar) charts. A scatter chart draws only the points in the dataset, a line
-    /// char draws a line between each point, and a bar chart draws a line from the x axis to the
-    /// point.  See [`GraphType`] for more details
+    /// [`Chart`] can draw [scatter](GraphType::Scatter), [line](GraphType::Line),
+    /// [bar](GraphType::Bar), [area](GraphType::Area) or [step](GraphType::Step) charts. A scatter
+    /// chart draws only the points in the dataset, a line char draws a line between each point, and
+    /// a bar chart draws a line from the x axis to the point.  See [`GraphType`] for more details
     ///
     /// This is a fluent setter method which must be chained or used as it consumes self
     #[must_use = "method moves the value of self and returns the modified value"]
@@ -424,6 +583,31 @@ impl<'a> Dataset<'a> {
         self.style = style.into();
         self
     }
+
+    /// Sets the value the area of an [area](GraphType::Area) dataset is filled down to
+    ///
+    /// Defaults to `0.0`, which is below the lower bound of a logarithmic axis, so the area is
+    /// filled down to the axis. The baseline is ignored when the chart is
+    /// [stacked](Chart::stacked).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn baseline(mut self, baseline: f64) -> Self {
+        self.baseline = baseline;
+        self
+    }
+
+    /// Sets whether the dataset is plotted against the [secondary Y axis](Chart::secondary_y_axis)
+    /// of the chart, on the right of the graph
+    ///
+    /// The dataset is plotted against the Y axis on the left if the chart has no secondary axis.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn secondary_axis(mut self, secondary_axis: bool) -> Self {
+        self.secondary_axis = secondary_axis;
+        self
+    }
 }
 
 /// A container that holds all the infos about where to display each elements of the chart (axis,
@@ -441,6 +625,12 @@ struct ChartLayout {
     axis_x: Option<u16>,
     /// X coordinate of the vertical axis
     axis_y: Option<u16>,
+    /// Location of the title of the secondary y axis
+    title_y2: Option<Position>,
+    /// Location of the labels of the secondary y axis
+    label_y2: Option<u16>,
+    /// X coordinate of the secondary vertical axis
+    axis_y2: Option<u16>,
     /// Area of the legend
     legend_area: Option<Rect>,
     /// Area of the graph
@@ -519,6 +709,8 @@ pub struct Chart<'a> {
     x_axis: Axis<'a>,
     /// The vertical axis
     y_axis: Axis<'a>,
+    /// The vertical axis on the right of the graph, if any
+    secondary_y_axis: Option<Axis<'a>>,
     /// A reference to the datasets
     datasets: Vec<Dataset<'a>>,
     /// The widget base style
@@ -528,6 +720,12 @@ pub struct Chart<'a> {
     /// The position determine where the length is shown or hide regardless of
     /// `hidden_legend_constraints`
     legend_position: Option<LegendPosition>,
+    /// Whether the line, area and step datasets are stacked on top of each other
+    stacked: bool,
+    /// The style of the crosshair drawn at the cursor of the [`ChartState`]
+    cursor_style: Style,
+    /// The style of the points nearest to the cursor
+    highlight_style: Style,
 }
 
 impl<'a> Chart<'a> {
@@ -563,10 +761,14 @@ impl<'a> Chart<'a> {
             block: None,
             x_axis: Axis::default(),
             y_axis: Axis::default(),
+            secondary_y_axis: None,
             style: Style::default(),
             datasets,
             hidden_legend_constraints: (Constraint::Ratio(1, 4), Constraint::Ratio(1, 4)),
             legend_position: Some(LegendPosition::default()),
+            stacked: false,
+            cursor_style: Style::new(),
+            highlight_style: DEFAULT_HIGHLIGHT_STYLE,
         }
     }
 
@@ -641,6 +843,66 @@ impl<'a> Chart<'a> {
         self
     }
 
+    /// Sets a secondary Y [`Axis`], displayed on the right of the graph
+    ///
+    /// The datasets are plotted against the Y axis on the left unless they are set to use the
+    /// [secondary axis](Dataset::secondary_axis), which allows plotting values of different units or
+    /// magnitudes in the same chart. When the chart has a secondary axis, the legend indicates the
+    /// axis of each dataset with an arrow pointing to it.
+    ///
+    /// The title of the secondary axis is displayed at the top right of the graph. Its grid lines
+    /// are not drawn, to keep the graph readable.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Axis, Chart, Dataset, LabelFormat};
+    ///
+    /// let requests = [(0.0, 1200.0), (1.0, 1500.0)];
+    /// let errors = [(0.0, 0.01), (1.0, 0.04)];
+    /// let chart = Chart::new(vec![
+    ///     Dataset::default().name("requests").data(&requests),
+    ///     Dataset::default()
+    ///         .name("errors")
+    ///         .data(&errors)
+    ///         .secondary_axis(true),
+    /// ])
+    /// .y_axis(Axis::default().auto_bounds(true).ticks(5))
+    /// .secondary_y_axis(
+    ///     Axis::default()
+    ///         .auto_bounds(true)
+    ///         .ticks(5)
+    ///         .label_format(LabelFormat::Percent),
+    /// );
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn secondary_y_axis(mut self, axis: Axis<'a>) -> Self {
+        self.secondary_y_axis = Some(axis);
+        self
+    }
+
+    /// Returns true if the dataset is plotted against the secondary Y axis.
+    pub(crate) const fn uses_secondary_axis(&self, dataset: &Dataset) -> bool {
+        dataset.secondary_axis && self.secondary_y_axis.is_some()
+    }
+
+    /// Returns the name of the dataset displayed in the legend, followed by an arrow pointing to
+    /// its Y axis if the chart has a secondary axis.
+    fn legend_name(&self, dataset: &Dataset<'a>) -> Option<Line<'a>> {
+        let mut name = dataset.name.clone()?;
+        if self.secondary_y_axis.is_some() {
+            let arrow = if dataset.secondary_axis {
+                " →"
+            } else {
+                " ←"
+            };
+            name.push_span(arrow);
+        }
+        Some(name)
+    }
+
     /// Sets the constraints used to determine whether the legend should be shown or not.
     ///
     /// The tuple's first constraint is used for the width and the second for the height. If the
@@ -729,9 +991,77 @@ impl<'a> Chart<'a> {
         self
     }
 
+    /// Stacks the [line](GraphType::Line), [area](GraphType::Area) and [step](GraphType::Step)
+    /// datasets on top of each other
+    ///
+    /// The value of each point of a stacked dataset is added to the top of the previous stacked
+    /// datasets at the same index, so the datasets are expected to share the same `x` values, e.g.
+    /// the timestamps of measurements. The first stacked dataset starts from zero, and the area of
+    /// the next ones is filled down to the previous dataset. Scatter and bar datasets are not
+    /// stacked.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::{
+    ///     style::Stylize,
+    ///     symbols::Marker,
+    ///     widgets::{Chart, Dataset, GraphType},
+    /// };
+    ///
+    /// let user = [(0.0, 20.0), (1.0, 35.0), (2.0, 30.0)];
+    /// let system = [(0.0, 5.0), (1.0, 10.0), (2.0, 8.0)];
+    /// let dataset = |name, data| {
+    ///     Dataset::default()
+    ///         .name(name)
+    ///         .data(data)
+    ///         .graph_type(GraphType::Area)
+    ///         .marker(Marker::HalfBlock)
+    /// };
+    /// let chart = Chart::new(vec![
+    ///     dataset("user", &user).green(),
+    ///     dataset("system", &system).red(),
+    /// ])
+    /// .stacked(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn stacked(mut self, stacked: bool) -> Self {
+        self.stacked = stacked;
+        self
+    }
+
+    /// Sets the style of the vertical crosshair drawn at the cursor of the [`ChartState`]
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn cursor_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.cursor_style = style.into();
+        self
+    }
+
+    /// Sets the style of the point of each dataset nearest to the cursor of the [`ChartState`]
+    ///
+    /// Defaults to reversed colors.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn highlight_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.highlight_style = style.into();
+        self
+    }
+
     /// Compute the internal layout of the chart given the area. If the area is too small some
     /// elements may be automatically hidden
-    fn layout(&self, area: Rect) -> Option<ChartLayout> {
+    #[allow(clippy::too_many_lines)]
+    fn layout(&self, area: Rect, axes: &ChartAxes) -> Option<ChartLayout> {
         if area.height == 0 || area.width == 0 {
             return None;
         }
@@ -739,27 +1069,41 @@ impl<'a> Chart<'a> {
         let mut y = area.bottom() - 1;
 
         let mut label_x = None;
-        if !self.x_axis.labels.is_empty() && y > area.top() {
+        if axes.x.has_labels() && y > area.top() {
             label_x = Some(y);
             y -= 1;
         }
 
-        let label_y = self.y_axis.labels.is_empty().not().then_some(x);
-        x += self.max_width_of_labels_left_of_y_axis(area, !self.y_axis.labels.is_empty());
+        let label_y = axes.y.has_labels().then_some(x);
+        x += Self::max_width_of_labels_left_of_y_axis(area, axes, axes.y.has_labels());
 
         let mut axis_x = None;
-        if !self.x_axis.labels.is_empty() && y > area.top() {
+        if axes.x.has_labels() && y > area.top() {
             axis_x = Some(y);
             y -= 1;
         }
 
         let mut axis_y = None;
-        if !self.y_axis.labels.is_empty() && x + 1 < area.right() {
+        if axes.y.has_labels() && x + 1 < area.right() {
             axis_y = Some(x);
             x += 1;
         }
 
-        let graph_width = area.right().saturating_sub(x);
+        // the secondary axis and its labels are on the right of the graph
+        let mut right = area.right();
+        let mut label_y2 = None;
+        let mut axis_y2 = None;
+        if let Some(axis) = axes.secondary_y.as_ref() {
+            let labels_width = axis.labels_width().min(area.width / 3);
+            if axis.has_labels() && x + labels_width + 2 < right {
+                right -= labels_width;
+                label_y2 = Some(right);
+                right -= 1;
+                axis_y2 = Some(right);
+            }
+        }
+
+        let graph_width = right.saturating_sub(x);
         let graph_height = y.saturating_sub(area.top()).saturating_add(1);
         debug_assert_ne!(
             graph_width, 0,
@@ -787,12 +1131,27 @@ impl<'a> Chart<'a> {
             }
         }
 
+        let mut title_y2 = None;
+        if let Some(title) = self
+            .secondary_y_axis
+            .as_ref()
+            .and_then(|axis| axis.title.as_ref())
+        {
+            let w = title.width() as u16;
+            let left_title_width = title_y
+                .and(self.y_axis.title.as_ref())
+                .map_or(0, |title| title.width() as u16 + 1);
+            if w + left_title_width < graph_area.width && graph_area.height > 2 {
+                title_y2 = Some(Position::new(graph_area.right() - w, area.top()));
+            }
+        }
+
         let mut legend_area = None;
         if let Some(legend_position) = self.legend_position {
             let legends = self
                 .datasets
                 .iter()
-                .filter_map(|d| Some(d.name.as_ref()?.width() as u16));
+                .filter_map(|d| Some(self.legend_name(d)?.width() as u16));
 
             if let Some(inner_width) = legends.clone().max() {
                 let legend_width = inner_width + 2;
@@ -833,23 +1192,20 @@ impl<'a> Chart<'a> {
             label_y,
             axis_x,
             axis_y,
+            title_y2,
+            label_y2,
+            axis_y2,
             legend_area,
             graph_area,
         })
     }
 
-    fn max_width_of_labels_left_of_y_axis(&self, area: Rect, has_y_axis: bool) -> u16 {
-        let mut max_width = self
-            .y_axis
-            .labels
-            .iter()
-            .map(Line::width)
-            .max()
-            .unwrap_or_default() as u16;
+    fn max_width_of_labels_left_of_y_axis(area: Rect, axes: &ChartAxes, has_y_axis: bool) -> u16 {
+        let mut max_width = axes.y.labels_width();
 
-        if let Some(first_x_label) = self.x_axis.labels.first() {
+        if let Some(first_x_label) = axes.x.labels().first() {
             let first_label_width = first_x_label.width() as u16;
-            let width_left_of_y_axis = match self.x_axis.labels_alignment {
+            let width_left_of_y_axis = match axes.x.axis.labels_alignment {
                 Alignment::Left => {
                     // The last character of the label should be below the Y-Axis when it exists,
                     // not on its left
@@ -869,11 +1225,16 @@ impl<'a> Chart<'a> {
         &self,
         buf: &mut Buffer,
         layout: &ChartLayout,
+        axes: &ChartAxes,
         chart_area: Rect,
         graph_area: Rect,
     ) {
         let Some(y) = layout.label_x else { return };
-        let labels = &self.x_axis.labels;
+        if axes.x.has_tick_labels() {
+            axes.x.render_column_labels(buf, y, chart_area, graph_area);
+            return;
+        }
+        let labels = axes.x.labels();
         let labels_len = labels.len() as u16;
         if labels_len < 2 {
             return;
@@ -945,25 +1306,49 @@ impl<'a> Chart<'a> {
     }
 
     fn render_y_labels(
-        &self,
         buf: &mut Buffer,
         layout: &ChartLayout,
+        axes: &ChartAxes,
         chart_area: Rect,
         graph_area: Rect,
     ) {
-        let Some(x) = layout.label_y else { return };
-        let labels = &self.y_axis.labels;
-        let labels_len = labels.len() as u16;
-        for (i, label) in labels.iter().enumerate() {
-            let dy = i as u16 * (graph_area.height - 1) / (labels_len - 1);
-            if dy < graph_area.bottom() {
-                let label_area = Rect::new(
-                    x,
-                    graph_area.bottom().saturating_sub(1) - dy,
-                    (graph_area.left() - chart_area.left()).saturating_sub(1),
-                    1,
-                );
-                Self::render_label(buf, label, label_area, self.y_axis.labels_alignment);
+        if let Some(x) = layout.label_y {
+            let width = (graph_area.left() - chart_area.left()).saturating_sub(1);
+            axes.y.render_row_labels(buf, x, width, graph_area);
+        }
+        if let (Some(x), Some(axis)) = (layout.label_y2, &axes.secondary_y) {
+            let width = chart_area.right().saturating_sub(x);
+            axis.render_row_labels(buf, x, width, graph_area);
+        }
+    }
+
+    /// Renders the grid lines at the ticks of the axes which have a grid style.
+    ///
+    /// There is no grid line at the lower bound of the axes, as it would be drawn next to the
+    /// other axis.
+    fn render_grid(buf: &mut Buffer, axes: &ChartAxes, graph_area: Rect) {
+        if let Some(style) = axes.x.axis.grid_style {
+            for offset in axes.x.tick_columns(graph_area.width).filter(|&x| x > 0) {
+                let x = graph_area.left() + offset;
+                for y in graph_area.top()..graph_area.bottom() {
+                    buf[(x, y)]
+                        .set_symbol(symbols::line::VERTICAL)
+                        .set_style(style);
+                }
+            }
+        }
+        if let Some(style) = axes.y.axis.grid_style {
+            for offset in axes.y.tick_rows(graph_area.height).filter(|&y| y > 0) {
+                let y = graph_area.bottom() - 1 - offset;
+                for x in graph_area.left()..graph_area.right() {
+                    let cell = &mut buf[(x, y)];
+                    let symbol = if cell.symbol() == symbols::line::VERTICAL {
+                        symbols::line::CROSS
+                    } else {
+                        symbols::line::HORIZONTAL
+                    };
+                    cell.set_symbol(symbol).set_style(style);
+                }
             }
         }
     }
@@ -976,24 +1361,65 @@ impl Widget for Chart<'_> {
 }
 
 impl Widget for &Chart<'_> {
-    #[allow(clippy::too_many_lines)]
     fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut state = ChartState::default();
+        StatefulWidget::render(self, area, buf, &mut state);
+    }
+}
+
+impl StatefulWidget for Chart<'_> {
+    type State = ChartState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidget::render(&self, area, buf, state);
+    }
+}
+
+impl StatefulWidget for &Chart<'_> {
+    type State = ChartState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let series = self.series();
+        let axes = self.axes(&series);
+        self.render_chart(area, buf, state, &series, &axes);
+    }
+}
+
+impl Chart<'_> {
+    /// Renders the chart, once the automatic bounds and labels of its axes are computed.
+    #[allow(clippy::too_many_lines)]
+    fn render_chart(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        state: &mut ChartState,
+        series: &[Series],
+        axes: &ChartAxes,
+    ) {
         buf.set_style(area, self.style);
 
         self.block.as_ref().render(area, buf);
         let chart_area = self.block.inner_if_some(area);
-        let Some(layout) = self.layout(chart_area) else {
+        let Some(layout) = self.layout(chart_area, axes) else {
+            state.transform = None;
             return;
         };
         let graph_area = layout.graph_area;
+        state.transform = Some(Transform {
+            graph_area,
+            x_bounds: axes.x.canvas_bounds(),
+            x_scale: self.x_axis.scale,
+            y_bounds: axes.y.canvas_bounds(),
+            y_scale: self.y_axis.scale,
+        });
 
         // Sample the style of the entire widget. This sample will be used to reset the style of
         // the cells that are part of the components put on top of the grah area (i.e legend and
         // axis names).
         let original_style = buf[(area.left(), area.top())].style();
 
-        self.render_x_labels(buf, &layout, chart_area, graph_area);
-        self.render_y_labels(buf, &layout, chart_area, graph_area);
+        self.render_x_labels(buf, &layout, axes, chart_area, graph_area);
+        Self::render_y_labels(buf, &layout, axes, chart_area, graph_area);
 
         if let Some(y) = layout.axis_x {
             for x in graph_area.left()..graph_area.right() {
@@ -1011,45 +1437,94 @@ impl Widget for &Chart<'_> {
             }
         }
 
+        if let (Some(x), Some(axis)) = (layout.axis_y2, &self.secondary_y_axis) {
+            for y in graph_area.top()..graph_area.bottom() {
+                buf[(x, y)]
+                    .set_symbol(symbols::line::VERTICAL)
+                    .set_style(axis.style);
+            }
+        }
+
         if let Some(y) = layout.axis_x {
             if let Some(x) = layout.axis_y {
                 buf[(x, y)]
                     .set_symbol(symbols::line::BOTTOM_LEFT)
                     .set_style(self.x_axis.style);
             }
+            if let Some(x) = layout.axis_y2 {
+                buf[(x, y)]
+                    .set_symbol(symbols::line::BOTTOM_RIGHT)
+                    .set_style(self.x_axis.style);
+            }
         }
 
-        for dataset in &self.datasets {
+        Self::render_grid(buf, axes, graph_area);
+        self.render_crosshair(buf, axes, graph_area, state);
+
+        for (dataset, series) in self.datasets.iter().zip(series) {
+            let y_axis = axes.dataset_y_axis(dataset);
+            // the bars and the areas of a logarithmic axis start from its lower bound, as there is
+            // no zero
+            let [y_min, _] = y_axis.canvas_bounds();
+            let scaled_base = |base: f64| {
+                let base = y_axis.axis.scale.transform(base);
+                if base.is_finite() {
+                    base
+                } else {
+                    y_min
+                }
+            };
+            let data = self.scaled_data(&series.data, y_axis.axis);
+            // the points out of the domain of the scales are not drawn
+            let points = match &data {
+                Cow::Borrowed(data) => Cow::Borrowed(*data),
+                Cow::Owned(data) => Cow::Owned(
+                    data.iter()
+                        .filter(|(x, y)| x.is_finite() && y.is_finite())
+                        .copied()
+                        .collect(),
+                ),
+            };
+            let color = dataset.style.fg.unwrap_or(Color::Reset);
             Canvas::default()
                 .background_color(self.style.bg.unwrap_or(Color::Reset))
-                .x_bounds(self.x_axis.bounds)
-                .y_bounds(self.y_axis.bounds)
+                .x_bounds(axes.x.canvas_bounds())
+                .y_bounds(y_axis.canvas_bounds())
                 .marker(dataset.marker)
                 .paint(|ctx| {
                     ctx.draw(&Points {
-                        coords: dataset.data,
-                        color: dataset.style.fg.unwrap_or(Color::Reset),
+                        coords: &points,
+                        color,
                     });
                     match dataset.graph_type {
-                        GraphType::Line => {
-                            for data in dataset.data.windows(2) {
-                                ctx.draw(&CanvasLine {
-                                    x1: data[0].0,
-                                    y1: data[0].1,
-                                    x2: data[1].0,
-                                    y2: data[1].1,
-                                    color: dataset.style.fg.unwrap_or(Color::Reset),
-                                });
-                            }
+                        GraphType::Line => draw_lines(ctx, &data, color),
+                        GraphType::Step(position) => {
+                            draw_lines(ctx, &step_points(&data, position), color);
+                        }
+                        GraphType::Area => {
+                            let bases = series.bases.as_deref();
+                            let points: Vec<_> = data
+                                .iter()
+                                .enumerate()
+                                .map(|(index, &(x, y))| {
+                                    let base = bases.map_or(dataset.baseline, |bases| bases[index]);
+                                    (x, y, scaled_base(base))
+                                })
+                                .collect();
+                            ctx.draw(&Fill {
+                                points: &points,
+                                color,
+                            });
                         }
                         GraphType::Bar => {
-                            for (x, y) in dataset.data {
+                            let base = scaled_base(0.0);
+                            for (x, y) in points.iter() {
                                 ctx.draw(&CanvasLine {
                                     x1: *x,
-                                    y1: 0.0,
+                                    y1: base,
                                     x2: *x,
                                     y2: *y,
-                                    color: dataset.style.fg.unwrap_or(Color::Reset),
+                                    color,
                                 });
                             }
                         }
@@ -1095,17 +1570,30 @@ impl Widget for &Chart<'_> {
             buf.set_line(x, y, title, width);
         }
 
+        if let Some(Position { x, y }) = layout.title_y2 {
+            let title = self
+                .secondary_y_axis
+                .as_ref()
+                .and_then(|axis| axis.title.as_ref());
+            let title = title.unwrap();
+            let width = graph_area
+                .right()
+                .saturating_sub(x)
+                .min(title.width() as u16);
+            buf.set_style(Rect::new(x, y, width, 1), original_style);
+            buf.set_line(x, y, title, width);
+        }
+
         if let Some(legend_area) = layout.legend_area {
             buf.set_style(legend_area, original_style);
             Block::bordered().render(legend_area, buf);
 
-            for (i, (dataset_name, dataset_style)) in self
+            for (i, name) in self
                 .datasets
                 .iter()
-                .filter_map(|ds| Some((ds.name.as_ref()?, ds.style())))
+                .filter_map(|ds| Some(self.legend_name(ds)?.patch_style(ds.style())))
                 .enumerate()
             {
-                let name = dataset_name.clone().patch_style(dataset_style);
                 name.render(
                     Rect {
                         x: legend_area.x + 1,
@@ -1117,6 +1605,71 @@ impl Widget for &Chart<'_> {
                 );
             }
         }
+
+        self.render_cursor(buf, series, axes, graph_area, state);
+    }
+}
+
+impl ResolvedAxis<'_, '_> {
+    /// Returns the offset of the column displaying each label, from the left of a horizontal axis
+    /// of the given length in cells: the columns of the ticks if they are generated, or evenly
+    /// spaced columns from the start to the end of the axis.
+    fn label_columns(&self, length: u16) -> Vec<(u16, &Line<'_>)> {
+        if self.has_tick_labels() {
+            return self.tick_label_cells(length, value_to_cell).collect();
+        }
+        let labels = self.labels();
+        let last = labels.len().saturating_sub(1).max(1);
+        labels
+            .iter()
+            .enumerate()
+            .map(|(i, label)| {
+                let offset = i * usize::from(length.saturating_sub(1)) / last;
+                (offset as u16, label)
+            })
+            .collect()
+    }
+
+    /// Renders the labels of the axis on the row `y`, each one centered below the column of its
+    /// value in the graph area and kept in the bounds. The labels overlapping the previous one are
+    /// skipped.
+    pub(crate) fn render_column_labels(&self, buf: &mut Buffer, y: u16, bounds: Rect, graph: Rect) {
+        let mut free_x = bounds.left();
+        for (offset, label) in self.label_columns(graph.width) {
+            let width = (label.width() as u16).min(bounds.width);
+            let x = (graph.left() + offset)
+                .saturating_sub(width / 2)
+                .clamp(bounds.left(), bounds.right() - width);
+            if x < free_x {
+                continue;
+            }
+            Chart::render_label(buf, label, Rect::new(x, y, width, 1), Alignment::Left);
+            // leave at least one space between two labels
+            free_x = x + width + 1;
+        }
+    }
+
+    /// Renders the labels of a vertical axis in the columns starting at `x`: the labels generated
+    /// for its ticks each on the row of its value, or its labels evenly spaced from the bottom to
+    /// the top of the graph area.
+    pub(crate) fn render_row_labels(&self, buf: &mut Buffer, x: u16, width: u16, graph: Rect) {
+        let alignment = self.axis.labels_alignment;
+        if self.has_tick_labels() {
+            for (offset, label) in self.tick_label_cells(graph.height, value_to_row) {
+                let label_area = Rect::new(x, graph.bottom() - 1 - offset, width, 1);
+                Chart::render_label(buf, label, label_area, alignment);
+            }
+            return;
+        }
+        let labels = self.labels();
+        let labels_len = labels.len() as u16;
+        for (i, label) in labels.iter().enumerate() {
+            let dy = i as u16 * (graph.height - 1) / (labels_len - 1);
+            if dy < graph.bottom() {
+                let label_area = Rect::new(x, graph.bottom().saturating_sub(1) - dy, width, 1);
+                Chart::render_label(buf, label, label_area, alignment);
+            }
+        }
     }
 }
 
@@ -1196,7 +1749,7 @@ mod tests {
                 .x_axis(Axis::default().title("X axis"))
                 .y_axis(Axis::default().title("Y axis"))
                 .hidden_legend_constraints(case.hidden_legend_constraints);
-            let layout = chart.layout(case.chart_area).unwrap();
+            let layout = chart.layout(case.chart_area, &chart.axes(&[])).unwrap();
             assert_eq!(layout.legend_area, case.legend_area);
         }
     }
@@ -1242,6 +1795,8 @@ mod tests {
         assert_eq!(GraphType::Scatter.to_string(), "Scatter");
         assert_eq!(GraphType::Line.to_string(), "Line");
         assert_eq!(GraphType::Bar.to_string(), "Bar");
+        assert_eq!(GraphType::Area.to_string(), "Area");
+        assert_eq!(GraphType::Step(StepPosition::Mid).to_string(), "Step");
     }
 
     #[test]
@@ -1249,6 +1804,11 @@ mod tests {
         assert_eq!("Scatter".parse::<GraphType>(), Ok(GraphType::Scatter));
         assert_eq!("Line".parse::<GraphType>(), Ok(GraphType::Line));
         assert_eq!("Bar".parse::<GraphType>(), Ok(GraphType::Bar));
+        assert_eq!("Area".parse::<GraphType>(), Ok(GraphType::Area));
+        assert_eq!(
+            "Step".parse::<GraphType>(),
+            Ok(GraphType::Step(StepPosition::Post))
+        );
         assert_eq!("".parse::<GraphType>(), Err(ParseError::VariantNotFound));
     }
 
@@ -1258,7 +1818,7 @@ mod tests {
             .y_axis(Axis::default().title("xxxxxxxxxxxxxxxx"))
             .x_axis(Axis::default().title("xxxxxxxxxxxxxxxx"));
         let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 4));
-        widget.render(buffer.area, &mut buffer);
+        Widget::render(widget, buffer.area, &mut buffer);
         assert_eq!(buffer, Buffer::with_lines(vec![" ".repeat(8); 4]));
     }
 
@@ -1269,7 +1829,7 @@ mod tests {
         let data_unnamed = Dataset::default(); // must not occupy a row in legend
         let widget = Chart::new(vec![data_named_1, data_unnamed, data_named_2]);
         let buffer = Buffer::empty(Rect::new(0, 0, 50, 25));
-        let layout = widget.layout(buffer.area).unwrap();
+        let layout = widget.layout(buffer.area, &widget.axes(&[])).unwrap();
 
         assert!(layout.legend_area.is_some());
         assert_eq!(layout.legend_area.unwrap().height, 4); // 2 for borders, 2 for rows
@@ -1280,7 +1840,7 @@ mod tests {
         let dataset = Dataset::default();
         let widget = Chart::new(vec![dataset; 3]);
         let buffer = Buffer::empty(Rect::new(0, 0, 50, 25));
-        let layout = widget.layout(buffer.area).unwrap();
+        let layout = widget.layout(buffer.area, &widget.axes(&[])).unwrap();
 
         assert!(layout.legend_area.is_none());
     }
@@ -1293,7 +1853,7 @@ mod tests {
         let widget = Chart::new(vec![long_dataset_name, short_dataset])
             .hidden_legend_constraints((100.into(), 100.into()));
         let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 5));
-        widget.render(buffer.area, &mut buffer);
+        Widget::render(widget, buffer.area, &mut buffer);
         let expected = Buffer::with_lines([
             "    ┌──────────────┐",
             "    │Very long name│",
@@ -1310,7 +1870,7 @@ mod tests {
             .legend_position(Some(LegendPosition::TopLeft));
         let area = Rect::new(0, 0, 30, 20);
         let mut buffer = Buffer::empty(area);
-        chart.render(buffer.area, &mut buffer);
+        Widget::render(chart, buffer.area, &mut buffer);
         let expected = Buffer::with_lines([
             "┌───┐                         ",
             "│Ds1│                         ",
@@ -1342,7 +1902,7 @@ mod tests {
             .y_axis(Axis::default().title("The title overlap a legend."));
         let area = Rect::new(0, 0, 30, 20);
         let mut buffer = Buffer::empty(area);
-        chart.render(buffer.area, &mut buffer);
+        Widget::render(chart, buffer.area, &mut buffer);
         let expected = Buffer::with_lines([
             "The title overlap a legend.   ",
             "                         ┌───┐",
@@ -1374,7 +1934,7 @@ mod tests {
             .y_axis(Axis::default().title("The title overlap a legend."));
         let area = Rect::new(0, 0, 10, 10);
         let mut buffer = Buffer::empty(area);
-        chart.render(buffer.area, &mut buffer);
+        Widget::render(chart, buffer.area, &mut buffer);
         let expected = Buffer::with_lines([
             "          ",
             "          ",
@@ -1409,7 +1969,7 @@ mod tests {
         ] {
             let chart = chart.clone().legend_position(Some(position));
             buffer.reset();
-            chart.render(buffer.area, &mut buffer);
+            Widget::render(chart, buffer.area, &mut buffer);
             #[rustfmt::skip]
             let expected = Buffer::with_lines([
                 "┌────┐",
@@ -1506,7 +2066,7 @@ mod tests {
         let chart = Chart::new(vec![Dataset::default().name(name)])
             .legend_position(legend_position)
             .hidden_legend_constraints((Constraint::Percentage(100), Constraint::Percentage(100)));
-        chart.render(buffer.area, &mut buffer);
+        Widget::render(chart, buffer.area, &mut buffer);
         assert_eq!(buffer, Buffer::with_lines(expected));
     }
 
@@ -1528,7 +2088,7 @@ mod tests {
         .y_axis(Axis::default().bounds([0.0, 10.0]));
         let area = Rect::new(0, 0, 11, 11);
         let mut buffer = Buffer::empty(area);
-        chart.render(buffer.area, &mut buffer);
+        Widget::render(chart, buffer.area, &mut buffer);
         let expected = Buffer::with_lines([
             "          •",
             "        • •",
@@ -1544,4 +2104,200 @@ mod tests {
         ]);
         assert_eq!(buffer, expected);
     }
+
+    #[test]
+    fn auto_bounds_and_ticks() {
+        let data = [(1.0, 3.0), (9.0, 38.0)];
+        let chart = Chart::new(vec![Dataset::default()
+            .data(&data)
+            .marker(symbols::Marker::Dot)])
+        .x_axis(Axis::default().auto_bounds(true).ticks(3))
+        .y_axis(Axis::default().auto_bounds(true).ticks(3));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 14, 7));
+        Widget::render(chart, buffer.area, &mut buffer);
+        let expected = Buffer::with_lines([
+            "40│         • ",
+            "  │           ",
+            "20│           ",
+            "  │ •         ",
+            "0 │           ",
+            "  └───────────",
+            "   0    5   10",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn grid_lines() {
+        let chart = Chart::new(vec![])
+            .x_axis(
+                Axis::default()
+                    .bounds([0.0, 4.0])
+                    .ticks(3)
+                    .grid_style(Style::new()),
+            )
+            .y_axis(
+                Axis::default()
+                    .bounds([0.0, 2.0])
+                    .ticks(2)
+                    .grid_style(Style::new()),
+            );
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 7, 5));
+        Widget::render(chart, buffer.area, &mut buffer);
+        let expected = Buffer::with_lines(["2│──┼─┼", " │  │ │", "0│  │ │", " └─────", "  0 2 4"]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn log_scale() {
+        // the point at zero is out of the domain of the scale, it is not drawn
+        let data = [(0.0, 5.0), (1.0, 1.0), (10.0, 10.0), (100.0, 100.0)];
+        let axis = || {
+            Axis::default()
+                .scale(AxisScale::Log10)
+                .bounds([1.0, 100.0])
+                .ticks(3)
+        };
+        let chart = Chart::new(vec![Dataset::default()
+            .marker(symbols::Marker::Dot)
+            .data(&data)])
+        .x_axis(axis())
+        .y_axis(axis());
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 14, 6));
+        Widget::render(chart, buffer.area, &mut buffer);
+        let expected = Buffer::with_lines([
+            "100│         •",
+            "10 │    •     ",
+            "   │          ",
+            "1  │•         ",
+            "   └──────────",
+            "    1  10  100",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn area() {
+        let data = [(0.0, 1.0), (4.0, 3.0)];
+        let chart = Chart::new(vec![Dataset::default()
+            .data(&data)
+            .graph_type(GraphType::Area)
+            .marker(symbols::Marker::HalfBlock)
+            .style(Color::Red)])
+        .x_axis(Axis::default().bounds([0.0, 4.0]))
+        .y_axis(Axis::default().bounds([0.0, 3.0]));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 3));
+        Widget::render(chart, buffer.area, &mut buffer);
+        // the area is filled down to the baseline with half blocks
+        let mut expected = Buffer::with_lines(["  ▄██", "▄████", "█████"]);
+        let (half, full) = (Style::new().red(), Style::new().red().on_red());
+        expected.set_style(Rect::new(2, 0, 1, 1), half);
+        expected.set_style(Rect::new(3, 0, 2, 1), full);
+        expected.set_style(Rect::new(0, 1, 1, 1), half);
+        expected.set_style(Rect::new(1, 1, 4, 1), full);
+        expected.set_style(Rect::new(0, 2, 5, 1), full);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn stacked_step() {
+        let lower = [(0.0, 1.0), (2.0, 1.0), (4.0, 1.0)];
+        let upper = [(0.0, 1.0), (2.0, 2.0), (4.0, 2.0)];
+        let dataset = |data| {
+            Dataset::default()
+                .data(data)
+                .graph_type(GraphType::Step(StepPosition::Post))
+        };
+        let chart = Chart::new(vec![dataset(&lower), dataset(&upper)])
+            .x_axis(Axis::default().bounds([0.0, 4.0]))
+            .y_axis(Axis::default().bounds([0.0, 4.0]))
+            .stacked(true);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 5));
+        Widget::render(chart, buffer.area, &mut buffer);
+        let expected = Buffer::with_lines(["     ", "  •••", "•••  ", "•••••", "     "]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn cursor() {
+        let data = [(0.0, 0.0), (2.0, 2.0), (4.0, 1.0)];
+        let chart = Chart::new(vec![Dataset::default()
+            .name("cpu")
+            .marker(symbols::Marker::Dot)
+            .data(&data)])
+        .x_axis(Axis::default().bounds([0.0, 4.0]))
+        .y_axis(Axis::default().bounds([0.0, 2.0]))
+        .legend_position(None);
+        let mut state = ChartState::default().with_cursor(1.5);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 7));
+        StatefulWidget::render(&chart, buffer.area, &mut buffer, &mut state);
+        // the tooltip is at the bottom, as the highlighted point is at the top
+        let mut expected = Buffer::with_lines([
+            "       │ •          ",
+            "       │            ",
+            "       │            ",
+            "       │┌────────┐ •",
+            "       ││1.5     │  ",
+            "       ││cpu: 2.0│  ",
+            "•      │└────────┘  ",
+        ]);
+        expected.set_style(Rect::new(9, 0, 1, 1), DEFAULT_HIGHLIGHT_STYLE);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn cell_to_data() {
+        let chart = Chart::new(vec![])
+            .x_axis(Axis::default().bounds([0.0, 10.0]))
+            .y_axis(Axis::default().scale(AxisScale::Log10).bounds([1.0, 100.0]));
+        let mut state = ChartState::default();
+        assert_eq!(state.cell_to_data(Position::new(0, 0)), None);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 11, 3));
+        StatefulWidget::render(&chart, buffer.area, &mut buffer, &mut state);
+        assert_eq!(state.graph_area(), Some(Rect::new(0, 0, 11, 3)));
+        let (x, y) = state.cell_to_data(Position::new(5, 1)).unwrap();
+        assert!((x - 5.0).abs() < 1e-9 && (y - 10.0).abs() < 1e-9);
+        assert_eq!(
+            state.data_to_cell((10.0, 100.0)),
+            Some(Position::new(10, 0))
+        );
+        assert_eq!(state.data_to_cell((11.0, 100.0)), None);
+    }
+
+    #[test]
+    fn secondary_y_axis() {
+        let requests = [(0.0, 0.0), (4.0, 20.0)];
+        let errors = [(0.0, 0.5), (4.0, 0.0)];
+        let chart = Chart::new(vec![
+            Dataset::default()
+                .name("rps")
+                .marker(symbols::Marker::Dot)
+                .data(&requests),
+            Dataset::default()
+                .name("err")
+                .marker(symbols::Marker::Dot)
+                .data(&errors)
+                .secondary_axis(true),
+        ])
+        .x_axis(Axis::default().bounds([0.0, 4.0]))
+        .y_axis(Axis::default().auto_bounds(true).ticks(2))
+        .secondary_y_axis(
+            Axis::default()
+                .auto_bounds(true)
+                .ticks(2)
+                .label_format(LabelFormat::Percent),
+        )
+        .hidden_legend_constraints((Constraint::Percentage(100), Constraint::Percentage(100)));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 5));
+        Widget::render(chart, buffer.area, &mut buffer);
+        // the legend points to the axis of each dataset
+        let expected = Buffer::with_lines([
+            "20│•     ┌─────┐│50%",
+            "  │      │rps ←││   ",
+            "  │      │err →││   ",
+            "  │      └─────┘│   ",
+            "0 │•           •│0% ",
+        ]);
+        assert_eq!(buffer, expected);
+    }
 }
@@ -0,0 +1,251 @@
+use ratatui_core::{
+    buffer::Buffer,
+    layout::{Position, Rect, Size},
+    symbols,
+    text::{Line, Span, Text},
+    widgets::Widget,
+};
+
+use super::{
+    scale::{cell_to_value, value_to_cell, value_to_row, ChartAxes},
+    series::Series,
+    AxisScale, Chart,
+};
+use crate::popup::{content_size, Popup};
+
+/// State of the [`Chart`] widget
+///
+/// This state holds the position of the cursor of the chart, in data coordinates. When the cursor
+/// is set, the chart draws a vertical crosshair at its position, highlights the point of each
+/// [`Dataset`] nearest to it, and displays their values in a tooltip.
+///
+/// The state also records where the data was drawn during the last render, which allows mapping
+/// the cells of the terminal to data coordinates and back, e.g. to move the cursor with the mouse.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     layout::{Position, Rect},
+///     widgets::{Chart, ChartState, Dataset},
+///     Frame,
+/// };
+///
+/// # fn ui(frame: &mut Frame) {
+/// let data = [(0.0, 1.0), (1.0, 3.0), (2.0, 2.0)];
+/// let chart = Chart::new(vec![Dataset::default().name("data").data(&data)]);
+///
+/// // This should be stored outside of the function in your application state.
+/// let mut state = ChartState::default().with_cursor(1.0);
+///
+/// frame.render_stateful_widget(chart, frame.area(), &mut state);
+///
+/// // move the cursor to the column of the mouse
+/// # let mouse = Position::new(10, 5);
+/// if let Some((x, _)) = state.cell_to_data(mouse) {
+///     state.set_cursor(x);
+/// }
+/// # }
+/// ```
+///
+/// [`Dataset`]: super::Dataset
+#[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChartState {
+    pub(crate) cursor: Option<f64>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) transform: Option<Transform>,
+}
+
+/// The mapping between the data coordinates and the cells of the graph of a rendered chart.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub(crate) struct Transform {
+    /// The area where the data is drawn
+    pub(crate) graph_area: Rect,
+    /// The bounds of the X axis, transformed by its scale
+    pub(crate) x_bounds: [f64; 2],
+    /// The scale of the X axis
+    pub(crate) x_scale: AxisScale,
+    /// The bounds of the Y axis, transformed by its scale
+    pub(crate) y_bounds: [f64; 2],
+    /// The scale of the Y axis
+    pub(crate) y_scale: AxisScale,
+}
+
+impl Transform {
+    /// Returns the cell displaying the point, if it is within the bounds of the axes.
+    fn data_to_cell(&self, (x, y): (f64, f64)) -> Option<Position> {
+        let area = self.graph_area;
+        let column = value_to_cell(self.x_scale.transform(x), self.x_bounds, area.width)?;
+        let row = value_to_row(self.y_scale.transform(y), self.y_bounds, area.height)?;
+        Some(Position::new(area.x + column, area.bottom() - 1 - row))
+    }
+
+    /// Returns the data coordinates of the cell, if it is in the graph area.
+    fn cell_to_data(&self, position: Position) -> Option<(f64, f64)> {
+        let area = self.graph_area;
+        if !area.contains(position) {
+            return None;
+        }
+        let x = cell_to_value(position.x - area.x, self.x_bounds, area.width);
+        let y = cell_to_value(area.bottom() - 1 - position.y, self.y_bounds, area.height);
+        Some((self.x_scale.inverse(x), self.y_scale.inverse(y)))
+    }
+}
+
+impl ChartState {
+    /// Sets the position of the cursor, in data coordinates
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_cursor<T: Into<Option<f64>>>(mut self, cursor: T) -> Self {
+        self.cursor = cursor.into();
+        self
+    }
+
+    /// Position of the cursor on the X axis, in data coordinates
+    pub const fn cursor(&self) -> Option<f64> {
+        self.cursor
+    }
+
+    /// Sets the position of the cursor on the X axis, in data coordinates
+    ///
+    /// Set it to `None` to hide the cursor.
+    pub fn set_cursor<T: Into<Option<f64>>>(&mut self, cursor: T) {
+        self.cursor = cursor.into();
+    }
+
+    /// Returns the area where the data was drawn during the last render, excluding the axes, the
+    /// labels and the block of the chart.
+    ///
+    /// Returns `None` if the chart has not been rendered yet, or was too small to draw its data.
+    pub fn graph_area(&self) -> Option<Rect> {
+        self.transform.map(|transform| transform.graph_area)
+    }
+
+    /// Returns the data coordinates of the given cell of the terminal, as drawn during the last
+    /// render
+    ///
+    /// The `y` coordinate is the value of the primary Y axis of the chart, on the left.
+    ///
+    /// Returns `None` if the cell is not in the [graph area](Self::graph_area).
+    pub fn cell_to_data(&self, position: Position) -> Option<(f64, f64)> {
+        self.transform?.cell_to_data(position)
+    }
+
+    /// Returns the cell of the terminal displaying the given point, as drawn during the last render
+    ///
+    /// The `y` coordinate is a value of the primary Y axis of the chart, on the left. Returns `None`
+    /// if the point is out of the bounds of the axes.
+    pub fn data_to_cell(&self, point: (f64, f64)) -> Option<Position> {
+        self.transform?.data_to_cell(point)
+    }
+}
+
+impl Chart<'_> {
+    /// Returns the column of the cursor, if it is within the bounds of the X axis.
+    fn cursor_column(axes: &ChartAxes, graph_area: Rect, state: &ChartState) -> Option<u16> {
+        let column = axes.x.value_to_column(state.cursor?, graph_area.width)?;
+        Some(graph_area.x + column)
+    }
+
+    /// Renders the vertical crosshair at the cursor, behind the datasets.
+    pub(crate) fn render_crosshair(
+        &self,
+        buf: &mut Buffer,
+        axes: &ChartAxes,
+        graph_area: Rect,
+        state: &ChartState,
+    ) {
+        let Some(x) = Self::cursor_column(axes, graph_area, state) else {
+            return;
+        };
+        for y in graph_area.top()..graph_area.bottom() {
+            buf[(x, y)]
+                .set_symbol(symbols::line::VERTICAL)
+                .set_style(self.cursor_style);
+        }
+    }
+
+    /// Highlights the point of each dataset nearest to the cursor, and renders their values in a
+    /// tooltip next to the crosshair.
+    pub(crate) fn render_cursor(
+        &self,
+        buf: &mut Buffer,
+        series: &[Series],
+        axes: &ChartAxes,
+        graph_area: Rect,
+        state: &ChartState,
+    ) {
+        let (Some(cursor), Some(transform), Some(column)) = (
+            state.cursor,
+            state.transform,
+            Self::cursor_column(axes, graph_area, state),
+        ) else {
+            return;
+        };
+        let x_scale = self.x_axis.scale;
+        let target = x_scale.transform(cursor);
+        let mut lines = vec![Line::from(axes.x.format_value(cursor, graph_area.width))];
+        let mut highlights = Vec::new();
+        for (dataset, series) in self.datasets.iter().zip(series) {
+            let y_axis = axes.dataset_y_axis(dataset);
+            let transform = Transform {
+                y_bounds: y_axis.canvas_bounds(),
+                y_scale: y_axis.axis.scale,
+                ..transform
+            };
+            // the nearest point horizontally, among the points which are drawn
+            let nearest = series
+                .data
+                .iter()
+                .enumerate()
+                .filter(|(_, &point)| transform.data_to_cell(point).is_some())
+                .min_by(|(_, a), (_, b)| {
+                    let distance = |x: f64| (x_scale.transform(x) - target).abs();
+                    distance(a.0).total_cmp(&distance(b.0))
+                });
+            let Some((index, &point)) = nearest else {
+                continue;
+            };
+            highlights.extend(transform.data_to_cell(point));
+            // the tooltip displays the value of the dataset, not its stacked value
+            let value = y_axis.format_value(dataset.data[index].1, graph_area.height);
+            let line = match &dataset.name {
+                Some(name) => {
+                    let mut line = name.clone().patch_style(dataset.style);
+                    line.push_span(Span::raw(format!(": {value}")));
+                    line
+                }
+                None => Line::styled(value, dataset.style),
+            };
+            lines.push(line);
+        }
+
+        let text = Text::from(lines);
+        // the content and the borders of the tooltip
+        let size = content_size(&text);
+        let size = Size::new(size.width + 2, size.height + 2);
+        // the tooltip is on the right of the crosshair, or on its left if there isn't enough room
+        let x = if column + 1 + size.width <= graph_area.right() {
+            column + 1
+        } else {
+            column.saturating_sub(size.width).max(graph_area.x)
+        };
+        // the tooltip is at the top of the graph, or at its bottom if it would hide the highlighted
+        // points
+        let middle = graph_area.y + graph_area.height / 2;
+        let y = if highlights.iter().any(|position| position.y < middle) {
+            graph_area.bottom().saturating_sub(size.height)
+        } else {
+            graph_area.y
+        };
+        let tooltip = Rect::new(x, y, size.width, size.height).clamp(graph_area);
+        Popup::new(text).style(self.style).render(tooltip, buf);
+        for position in highlights {
+            if !tooltip.contains(position) {
+                buf[position].set_style(self.highlight_style);
+            }
+        }
+    }
+}
@@ -0,0 +1,255 @@
+use std::{fmt, mem, sync::Arc};
+
+/// The format of the labels generated for the ticks of an [`Axis`]
+///
+/// The labels are generated when the ticks of the axis are computed with [`Axis::ticks`]. The
+/// number of decimals of the labels is derived from the step between two ticks, so that two
+/// consecutive labels are always different.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::widgets::{Axis, LabelFormat};
+///
+/// let axis = Axis::default().ticks(5).label_format(LabelFormat::Si);
+/// assert_eq!(LabelFormat::Si.format(1500.0, 500.0), "1.5k");
+/// assert_eq!(LabelFormat::Percent.format(0.25, 0.05), "25%");
+/// assert_eq!(LabelFormat::Duration.format(90.0, 30.0), "1.5m");
+/// ```
+///
+/// [`Axis`]: super::Axis
+/// [`Axis::ticks`]: super::Axis::ticks
+#[derive(Default, Clone)]
+pub enum LabelFormat {
+    /// Plain numbers, e.g. `2.5`. This is the default.
+    #[default]
+    Number,
+    /// Numbers with a metric prefix, e.g. `1.5k`, `2M` or `300m`
+    Si,
+    /// Ratios displayed as percentages, e.g. `0.25` is displayed as `25%`
+    Percent,
+    /// Durations in seconds, displayed with the largest fitting unit, e.g. `250ms`, `1.5m` or `2h`
+    Duration,
+    /// Labels formatted by the given function, which is called with the value of each tick and the
+    /// step between two ticks
+    ///
+    /// Two custom formats are equal if they share the same function. See [`LabelFormat::custom`].
+    Custom(Arc<dyn Fn(f64, f64) -> String + Send + Sync>),
+}
+
+/// The metric prefixes, from the largest to the smallest.
+const SI_PREFIXES: [(f64, &str); 8] = [
+    (1e12, "T"),
+    (1e9, "G"),
+    (1e6, "M"),
+    (1e3, "k"),
+    (1.0, ""),
+    (1e-3, "m"),
+    (1e-6, "µ"),
+    (1e-9, "n"),
+];
+
+/// The units of durations, in seconds, from the largest to the smallest.
+const DURATION_UNITS: [(f64, &str); 7] = [
+    (86400.0, "d"),
+    (3600.0, "h"),
+    (60.0, "m"),
+    (1.0, "s"),
+    (1e-3, "ms"),
+    (1e-6, "µs"),
+    (1e-9, "ns"),
+];
+
+impl fmt::Debug for LabelFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Number => f.write_str("Number"),
+            Self::Si => f.write_str("Si"),
+            Self::Percent => f.write_str("Percent"),
+            Self::Duration => f.write_str("Duration"),
+            Self::Custom(_) => f.write_str("Custom(..)"),
+        }
+    }
+}
+
+impl PartialEq for LabelFormat {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Custom(a), Self::Custom(b)) => Arc::ptr_eq(a, b),
+            _ => mem::discriminant(self) == mem::discriminant(other),
+        }
+    }
+}
+
+impl LabelFormat {
+    /// Creates a [`LabelFormat::Custom`] format from a function called with the value of each tick
+    /// and the step between two ticks
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::LabelFormat;
+    ///
+    /// let unit = "°C";
+    /// let format = LabelFormat::custom(move |value, _| format!("{value}{unit}"));
+    /// assert_eq!(format.format(21.5, 0.5), "21.5°C");
+    /// ```
+    pub fn custom<F>(format: F) -> Self
+    where
+        F: Fn(f64, f64) -> String + Send + Sync + 'static,
+    {
+        Self::Custom(Arc::new(format))
+    }
+
+    /// Formats the value of a tick, given the step between two ticks.
+    pub fn format(&self, value: f64, step: f64) -> String {
+        match self {
+            Self::Number => format_number(value, step),
+            Self::Si => format_scaled(value, step, &SI_PREFIXES),
+            Self::Percent => format!("{}%", format_number(value * 100.0, step * 100.0)),
+            Self::Duration => format_scaled(value, step, &DURATION_UNITS),
+            Self::Custom(format) => format(value, step),
+        }
+    }
+}
+
+/// Returns the number of decimals needed to display values which are multiples of the step.
+fn decimals(step: f64) -> usize {
+    if !step.is_finite() || step <= 0.0 {
+        return 0;
+    }
+    // at most 10 decimals, as the step is approximate for tiny ranges
+    (0..=10_u8)
+        .find(|&decimals| {
+            let scaled = step * 10_f64.powi(i32::from(decimals));
+            (scaled - scaled.round()).abs() < 1e-6 * scaled.max(1.0)
+        })
+        .map_or(10, usize::from)
+}
+
+/// Formats the value with the number of decimals needed by the step.
+fn format_number(value: f64, step: f64) -> String {
+    let decimals = decimals(step);
+    let label = format!("{value:.decimals$}");
+    // avoid labels such as "-0.0" for values slightly below zero
+    if label.trim_start_matches(['-', '0', '.']).is_empty() {
+        label.trim_start_matches('-').to_string()
+    } else {
+        label
+    }
+}
+
+/// Formats the value scaled to the largest unit smaller than it, with the trailing zeros of the
+/// decimals removed, e.g. `1.5k` or `1k`.
+fn format_scaled(value: f64, step: f64, units: &[(f64, &str)]) -> String {
+    let magnitude = value.abs();
+    if magnitude == 0.0 || !magnitude.is_finite() {
+        return format_number(value, 1.0);
+    }
+    let (scale, unit) = units
+        .iter()
+        .find(|(scale, _)| magnitude >= *scale * (1.0 - 1e-9))
+        .or_else(|| units.last())
+        .copied()
+        .unwrap_or((1.0, ""));
+    let label = format_number(value / scale, step / scale);
+    let label = if label.contains('.') {
+        label.trim_end_matches('0').trim_end_matches('.')
+    } else {
+        &label
+    };
+    format!("{label}{unit}")
+}
+
+/// The abbreviated names of the months.
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// Formats a timestamp in seconds since the Unix epoch as a UTC time of day, given the step
+/// between two ticks in seconds, e.g. `14:30:15` or `14:30`. The ticks at midnight, and all the
+/// ticks of steps of a day or more, are formatted as a date, e.g. `Jan 05`.
+pub(crate) fn format_time(value: f64, step: f64) -> String {
+    const DAY: i64 = 86400;
+    let seconds = value.round() as i64;
+    let (days, time) = (seconds.div_euclid(DAY), seconds.rem_euclid(DAY));
+    if step >= DAY as f64 || (time == 0 && step >= 60.0) {
+        let (month, day) = month_day(days);
+        return format!("{} {day:02}", MONTHS[month - 1]);
+    }
+    let (hours, minutes, seconds) = (time / 3600, time % 3600 / 60, time % 60);
+    if step >= 60.0 {
+        format!("{hours:02}:{minutes:02}")
+    } else {
+        format!("{hours:02}:{minutes:02}:{seconds:02}")
+    }
+}
+
+/// Returns the month, from 1 to 12, and the day of the month of the given number of days since
+/// the Unix epoch, in the proleptic Gregorian calendar.
+///
+/// This is the `civil_from_days` algorithm of Howard Hinnant, without the year.
+const fn month_day(days: i64) -> (usize, i64) {
+    // shift the epoch to 0000-03-01, so that leap days are at the end of the 400 years eras
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    // months starting from March
+    let month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month + 2) / 5 + 1;
+    let month = if month < 10 { month + 3 } else { month - 9 };
+    (month as usize, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::integer(LabelFormat::Number, 20.0, 10.0, "20")]
+    #[case::decimals(LabelFormat::Number, 0.25, 0.05, "0.25")]
+    #[case::negative_zero(LabelFormat::Number, -1e-17, 0.1, "0.0")]
+    #[case::si_kilo(LabelFormat::Si, 1500.0, 500.0, "1.5k")]
+    #[case::si_round(LabelFormat::Si, 2e6, 1e6, "2M")]
+    #[case::si_milli(LabelFormat::Si, 0.3, 0.1, "300m")]
+    #[case::si_unit(LabelFormat::Si, 500.0, 500.0, "500")]
+    #[case::si_zero(LabelFormat::Si, 0.0, 500.0, "0")]
+    #[case::percent(LabelFormat::Percent, 0.5, 0.25, "50%")]
+    #[case::percent_decimals(LabelFormat::Percent, 0.125, 0.025, "12.5%")]
+    #[case::duration_milliseconds(LabelFormat::Duration, 0.25, 0.05, "250ms")]
+    #[case::duration_minutes(LabelFormat::Duration, 90.0, 30.0, "1.5m")]
+    #[case::duration_hours(LabelFormat::Duration, 7200.0, 3600.0, "2h")]
+    fn format(
+        #[case] label_format: LabelFormat,
+        #[case] value: f64,
+        #[case] step: f64,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(label_format.format(value, step), expected);
+    }
+
+    #[rstest]
+    #[case::seconds(45_296.0, 15.0, "12:34:56")]
+    #[case::minutes(45_240.0, 60.0, "12:34")]
+    #[case::midnight(86_400.0 * 4.0, 3600.0, "Jan 05")]
+    #[case::days(951_782_400.0, 86_400.0, "Feb 29")]
+    #[case::before_epoch(-86_400.0, 86_400.0, "Dec 31")]
+    fn format_time(#[case] value: f64, #[case] step: f64, #[case] expected: &str) {
+        assert_eq!(super::format_time(value, step), expected);
+    }
+
+    #[test]
+    fn custom() {
+        let format = LabelFormat::custom(|value, _| format!("{value}°C"));
+        assert_eq!(format.format(21.5, 0.5), "21.5°C");
+        assert_eq!(format, format.clone());
+        assert_ne!(format, LabelFormat::custom(|value, _| format!("{value}°C")));
+        assert_ne!(format, LabelFormat::Number);
+    }
+}
@@ -0,0 +1,682 @@
+use std::borrow::Cow;
+
+use ratatui_core::text::Line;
+use strum::{Display, EnumString};
+
+use super::{
+    format::{format_time, LabelFormat},
+    series::Series,
+    Axis, Chart, Dataset, GraphType,
+};
+
+/// Returns a "nice" step between the ticks of an axis covering the given range with about `count`
+/// ticks: 1, 2 or 5 times a power of ten.
+pub(crate) fn nice_step(range: f64, count: usize) -> f64 {
+    let intervals = count.saturating_sub(1).max(1) as f64;
+    let raw = range.abs() / intervals;
+    if !raw.is_finite() || raw == 0.0 {
+        return 1.0;
+    }
+    let magnitude = 10_f64.powf(raw.log10().floor());
+    let fraction = raw / magnitude;
+    let nice = if fraction <= 1.0 {
+        1.0
+    } else if fraction <= 2.0 {
+        2.0
+    } else if fraction <= 5.0 {
+        5.0
+    } else {
+        10.0
+    };
+    nice * magnitude
+}
+
+/// The maximum number of ticks of an axis, far more than can be displayed.
+const MAX_TICKS: f64 = 1000.0;
+
+/// Returns the multiples of the step within the bounds, or none if there are too many of them.
+pub(crate) fn ticks(bounds: [f64; 2], step: f64) -> Vec<f64> {
+    let [min, max] = bounds;
+    if !(step > 0.0 && min.is_finite() && max.is_finite()) {
+        return Vec::new();
+    }
+    // tolerate rounding errors on the bounds, which are often multiples of the step themselves
+    let epsilon = step * 1e-9;
+    let first = ((min - epsilon) / step).ceil();
+    let last = ((max + epsilon) / step).floor();
+    let count = last - first + 1.0;
+    // the indexes of the ticks can't be told apart when they are too large for an `f64`
+    if !(count.is_finite() && count <= MAX_TICKS) || first + 1.0 <= first {
+        return Vec::new();
+    }
+    // multiply instead of accumulating the step, to avoid accumulating rounding errors
+    (0..count.max(0.0) as usize)
+        .map(|index| (first + index as f64) * step)
+        .collect()
+}
+
+/// Returns the smallest and the largest of the finite values, if any.
+pub(crate) fn data_bounds(values: impl Iterator<Item = f64>) -> Option<[f64; 2]> {
+    values
+        .filter(|value| value.is_finite())
+        .fold(None, |bounds, value| match bounds {
+            None => Some([value, value]),
+            Some([min, max]) => Some([min.min(value), max.max(value)]),
+        })
+}
+
+/// Returns the offset of the cell displaying the value, from the start of an axis of the given
+/// length in cells, or `None` if the value is out of the bounds.
+pub(crate) fn value_to_cell(value: f64, bounds: [f64; 2], length: u16) -> Option<u16> {
+    let [min, max] = bounds;
+    let range = max - min;
+    // tolerate rounding errors on the values computed from the bounds, such as the ticks
+    let epsilon = range * 1e-9;
+    if length == 0 || range <= 0.0 || !(min - epsilon..=max + epsilon).contains(&value) {
+        return None;
+    }
+    // truncated as the points of the canvas, so that the ticks are aligned with the data
+    let offset = ((value - min) / range * f64::from(length - 1) + 1e-9).floor();
+    Some((offset.max(0.0) as u16).min(length - 1))
+}
+
+/// Returns the offset of the row displaying the value, from the bottom of a vertical axis of the
+/// given length in cells, or `None` if the value is out of the bounds.
+///
+/// The rows of the canvas are counted from the top, so the value is truncated towards the top.
+pub(crate) fn value_to_row(value: f64, bounds: [f64; 2], length: u16) -> Option<u16> {
+    let [min, max] = bounds;
+    let offset_from_top = value_to_cell(-value, [-max, -min], length)?;
+    Some(length - 1 - offset_from_top)
+}
+
+/// Returns the value displayed by the cell at the given offset from the start of an axis of the
+/// given length in cells. This is the inverse of [`value_to_cell`].
+pub(crate) fn cell_to_value(offset: u16, bounds: [f64; 2], length: u16) -> f64 {
+    let [min, max] = bounds;
+    if length <= 1 {
+        return min;
+    }
+    min + (max - min) * f64::from(offset) / f64::from(length - 1)
+}
+
+/// The scale of an [`Axis`], i.e. how the values are mapped to positions along the axis
+///
+/// The values of the datasets are transformed by the scale of each axis before being drawn, and the
+/// ticks generated with [`Axis::ticks`] are adapted to the scale. The bounds of the axis are always
+/// given as values, not as transformed values.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::widgets::{Axis, AxisScale, LabelFormat};
+///
+/// // latencies from 1ms to 10s
+/// let axis = Axis::default()
+///     .scale(AxisScale::Log10)
+///     .bounds([0.001, 10.0])
+///     .ticks(5)
+///     .label_format(LabelFormat::Duration);
+/// ```
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum AxisScale {
+    /// Values are evenly spaced. This is the default.
+    #[default]
+    Linear,
+    /// Powers of ten are evenly spaced, e.g. 1, 10, 100 and 1000
+    ///
+    /// Non-positive values are out of the domain of the scale: they are not drawn, and the lines
+    /// of [`GraphType::Line`] datasets are interrupted around them. The ticks are powers of ten.
+    Log10,
+    /// Powers of two are evenly spaced, e.g. 1, 2, 4 and 8
+    ///
+    /// Non-positive values are handled as with [`AxisScale::Log10`]. The ticks are powers of two.
+    Log2,
+    /// Values are timestamps in seconds since the Unix epoch, evenly spaced
+    ///
+    /// The ticks are aligned on calendar units, such as minutes, hours or days, and labelled with
+    /// the UTC time of day, or the date for the ticks at midnight, unless a
+    /// [`LabelFormat::Custom`] format is set.
+    ///
+    /// [`LabelFormat::Custom`]: super::LabelFormat::Custom
+    Time,
+}
+
+/// The steps between the ticks of a time axis, in seconds, up to a week.
+const TIME_STEPS: [f64; 20] = [
+    1.0, 2.0, 5.0, 10.0, 15.0, 30.0, 60.0, 120.0, 300.0, 600.0, 900.0, 1800.0, 3600.0, 7200.0,
+    10800.0, 21600.0, 43200.0, 86400.0, 172_800.0, 604_800.0,
+];
+
+impl AxisScale {
+    /// Returns the transformed value drawn on the canvas, or NaN if the value is out of the
+    /// domain of the scale.
+    pub(crate) fn transform(self, value: f64) -> f64 {
+        match self {
+            Self::Log10 if value > 0.0 => value.log10(),
+            Self::Log2 if value > 0.0 => value.log2(),
+            Self::Log10 | Self::Log2 => f64::NAN,
+            Self::Linear | Self::Time => value,
+        }
+    }
+
+    /// Returns the value of a transformed value.
+    pub(crate) fn inverse(self, value: f64) -> f64 {
+        match self {
+            Self::Log10 => 10_f64.powf(value),
+            Self::Log2 => value.exp2(),
+            Self::Linear | Self::Time => value,
+        }
+    }
+
+    /// Returns true if the scale is logarithmic.
+    pub(crate) const fn is_log(self) -> bool {
+        matches!(self, Self::Log10 | Self::Log2)
+    }
+}
+
+/// An [`Axis`] as it is rendered, with its bounds, ticks and labels computed from the data when they
+/// are automatic
+///
+/// This is computed on each render, so that the axis itself is left as configured.
+pub(crate) struct ResolvedAxis<'r, 'a> {
+    /// The configuration of the axis
+    pub(crate) axis: &'r Axis<'a>,
+    /// The bounds of the axis, computed from the data if they are automatic
+    bounds: [f64; 2],
+    /// The values of the generated ticks, if any
+    ticks: Vec<f64>,
+    /// The labels of the axis, or the labels generated at the ticks if it has none
+    labels: Cow<'r, [Line<'a>]>,
+}
+
+impl<'a> Axis<'a> {
+    /// Returns the bounds of the axis, unless they are computed from the data or empty.
+    pub(crate) fn fixed_bounds(&self) -> Option<[f64; 2]> {
+        (!self.auto_bounds && self.bounds[1] - self.bounds[0] > 0.0).then_some(self.bounds)
+    }
+
+    /// Returns the axis spanning the given bounds, with its ticks and their labels if they are
+    /// generated, for the widgets computing the bounds of their axis themselves.
+    pub(crate) fn with_bounds(&self, bounds: [f64; 2]) -> ResolvedAxis<'_, 'a> {
+        ResolvedAxis::new(self, bounds, false)
+    }
+
+    /// Returns the axis with its bounds computed from the values of the data if they are
+    /// automatic, then its ticks and their labels if they are generated.
+    ///
+    /// `include_zero` extends the automatic bounds to zero, e.g. for the bars of a bar chart. It is
+    /// ignored on logarithmic scales.
+    pub(crate) fn resolve(
+        &self,
+        values: impl Iterator<Item = f64>,
+        include_zero: bool,
+    ) -> ResolvedAxis<'_, 'a> {
+        let scale = self.scale;
+        let mut bounds = self.bounds;
+        if self.auto_bounds {
+            // the bounds and the padding are computed on the transformed values
+            let values = values.map(|value| scale.transform(value));
+            if let Some([mut min, mut max]) = data_bounds(values) {
+                if include_zero && !scale.is_log() {
+                    min = min.min(0.0);
+                    max = max.max(0.0);
+                }
+                if max <= min {
+                    let delta = if min == 0.0 { 1.0 } else { min.abs() / 10.0 };
+                    min -= delta;
+                    max += delta;
+                }
+                let padding = (max - min) * self.bounds_padding;
+                bounds = [min - padding, max + padding].map(|bound| scale.inverse(bound));
+            }
+        }
+        ResolvedAxis::new(self, bounds, self.auto_bounds)
+    }
+
+    /// Formats a value with the label format of the axis, given the step between two ticks.
+    ///
+    /// The values of a time axis are formatted as times unless a custom format is set.
+    pub(crate) fn format_label(&self, value: f64, step: f64) -> String {
+        match (self.scale, &self.label_format) {
+            (AxisScale::Time, LabelFormat::Number) => format_time(value, step),
+            (_, label_format) => label_format.format(value, step),
+        }
+    }
+}
+
+impl<'r, 'a> ResolvedAxis<'r, 'a> {
+    /// Returns the axis with the given bounds and its generated ticks, extending the bounds to
+    /// the ticks around them if they are computed from the data.
+    fn new(axis: &'r Axis<'a>, bounds: [f64; 2], auto_bounds: bool) -> Self {
+        let mut resolved = Self {
+            axis,
+            bounds,
+            ticks: Vec::new(),
+            labels: Cow::Borrowed(&axis.labels),
+        };
+        let Some(count) = axis.tick_count else {
+            return resolved;
+        };
+        let ticks = match axis.scale {
+            AxisScale::Linear => {
+                resolved.linear_ticks(nice_step(resolved.range(), count), auto_bounds)
+            }
+            AxisScale::Log10 | AxisScale::Log2 => resolved.log_ticks(count, auto_bounds),
+            AxisScale::Time => {
+                resolved.linear_ticks(time_step(resolved.range(), count), auto_bounds)
+            }
+        };
+        // the labels given to the axis are displayed instead of the generated ones
+        if axis.labels.is_empty() {
+            resolved.labels = ticks
+                .iter()
+                .map(|&(tick, step)| axis.format_label(tick, step).into())
+                .collect();
+        }
+        resolved.ticks = ticks.into_iter().map(|(tick, _)| tick).collect();
+        resolved
+    }
+
+    /// Returns the bounds of the axis.
+    pub(crate) const fn bounds(&self) -> [f64; 2] {
+        self.bounds
+    }
+
+    /// Returns the bounds of the axis transformed by its scale, i.e. the bounds of the canvas.
+    pub(crate) fn canvas_bounds(&self) -> [f64; 2] {
+        self.bounds.map(|bound| self.axis.scale.transform(bound))
+    }
+
+    /// Returns the labels displayed along the axis.
+    pub(crate) fn labels(&self) -> &[Line<'a>] {
+        &self.labels
+    }
+
+    /// Returns true if the labels are generated, in which case each of them is displayed at the
+    /// position of its tick.
+    pub(crate) fn has_tick_labels(&self) -> bool {
+        self.axis.labels.is_empty() && !self.ticks.is_empty()
+    }
+
+    /// Returns the offset of the column displaying each tick, from the left of a horizontal axis of
+    /// the given length in cells. The ticks out of the bounds are skipped.
+    pub(crate) fn tick_columns(&self, length: u16) -> impl Iterator<Item = u16> + '_ {
+        self.tick_cells(length, value_to_cell)
+    }
+
+    /// Returns the offset of the row displaying each tick, from the bottom of a vertical axis of
+    /// the given length in cells. The ticks out of the bounds are skipped.
+    pub(crate) fn tick_rows(&self, length: u16) -> impl Iterator<Item = u16> + '_ {
+        self.tick_cells(length, value_to_row)
+    }
+
+    fn tick_cells(
+        &self,
+        length: u16,
+        to_cell: fn(f64, [f64; 2], u16) -> Option<u16>,
+    ) -> impl Iterator<Item = u16> + '_ {
+        let (scale, bounds) = (self.axis.scale, self.canvas_bounds());
+        self.ticks
+            .iter()
+            .filter_map(move |&tick| to_cell(scale.transform(tick), bounds, length))
+    }
+
+    /// Returns the offset of the cell displaying each generated label, along an axis of the given
+    /// length in cells. The labels out of the bounds are skipped.
+    pub(crate) fn tick_label_cells(
+        &self,
+        length: u16,
+        to_cell: fn(f64, [f64; 2], u16) -> Option<u16>,
+    ) -> impl Iterator<Item = (u16, &Line<'a>)> + '_ {
+        let (scale, bounds) = (self.axis.scale, self.canvas_bounds());
+        self.ticks
+            .iter()
+            .zip(self.labels.iter())
+            .filter_map(move |(&tick, label)| {
+                let offset = to_cell(scale.transform(tick), bounds, length)?;
+                Some((offset, label))
+            })
+    }
+
+    /// Returns the offset of the column displaying the value, from the left of a horizontal axis of
+    /// the given length in cells, if it is within the bounds.
+    pub(crate) fn value_to_column(&self, value: f64, length: u16) -> Option<u16> {
+        value_to_cell(
+            self.axis.scale.transform(value),
+            self.canvas_bounds(),
+            length,
+        )
+    }
+
+    /// Returns true if the axis has labels to display.
+    pub(crate) fn has_labels(&self) -> bool {
+        !self.labels.is_empty()
+    }
+
+    /// Returns the width of the widest label of the axis.
+    pub(crate) fn labels_width(&self) -> u16 {
+        self.labels
+            .iter()
+            .map(Line::width)
+            .max()
+            .unwrap_or_default() as u16
+    }
+
+    /// Formats a value with the precision of a cell of an axis of the given length in cells.
+    pub(crate) fn format_value(&self, value: f64, length: u16) -> String {
+        let step = if self.axis.scale.is_log() {
+            // three significant digits
+            10_f64.powf(value.abs().log10().floor() - 2.0)
+        } else {
+            // about the precision of half a cell
+            nice_step(self.range(), usize::from(length.max(1)) * 2)
+        };
+        self.axis.format_label(value, step)
+    }
+
+    /// Returns the range of the bounds.
+    fn range(&self) -> f64 {
+        self.bounds[1] - self.bounds[0]
+    }
+
+    /// Returns the multiples of the step within the bounds, with the step, after extending the
+    /// automatic bounds to the ticks around the data.
+    fn linear_ticks(&mut self, step: f64, auto_bounds: bool) -> Vec<(f64, f64)> {
+        let [min, max] = self.bounds;
+        if auto_bounds {
+            self.bounds = [(min / step).floor() * step, (max / step).ceil() * step];
+        }
+        ticks(self.bounds, step)
+            .into_iter()
+            .map(|tick| (tick, step))
+            .collect()
+    }
+
+    /// Returns at most `count` powers of the base of the logarithmic scale within the bounds, with
+    /// the step used to format each of them, i.e. the value itself, after extending the automatic
+    /// bounds to the powers around the data.
+    fn log_ticks(&mut self, count: usize, auto_bounds: bool) -> Vec<(f64, f64)> {
+        let scale = self.axis.scale;
+        let [min, max] = self.canvas_bounds();
+        if !(min.is_finite() && max.is_finite()) {
+            return Vec::new();
+        }
+        if auto_bounds {
+            self.bounds = [min.floor(), max.ceil()].map(|bound| scale.inverse(bound));
+        }
+        let [min, max] = self.canvas_bounds();
+        let first = (min - 1e-9).ceil() as i32;
+        let last = (max + 1e-9).floor() as i32;
+        let powers = (last - first + 1).max(0) as usize;
+        // skip some powers if there are too many of them
+        let skip = powers.div_ceil(count.max(1)).max(1);
+        (first..=last)
+            .step_by(skip)
+            .map(|exponent| {
+                let tick = scale.inverse(f64::from(exponent));
+                (tick, tick)
+            })
+            .collect()
+    }
+}
+
+/// Returns the step between the ticks of a time axis covering the given range with about `count`
+/// ticks: a calendar unit up to a week, or a "nice" number of days.
+fn time_step(range: f64, count: usize) -> f64 {
+    const DAY: f64 = 86400.0;
+    let raw = range.abs() / count.saturating_sub(1).max(1) as f64;
+    TIME_STEPS
+        .into_iter()
+        .find(|&step| step >= raw)
+        .unwrap_or_else(|| nice_step(range / DAY, count) * DAY)
+}
+
+/// The axes of a [`Chart`] as they are rendered
+pub(crate) struct ChartAxes<'r, 'a> {
+    pub(crate) x: ResolvedAxis<'r, 'a>,
+    pub(crate) y: ResolvedAxis<'r, 'a>,
+    pub(crate) secondary_y: Option<ResolvedAxis<'r, 'a>>,
+}
+
+impl<'r, 'a> ChartAxes<'r, 'a> {
+    /// Returns the Y axis the dataset is plotted against.
+    pub(crate) const fn dataset_y_axis(&self, dataset: &Dataset) -> &ResolvedAxis<'r, 'a> {
+        match &self.secondary_y {
+            Some(axis) if dataset.secondary_axis => axis,
+            _ => &self.y,
+        }
+    }
+}
+
+impl<'a> Chart<'a> {
+    /// Returns the axes of the chart, with their automatic bounds and labels computed from the
+    /// series of its datasets.
+    pub(crate) fn axes(&self, series: &[Series]) -> ChartAxes<'_, 'a> {
+        let x_values = series.iter().flat_map(|series| series.data.iter());
+        ChartAxes {
+            x: self.x_axis.resolve(x_values.map(|&(x, _)| x), false),
+            y: self.resolve_y_axis(&self.y_axis, series, false),
+            secondary_y: self
+                .secondary_y_axis
+                .as_ref()
+                .map(|axis| self.resolve_y_axis(axis, series, true)),
+        }
+    }
+
+    /// Returns the Y axis resolved from the series of the datasets plotted against it.
+    fn resolve_y_axis<'r>(
+        &self,
+        axis: &'r Axis<'a>,
+        series: &[Series],
+        secondary: bool,
+    ) -> ResolvedAxis<'r, 'a> {
+        let datasets = || {
+            self.datasets
+                .iter()
+                .zip(series)
+                .filter(move |(dataset, _)| self.uses_secondary_axis(dataset) == secondary)
+        };
+        let has_bars = datasets().any(|(dataset, _)| dataset.graph_type == GraphType::Bar);
+        let values = datasets().flat_map(|(_, series)| series.data.iter().map(|&(_, y)| y));
+        // the areas are filled down to their baseline
+        let baselines = datasets()
+            .filter(|(dataset, _)| dataset.graph_type == GraphType::Area)
+            .map(|(dataset, _)| if self.stacked { 0.0 } else { dataset.baseline });
+        axis.resolve(values.chain(baselines), has_bars)
+    }
+
+    /// Returns the data transformed by the scales of the X axis and the given Y axis, borrowed if
+    /// both axes are linear.
+    ///
+    /// The points out of the domain of a scale have NaN coordinates.
+    pub(crate) fn scaled_data<'d>(
+        &self,
+        data: &'d [(f64, f64)],
+        y_axis: &Axis,
+    ) -> Cow<'d, [(f64, f64)]> {
+        let (x_scale, y_scale) = (self.x_axis.scale, y_axis.scale);
+        if x_scale == AxisScale::Linear && y_scale == AxisScale::Linear {
+            return Cow::Borrowed(data);
+        }
+        data.iter()
+            .map(|&(x, y)| (x_scale.transform(x), y_scale.transform(y)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(10.0, 6, 2.0)]
+    #[case(10.0, 3, 5.0)]
+    #[case(100.0, 11, 10.0)]
+    #[case(0.7, 5, 0.2)]
+    #[case(0.0, 5, 1.0)]
+    fn nice_step(#[case] range: f64, #[case] count: usize, #[case] expected: f64) {
+        assert!((super::nice_step(range, count) - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn ticks() {
+        assert_eq!(super::ticks([0.0, 10.0], 5.0), [0.0, 5.0, 10.0]);
+        assert_eq!(super::ticks([-3.0, 7.0], 2.0), [-2.0, 0.0, 2.0, 4.0, 6.0]);
+        assert_eq!(super::ticks([0.0, 0.1 + 0.2], 0.1).len(), 4);
+        assert_eq!(super::ticks([0.0, 1.0], 0.0), Vec::<f64>::new());
+        assert_eq!(super::ticks([0.0, 1e6], 1.0), Vec::<f64>::new());
+        assert_eq!(super::ticks([1e18, 1e18 + 256.0], 64.0), Vec::<f64>::new());
+    }
+
+    #[test]
+    fn data_bounds() {
+        let values = [3.0, f64::NAN, -1.0, 2.0];
+        assert_eq!(super::data_bounds(values.into_iter()), Some([-1.0, 3.0]));
+        assert_eq!(super::data_bounds(std::iter::empty()), None);
+    }
+
+    #[allow(clippy::float_cmp)]
+    #[test]
+    fn cell_to_value() {
+        assert_eq!(super::cell_to_value(5, [0.0, 10.0], 11), 5.0);
+        assert_eq!(super::cell_to_value(3, [0.0, 10.0], 1), 0.0);
+        let offset = super::value_to_cell(7.0, [0.0, 10.0], 11).unwrap();
+        assert_eq!(super::cell_to_value(offset, [0.0, 10.0], 11), 7.0);
+    }
+
+    #[test]
+    fn value_to_row() {
+        assert_eq!(super::value_to_row(0.0, [0.0, 10.0], 11), Some(0));
+        assert_eq!(super::value_to_row(10.0, [0.0, 10.0], 11), Some(10));
+        // rounded towards the top, as the canvas
+        assert_eq!(super::value_to_row(1.0, [0.0, 4.0], 4), Some(1));
+        assert_eq!(super::value_to_row(5.0, [0.0, 4.0], 4), None);
+    }
+
+    #[test]
+    fn value_to_cell() {
+        assert_eq!(super::value_to_cell(0.0, [0.0, 10.0], 11), Some(0));
+        assert_eq!(super::value_to_cell(5.0, [0.0, 10.0], 11), Some(5));
+        assert_eq!(super::value_to_cell(10.0, [0.0, 10.0], 6), Some(5));
+        assert_eq!(super::value_to_cell(11.0, [0.0, 10.0], 11), None);
+    }
+
+    // the expected bounds are exactly representable
+    #[allow(clippy::float_cmp)]
+    #[test]
+    fn resolve_auto_bounds() {
+        let axis = Axis::default().auto_bounds(true).bounds_padding(0.1);
+        let resolved = axis.resolve([2.0, 4.0, 12.0].into_iter(), false);
+        assert_eq!(resolved.bounds, [1.0, 13.0]);
+        // the axis itself is left as configured
+        assert_eq!(axis, Axis::default().auto_bounds(true).bounds_padding(0.1));
+
+        let axis = Axis::default().auto_bounds(true);
+        let resolved = axis.resolve([2.0, 4.0].into_iter(), true);
+        assert_eq!(resolved.bounds, [0.0, 4.0]);
+
+        // a single value is displayed in the middle of the axis
+        let resolved = axis.resolve([5.0].into_iter(), false);
+        assert_eq!(resolved.bounds, [4.5, 5.5]);
+    }
+
+    #[allow(clippy::float_cmp)]
+    #[test]
+    fn resolve_ticks() {
+        let axis = Axis::default().auto_bounds(true).ticks(5);
+        let resolved = axis.resolve([0.3, 7.6].into_iter(), false);
+        assert_eq!(resolved.bounds, [0.0, 8.0]);
+        assert_eq!(resolved.ticks, [0.0, 2.0, 4.0, 6.0, 8.0]);
+        assert_eq!(resolved.labels(), ["0", "2", "4", "6", "8"].map(Line::from));
+        assert!(resolved.has_tick_labels());
+
+        // explicit bounds are kept
+        let axis = Axis::default()
+            .bounds([0.05, 0.5])
+            .ticks(4)
+            .label_format(LabelFormat::Percent);
+        let resolved = axis.resolve(std::iter::empty(), false);
+        assert_eq!(resolved.bounds, [0.05, 0.5]);
+        assert_eq!(resolved.labels(), ["20%", "40%"].map(Line::from));
+
+        // the ticks of a narrow range of huge values can't be computed
+        let axis = Axis::default().bounds([1e18, 1e18 + 256.0]).ticks(5);
+        let resolved = axis.resolve(std::iter::empty(), false);
+        assert!(resolved.ticks.is_empty());
+    }
+
+    #[allow(clippy::float_cmp)]
+    #[test]
+    fn resolve_ticks_keeps_labels() {
+        let axis = Axis::default()
+            .auto_bounds(true)
+            .ticks(5)
+            .labels(["low", "high"]);
+        let resolved = axis.resolve([0.3, 7.6].into_iter(), false);
+        assert_eq!(resolved.bounds, [0.0, 8.0]);
+        assert_eq!(resolved.ticks, [0.0, 2.0, 4.0, 6.0, 8.0]);
+        assert_eq!(resolved.labels(), ["low", "high"].map(Line::from));
+        assert!(!resolved.has_tick_labels());
+    }
+
+    #[test]
+    fn transform() {
+        assert!((AxisScale::Log10.transform(1000.0) - 3.0).abs() < 1e-12);
+        assert!((AxisScale::Log2.inverse(3.0) - 8.0).abs() < 1e-12);
+        assert!(AxisScale::Log10.transform(0.0).is_nan());
+        assert!(AxisScale::Log2.transform(-1.0).is_nan());
+        assert!((AxisScale::Time.transform(60.0) - 60.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn resolve_log_ticks() {
+        // the non-positive values are ignored
+        let axis = Axis::default()
+            .scale(AxisScale::Log10)
+            .auto_bounds(true)
+            .ticks(5);
+        let resolved = axis.resolve([-5.0, 0.0, 3.0, 420.0].into_iter(), true);
+        let [min, max] = resolved.bounds;
+        assert!((min - 1.0).abs() < 1e-9 && (max - 1000.0).abs() < 1e-9);
+        assert_eq!(
+            resolved.labels(),
+            ["1", "10", "100", "1000"].map(Line::from)
+        );
+
+        // too many powers are thinned out
+        let axis = Axis::default()
+            .scale(AxisScale::Log2)
+            .bounds([1.0, 1024.0])
+            .ticks(4)
+            .label_format(LabelFormat::Si);
+        let resolved = axis.resolve(std::iter::empty(), false);
+        assert_eq!(resolved.labels(), ["1", "8", "64", "512"].map(Line::from));
+    }
+
+    #[test]
+    fn resolve_time_ticks() {
+        let axis = Axis::default()
+            .scale(AxisScale::Time)
+            .bounds([0.0, 4.0 * 3600.0])
+            .ticks(5);
+        let resolved = axis.resolve(std::iter::empty(), false);
+        assert_eq!(
+            resolved.labels(),
+            ["Jan 01", "01:00", "02:00", "03:00", "04:00"].map(Line::from)
+        );
+
+        let axis = Axis::default()
+            .scale(AxisScale::Time)
+            .bounds([0.0, 20.0 * 86400.0])
+            .ticks(3);
+        let resolved = axis.resolve(std::iter::empty(), false);
+        assert_eq!(
+            resolved.labels(),
+            ["Jan 01", "Jan 11", "Jan 21"].map(Line::from)
+        );
+    }
+}
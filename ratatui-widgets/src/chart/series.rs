@@ -0,0 +1,215 @@
+use std::borrow::Cow;
+
+use ratatui_core::style::Color;
+
+use super::{Chart, GraphType, StepPosition};
+use crate::canvas::{Context, Line as CanvasLine, Painter, Shape};
+
+/// The points of a dataset to draw, after stacking it on the previous datasets.
+pub(crate) struct Series<'a> {
+    /// The points, with their stacked values
+    pub(crate) data: Cow<'a, [(f64, f64)]>,
+    /// The bottom of each point of a stacked dataset, i.e. the top of the previous datasets
+    pub(crate) bases: Option<Vec<f64>>,
+}
+
+impl GraphType {
+    /// Returns true if the datasets of this type are stacked in a stacked chart.
+    const fn is_stackable(self) -> bool {
+        matches!(self, Self::Line | Self::Area | Self::Step(_))
+    }
+}
+
+impl<'a> Chart<'a> {
+    /// Returns the points to draw for each dataset, stacked if the chart is stacked.
+    ///
+    /// The datasets plotted against each Y axis are stacked separately.
+    pub(crate) fn series(&self) -> Vec<Series<'a>> {
+        // the top of the stacked datasets at each index, for each Y axis
+        let mut stacks: [Vec<f64>; 2] = [Vec::new(), Vec::new()];
+        self.datasets
+            .iter()
+            .map(|dataset| {
+                if !self.stacked || !dataset.graph_type.is_stackable() {
+                    return Series {
+                        data: Cow::Borrowed(dataset.data),
+                        bases: None,
+                    };
+                }
+                let totals = &mut stacks[usize::from(self.uses_secondary_axis(dataset))];
+                let bases: Vec<f64> = (0..dataset.data.len())
+                    .map(|index| totals.get(index).copied().unwrap_or(0.0))
+                    .collect();
+                let data: Vec<(f64, f64)> = dataset
+                    .data
+                    .iter()
+                    .zip(&bases)
+                    .map(|(&(x, y), base)| (x, base + y))
+                    .collect();
+                totals.resize(totals.len().max(data.len()), 0.0);
+                for (total, &(_, top)) in totals.iter_mut().zip(&data) {
+                    // a missing value does not interrupt the stack
+                    if top.is_finite() {
+                        *total = top;
+                    }
+                }
+                Series {
+                    data: Cow::Owned(data),
+                    bases: Some(bases),
+                }
+            })
+            .collect()
+    }
+}
+
+/// Returns the points of the horizontal and vertical lines between each following point.
+pub(crate) fn step_points(data: &[(f64, f64)], position: StepPosition) -> Vec<(f64, f64)> {
+    let mut points = Vec::with_capacity(data.len() * 3);
+    for (index, &(x, y)) in data.iter().enumerate() {
+        if let Some(&(previous_x, previous_y)) = index.checked_sub(1).map(|index| &data[index]) {
+            match position {
+                StepPosition::Post => points.push((x, previous_y)),
+                StepPosition::Pre => points.push((previous_x, y)),
+                StepPosition::Mid => {
+                    let middle = (previous_x + x) / 2.0;
+                    points.extend([(middle, previous_y), (middle, y)]);
+                }
+            }
+        }
+        points.push((x, y));
+    }
+    points
+}
+
+/// Draws a line between each following point, interrupted around the points with a non-finite
+/// coordinate, e.g. out of the domain of a logarithmic scale.
+pub(crate) fn draw_lines(ctx: &mut Context, data: &[(f64, f64)], color: Color) {
+    let finite = |(x, y): (f64, f64)| x.is_finite() && y.is_finite();
+    for data in data.windows(2) {
+        if finite(data[0]) && finite(data[1]) {
+            ctx.draw(&CanvasLine::new(
+                data[0].0, data[0].1, data[1].0, data[1].1, color,
+            ));
+        }
+    }
+}
+
+/// A shape filling the area between a line and a base line, both going through the points
+///
+/// Each column of the grid between two points is filled, so the area is as smooth as the resolution
+/// of the marker allows. The points with a non-finite coordinate interrupt the area.
+pub(crate) struct Fill<'a> {
+    /// The `x`, `y` and base `y` coordinates of each point
+    pub(crate) points: &'a [(f64, f64, f64)],
+    /// Color of the area
+    pub(crate) color: Color,
+}
+
+impl Shape for Fill<'_> {
+    fn draw(&self, painter: &mut Painter) {
+        let ([left, right], [bottom, top]) = painter.bounds();
+        let (width, height) = painter.resolution();
+        if right <= left || top <= bottom || width < 1.0 || height < 1.0 {
+            return;
+        }
+        let column_width = (right - left) / (width - 1.0).max(1.0);
+        let row =
+            |y: f64| ((top - y.clamp(bottom, top)) * (height - 1.0) / (top - bottom)) as usize;
+        for segment in self.points.windows(2) {
+            let [start, end] = if segment[0].0 <= segment[1].0 {
+                [segment[0], segment[1]]
+            } else {
+                [segment[1], segment[0]]
+            };
+            let finite =
+                |(x, y, base): (f64, f64, f64)| x.is_finite() && y.is_finite() && base.is_finite();
+            if !finite(start) || !finite(end) || end.0 < left || start.0 > right {
+                continue;
+            }
+            let first = ((start.0.max(left) - left) / column_width - 1e-9).ceil() as usize;
+            let last = ((end.0.min(right) - left) / column_width + 1e-9).floor() as usize;
+            for column in first..=last.min(width as usize - 1) {
+                let x = left + column as f64 * column_width;
+                let ratio = if end.0 > start.0 {
+                    (x - start.0) / (end.0 - start.0)
+                } else {
+                    0.0
+                };
+                let y = start.1 + (end.1 - start.1) * ratio;
+                let base = start.2 + (end.2 - start.2) * ratio;
+                let (upper, lower) = (row(y.max(base)), row(y.min(base)));
+                for row in upper..=lower {
+                    painter.paint(column, row, self.color);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::chart::{Axis, Dataset};
+
+    #[allow(clippy::float_cmp)]
+    #[test]
+    fn step_points() {
+        let data = [(0.0, 1.0), (2.0, 3.0)];
+        assert_eq!(
+            super::step_points(&data, StepPosition::Post),
+            [(0.0, 1.0), (2.0, 1.0), (2.0, 3.0)]
+        );
+        assert_eq!(
+            super::step_points(&data, StepPosition::Pre),
+            [(0.0, 1.0), (0.0, 3.0), (2.0, 3.0)]
+        );
+        assert_eq!(
+            super::step_points(&data, StepPosition::Mid),
+            [(0.0, 1.0), (1.0, 1.0), (1.0, 3.0), (2.0, 3.0)]
+        );
+    }
+
+    #[allow(clippy::float_cmp)]
+    #[test]
+    fn stacked_series_by_axis() {
+        let data = [(0.0, 1.0)];
+        let dataset = Dataset::default().data(&data).graph_type(GraphType::Line);
+        let chart = Chart::new(vec![
+            dataset.clone(),
+            dataset.clone().secondary_axis(true),
+            dataset,
+        ])
+        .secondary_y_axis(Axis::default())
+        .stacked(true);
+        let tops: Vec<f64> = chart
+            .series()
+            .iter()
+            .map(|series| series.data[0].1)
+            .collect();
+        assert_eq!(tops, [1.0, 1.0, 2.0]);
+    }
+
+    #[allow(clippy::float_cmp)]
+    #[test]
+    fn stacked_series() {
+        let user = [(0.0, 2.0), (1.0, 3.0)];
+        let points = [(0.0, 9.0)];
+        let system = [(0.0, 1.0), (1.0, 1.0), (2.0, 4.0)];
+        let chart = Chart::new(vec![
+            Dataset::default().data(&user).graph_type(GraphType::Area),
+            Dataset::default().data(&points),
+            Dataset::default().data(&system).graph_type(GraphType::Line),
+        ])
+        .stacked(true);
+        let series = chart.series();
+        assert_eq!(*series[0].data, user);
+        assert_eq!(series[0].bases, Some(vec![0.0, 0.0]));
+        // scatter datasets are not stacked
+        assert_eq!(*series[1].data, points);
+        assert_eq!(series[1].bases, None);
+        assert_eq!(*series[2].data, [(0.0, 3.0), (1.0, 4.0), (2.0, 4.0)]);
+        assert_eq!(series[2].bases, Some(vec![2.0, 3.0, 0.0]));
+    }
+}
@@ -0,0 +1,548 @@
+//! The [`Heatmap`] widget and its related types (e.g. [`ColorScale`]).
+
+use ratatui_core::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style, Styled},
+    symbols,
+    text::Line,
+    widgets::Widget,
+};
+
+pub use self::color_scale::ColorScale;
+use crate::{
+    block::{Block, BlockExt},
+    chart::{
+        scale::{data_bounds, nice_step},
+        LabelFormat,
+    },
+};
+
+mod color_scale;
+
+/// A widget displaying a matrix of values as colored cells
+///
+/// Each value is displayed with the color of its [`ColorScale`], from the lowest to the highest
+/// value of the matrix, or of the [`bounds`](Heatmap::bounds) if they are set. The values which are
+/// not finite, e.g. `NaN`, are considered missing and are not displayed.
+///
+/// The rows can be labelled on the left and the columns on the bottom. A legend showing the color
+/// scale between the lowest and the highest value can be displayed below the matrix.
+///
+/// With [`half_blocks`](Heatmap::half_blocks), each line of the terminal displays two rows of the
+/// matrix using the upper half block `▀`, so that the cells are about square.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::{
+///     style::palette::tailwind,
+///     widgets::{Block, ColorScale, Heatmap},
+/// };
+///
+/// let requests = [
+///     [12.0, 40.0, 85.0, 30.0],
+///     [8.0, 22.0, 64.0, 18.0],
+///     [2.0, 5.0, 9.0, 4.0],
+/// ];
+/// Heatmap::new(requests)
+///     .block(Block::bordered().title("Requests"))
+///     .color_scale(ColorScale::sequential(&tailwind::ORANGE))
+///     .row_labels(["api", "web", "cron"])
+///     .column_labels(["00:00", "06:00", "12:00", "18:00"])
+///     .cell_width(6)
+///     .legend(true);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Heatmap<'a> {
+    /// Block to wrap the widget in
+    block: Option<Block<'a>>,
+    /// The rows of values, from top to bottom
+    data: Vec<Vec<f64>>,
+    /// The mapping from the values to colors
+    color_scale: ColorScale,
+    /// The values of the lowest and highest colors, if not computed from the data
+    bounds: Option<[f64; 2]>,
+    /// The value at the middle of the color scale, if any
+    midpoint: Option<f64>,
+    /// The labels of the rows, displayed on the left
+    row_labels: Vec<Line<'a>>,
+    /// The labels of the columns, displayed on the bottom
+    column_labels: Vec<Line<'a>>,
+    /// The width of each cell
+    cell_width: u16,
+    /// Whether two rows are displayed on each line
+    half_blocks: bool,
+    /// Whether the legend of the color scale is displayed
+    legend: bool,
+    /// The format of the values of the legend
+    label_format: LabelFormat,
+    /// Style for the widget
+    style: Style,
+}
+
+impl Default for Heatmap<'_> {
+    fn default() -> Self {
+        Self {
+            block: None,
+            data: Vec::new(),
+            color_scale: ColorScale::default(),
+            bounds: None,
+            midpoint: None,
+            row_labels: Vec::new(),
+            column_labels: Vec::new(),
+            cell_width: 1,
+            half_blocks: false,
+            legend: false,
+            label_format: LabelFormat::default(),
+            style: Style::default(),
+        }
+    }
+}
+
+impl<'a> Heatmap<'a> {
+    /// Creates a heatmap of the given rows of values, from top to bottom
+    ///
+    /// Each row can be anything convertible to a `Vec<f64>`, e.g. an array or a slice. The rows
+    /// don't need to have the same length.
+    pub fn new<R>(rows: R) -> Self
+    where
+        R: IntoIterator,
+        R::Item: Into<Vec<f64>>,
+    {
+        Self {
+            data: rows.into_iter().map(Into::into).collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Surrounds the `Heatmap` with a [`Block`]
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the mapping from the values to colors
+    ///
+    /// Defaults to a [sequential](ColorScale::sequential) scale on the blue palette.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn color_scale(mut self, color_scale: ColorScale) -> Self {
+        self.color_scale = color_scale;
+        self
+    }
+
+    /// Sets the values of the lowest and highest colors of the scale
+    ///
+    /// The values out of the bounds take the color of the nearest bound. If not set, the bounds are
+    /// the lowest and highest values of the data.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn bounds(mut self, bounds: [f64; 2]) -> Self {
+        self.bounds = Some(bounds);
+        self
+    }
+
+    /// Sets the value at the middle of the color scale
+    ///
+    /// The bounds are extended to be symmetric around the midpoint, so that the values at the same
+    /// distance below and above it have colors at the same distance from the middle of the scale.
+    /// This is meant for [diverging](ColorScale::diverging) scales, e.g. with a midpoint of `0.0`.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn midpoint(mut self, midpoint: f64) -> Self {
+        self.midpoint = Some(midpoint);
+        self
+    }
+
+    /// Sets the labels of the rows, displayed on their left from top to bottom
+    ///
+    /// With [`half_blocks`](Self::half_blocks), each line displays the label of its upper row.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn row_labels<T>(mut self, labels: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: Into<Line<'a>>,
+    {
+        self.row_labels = labels.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the labels of the columns, displayed below them from left to right
+    ///
+    /// Each label starts at the left of its column, and the labels overlapping the previous one are
+    /// skipped.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn column_labels<T>(mut self, labels: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: Into<Line<'a>>,
+    {
+        self.column_labels = labels.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the width of each cell, in columns of the terminal
+    ///
+    /// Defaults to `1`. The columns which don't fit in the area are not displayed.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn cell_width(mut self, width: u16) -> Self {
+        self.cell_width = width;
+        self
+    }
+
+    /// Displays two rows of values on each line of the terminal
+    ///
+    /// The upper row is displayed in the foreground color of the upper half block `▀` and the lower
+    /// row in its background color. The rows which don't fit in the area are not displayed.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn half_blocks(mut self, half_blocks: bool) -> Self {
+        self.half_blocks = half_blocks;
+        self
+    }
+
+    /// Displays a legend of the color scale on the bottom line, between the lowest and the highest
+    /// value
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn legend(mut self, legend: bool) -> Self {
+        self.legend = legend;
+        self
+    }
+
+    /// Sets the format of the values of the legend
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn label_format(mut self, format: LabelFormat) -> Self {
+        self.label_format = format;
+        self
+    }
+
+    /// Sets the style of the entire widget
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// The style is applied to the whole area, and is visible where there is no value.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl Heatmap<'_> {
+    /// Returns the values of the lowest and highest colors, or `None` if there is no finite value.
+    fn color_bounds(&self) -> Option<[f64; 2]> {
+        let [min, max] = self.bounds.or_else(|| {
+            let values = self.data.iter().flatten().copied();
+            data_bounds(values.filter(|v| v.is_finite()))
+        })?;
+        let Some(midpoint) = self.midpoint else {
+            return Some([min, max]);
+        };
+        let extent = (max - midpoint).abs().max((midpoint - min).abs());
+        Some([midpoint - extent, midpoint + extent])
+    }
+
+    /// Returns the position of the value in the bounds, between `0.0` and `1.0`, or the middle if
+    /// the bounds are empty.
+    fn normalize(value: f64, [min, max]: [f64; 2]) -> f64 {
+        if max > min {
+            ((value - min) / (max - min)).clamp(0.0, 1.0)
+        } else {
+            0.5
+        }
+    }
+
+    /// Returns the color of the value, or `None` if it is missing.
+    fn color(&self, value: Option<&f64>, bounds: [f64; 2]) -> Option<Color> {
+        let value = *value?;
+        value
+            .is_finite()
+            .then(|| self.color_scale.color(Self::normalize(value, bounds)))
+    }
+
+    fn render_cells(&self, buf: &mut Buffer, area: Rect, bounds: [f64; 2]) {
+        let rows_per_line = if self.half_blocks { 2 } else { 1 };
+        let cell_width = usize::from(self.cell_width.max(1));
+        for (line, y) in (area.top()..area.bottom()).enumerate() {
+            let upper = self.data.get(line * rows_per_line);
+            let lower = self
+                .data
+                .get(line * rows_per_line + 1)
+                .filter(|_| self.half_blocks);
+            for (offset, x) in (area.left()..area.right()).enumerate() {
+                let column = offset / cell_width;
+                let cell = &mut buf[(x, y)];
+                let upper = self.color(upper.and_then(|row| row.get(column)), bounds);
+                if !self.half_blocks {
+                    if let Some(color) = upper {
+                        cell.set_symbol(" ").set_bg(color);
+                    }
+                    continue;
+                }
+                let lower = self.color(lower.and_then(|row| row.get(column)), bounds);
+                if upper.is_none() && lower.is_none() {
+                    continue;
+                }
+                cell.set_char(symbols::half_block::UPPER);
+                if let Some(color) = upper {
+                    cell.set_fg(color);
+                } else {
+                    // the upper half keeps the background of the widget
+                    cell.set_fg(self.style.bg.unwrap_or_default());
+                }
+                if let Some(color) = lower {
+                    cell.set_bg(color);
+                }
+            }
+        }
+    }
+
+    fn render_row_labels(&self, buf: &mut Buffer, area: Rect) {
+        let rows_per_line = if self.half_blocks { 2 } else { 1 };
+        let labels = self.row_labels.iter().step_by(rows_per_line);
+        for (label, y) in labels.zip(area.top()..area.bottom()) {
+            label.render(
+                Rect {
+                    y,
+                    height: 1,
+                    ..area
+                },
+                buf,
+            );
+        }
+    }
+
+    fn render_column_labels(&self, buf: &mut Buffer, area: Rect) {
+        let cell_width = self.cell_width.max(1);
+        let mut free_x = area.left();
+        for (column, label) in self.column_labels.iter().enumerate() {
+            let x = area.left().saturating_add(column as u16 * cell_width);
+            if x >= area.right() {
+                break;
+            }
+            if x < free_x {
+                continue;
+            }
+            let width = (label.width() as u16).min(area.right() - x);
+            label.render(Rect::new(x, area.y, width, 1), buf);
+            // leave at least one space between two labels
+            free_x = x + width + 1;
+        }
+    }
+
+    /// Renders the lowest value, the colors of the scale and the highest value on the line.
+    fn render_legend(&self, buf: &mut Buffer, area: Rect, [min, max]: [f64; 2]) {
+        let step = nice_step(max - min, usize::from(area.width));
+        let min_label = Line::from(self.label_format.format(min, step));
+        let max_label = Line::from(self.label_format.format(max, step));
+        let (min_width, max_width) = (min_label.width() as u16, max_label.width() as u16);
+        let Some(bar_width) = area.width.checked_sub(min_width + max_width + 2) else {
+            return;
+        };
+        min_label.render(
+            Rect {
+                width: min_width,
+                ..area
+            },
+            buf,
+        );
+        let bar_x = area.x + min_width + 1;
+        for offset in 0..bar_width {
+            let value = f64::from(offset) / f64::from(bar_width.saturating_sub(1).max(1));
+            buf[(bar_x + offset, area.y)]
+                .set_symbol(" ")
+                .set_bg(self.color_scale.color(value));
+        }
+        let max_area = Rect::new(bar_x + bar_width + 1, area.y, max_width, 1);
+        max_label.render(max_area, buf);
+    }
+}
+
+impl Widget for Heatmap<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl Widget for &Heatmap<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        buf.set_style(area, self.style);
+        self.block.as_ref().render(area, buf);
+        let inner = self.block.inner_if_some(area);
+        if inner.is_empty() {
+            return;
+        }
+        let Some(bounds) = self.color_bounds() else {
+            return;
+        };
+        let legend_height = u16::from(self.legend);
+        let labels_height = u16::from(!self.column_labels.is_empty());
+        let label_width = self
+            .row_labels
+            .iter()
+            .map(Line::width)
+            .max()
+            .unwrap_or_default() as u16;
+        let label_width = label_width.min(inner.width / 3);
+        let margin = u16::from(label_width != 0);
+        let cells = Rect {
+            x: inner.x + label_width + margin,
+            width: inner.width - label_width - margin,
+            height: inner.height.saturating_sub(legend_height + labels_height),
+            ..inner
+        };
+        self.render_cells(buf, cells, bounds);
+        if label_width > 0 {
+            let labels = Rect {
+                x: inner.x,
+                width: label_width,
+                ..cells
+            };
+            self.render_row_labels(buf, labels);
+        }
+        if labels_height > 0 && cells.bottom() < inner.bottom() {
+            let labels = Rect {
+                y: cells.bottom(),
+                height: 1,
+                ..cells
+            };
+            self.render_column_labels(buf, labels);
+        }
+        if self.legend {
+            let legend = Rect {
+                y: inner.bottom() - 1,
+                height: 1,
+                ..inner
+            };
+            self.render_legend(buf, legend, bounds);
+        }
+    }
+}
+
+impl Styled for Heatmap<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    const RED: Color = Color::Rgb(255, 0, 0);
+    const BLUE: Color = Color::Rgb(0, 0, 255);
+
+    fn scale() -> ColorScale {
+        ColorScale::Gradient(vec![BLUE, RED])
+    }
+
+    #[test]
+    fn color_bounds() {
+        let heatmap = Heatmap::new([[1.0, f64::NAN], [3.0, -2.0]]);
+        assert_eq!(heatmap.color_bounds(), Some([-2.0, 3.0]));
+        assert_eq!(
+            heatmap.clone().midpoint(0.0).color_bounds(),
+            Some([-3.0, 3.0])
+        );
+        assert_eq!(
+            heatmap.bounds([0.0, 10.0]).color_bounds(),
+            Some([0.0, 10.0])
+        );
+        assert_eq!(Heatmap::new([[f64::NAN]]).color_bounds(), None);
+    }
+
+    #[test]
+    fn render() {
+        let heatmap = Heatmap::new([[0.0, 1.0], [f64::NAN, 0.5]])
+            .color_scale(scale())
+            .cell_width(2);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 3));
+        heatmap.render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(["     "; 3]);
+        expected.set_style(Rect::new(0, 0, 2, 1), Style::new().bg(BLUE));
+        expected.set_style(Rect::new(2, 0, 2, 1), Style::new().bg(RED));
+        expected.set_style(
+            Rect::new(2, 1, 2, 1),
+            Style::new().bg(Color::Rgb(128, 0, 128)),
+        );
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_half_blocks() {
+        let heatmap = Heatmap::new([vec![0.0, 1.0], vec![1.0, f64::NAN], vec![0.0]])
+            .color_scale(scale())
+            .half_blocks(true);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 2, 2));
+        heatmap.render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(["▀▀", "▀ "]);
+        expected[(0, 0)].set_fg(BLUE).set_bg(RED);
+        expected[(1, 0)].set_fg(RED);
+        expected[(0, 1)].set_fg(BLUE);
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_labels() {
+        let heatmap = Heatmap::new([[0.0, 1.0, 0.0], [1.0, 0.0, 1.0]])
+            .color_scale(ColorScale::Steps(vec![Color::Black, Color::White]))
+            .row_labels(["a", "bb"])
+            .column_labels(["x", "long", "z"])
+            .cell_width(2);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 9, 3));
+        heatmap.render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(["a        ", "bb       ", "   x long"]);
+        expected.set_style(Rect::new(3, 0, 2, 1), Style::new().bg(Color::Black));
+        expected.set_style(Rect::new(5, 0, 2, 1), Style::new().bg(Color::White));
+        expected.set_style(Rect::new(7, 0, 2, 1), Style::new().bg(Color::Black));
+        expected.set_style(Rect::new(3, 1, 2, 1), Style::new().bg(Color::White));
+        expected.set_style(Rect::new(5, 1, 2, 1), Style::new().bg(Color::Black));
+        expected.set_style(Rect::new(7, 1, 2, 1), Style::new().bg(Color::White));
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_legend() {
+        let heatmap = Heatmap::new([[0.0, 10.0]])
+            .color_scale(ColorScale::Steps(vec![Color::Black, Color::White]))
+            .legend(true);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 9, 2));
+        heatmap.render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(["         ", "0      10"]);
+        expected.set_style(Rect::new(0, 0, 1, 1), Style::new().bg(Color::Black));
+        expected.set_style(Rect::new(1, 0, 1, 1), Style::new().bg(Color::White));
+        expected.set_style(Rect::new(2, 1, 2, 1), Style::new().bg(Color::Black));
+        expected.set_style(Rect::new(4, 1, 2, 1), Style::new().bg(Color::White));
+        assert_eq!(buf, expected);
+    }
+}
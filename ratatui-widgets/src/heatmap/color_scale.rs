@@ -0,0 +1,227 @@
+use std::{fmt, sync::Arc};
+
+use ratatui_core::style::{palette::tailwind::Palette, Color};
+
+/// A mapping from the values of a [`Heatmap`] to colors
+///
+/// The values are normalized between `0.0` for the lowest value and `1.0` for the highest one
+/// before being mapped to a color, see [`Heatmap::bounds`] and [`Heatmap::midpoint`].
+///
+/// The [`sequential`](Self::sequential) and [`diverging`](Self::diverging) scales are built on the
+/// [`tailwind`](ratatui_core::style::palette::tailwind) palettes. Any other colors can be
+/// interpolated with a [`Gradient`](Self::Gradient), or a [`custom`](Self::custom) function can
+/// compute the color of each value.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::{
+///     style::{palette::tailwind, Color},
+///     widgets::ColorScale,
+/// };
+///
+/// let heat = ColorScale::sequential(&tailwind::ORANGE);
+/// let change = ColorScale::diverging(&tailwind::RED, &tailwind::GREEN);
+/// let status = ColorScale::Steps(vec![Color::Green, Color::Yellow, Color::Red]);
+/// assert_eq!(status.color(0.9), Color::Red);
+/// ```
+///
+/// [`Heatmap`]: super::Heatmap
+/// [`Heatmap::bounds`]: super::Heatmap::bounds
+/// [`Heatmap::midpoint`]: super::Heatmap::midpoint
+#[derive(Clone)]
+pub enum ColorScale {
+    /// Colors evenly spaced from the lowest to the highest value, interpolated in between
+    ///
+    /// The interpolation is linear between the RGB components of the colors. The values between
+    /// two colors which are not [`Color::Rgb`] take the nearest one.
+    Gradient(Vec<Color>),
+    /// Colors for evenly sized ranges of values, from the lowest to the highest value
+    Steps(Vec<Color>),
+    /// A function returning the color of a normalized value, between `0.0` and `1.0`
+    ///
+    /// Two custom scales are equal if they share the same function. See [`ColorScale::custom`].
+    Custom(Arc<dyn Fn(f64) -> Color + Send + Sync>),
+}
+
+impl fmt::Debug for ColorScale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Gradient(colors) => f.debug_tuple("Gradient").field(colors).finish(),
+            Self::Steps(colors) => f.debug_tuple("Steps").field(colors).finish(),
+            Self::Custom(_) => f.write_str("Custom(..)"),
+        }
+    }
+}
+
+impl PartialEq for ColorScale {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Gradient(a), Self::Gradient(b)) | (Self::Steps(a), Self::Steps(b)) => a == b,
+            (Self::Custom(a), Self::Custom(b)) => Arc::ptr_eq(a, b),
+            _ => false,
+        }
+    }
+}
+
+impl Eq for ColorScale {}
+
+impl Default for ColorScale {
+    /// A sequential scale on the [`BLUE`](ratatui_core::style::palette::tailwind::BLUE) palette
+    fn default() -> Self {
+        Self::sequential(&ratatui_core::style::palette::tailwind::BLUE)
+    }
+}
+
+impl ColorScale {
+    /// A gradient through the shades of a palette, from the lightest for the lowest value to the
+    /// darkest for the highest one
+    pub fn sequential(palette: &Palette) -> Self {
+        Self::Gradient(shades(palette).to_vec())
+    }
+
+    /// A gradient from the darkest shade of the `low` palette for the lowest value, to the darkest
+    /// shade of the `high` palette for the highest one, through their lightest shades in the middle
+    ///
+    /// This is suited to values diverging from a [midpoint](super::Heatmap::midpoint), e.g. the
+    /// changes of a value.
+    pub fn diverging(low: &Palette, high: &Palette) -> Self {
+        let mut colors = shades(low).to_vec();
+        colors.reverse();
+        colors.extend(shades(high));
+        Self::Gradient(colors)
+    }
+
+    /// A scale computing the color of each normalized value, between `0.0` and `1.0`, with the
+    /// given function
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{style::Color, widgets::ColorScale};
+    ///
+    /// let threshold = 0.8;
+    /// let alert = ColorScale::custom(move |value| {
+    ///     if value > threshold {
+    ///         Color::Red
+    ///     } else {
+    ///         Color::Gray
+    ///     }
+    /// });
+    /// assert_eq!(alert.color(0.9), Color::Red);
+    /// ```
+    pub fn custom<F>(color: F) -> Self
+    where
+        F: Fn(f64) -> Color + Send + Sync + 'static,
+    {
+        Self::Custom(Arc::new(color))
+    }
+
+    /// Returns the color of a normalized value, between `0.0` and `1.0`
+    ///
+    /// The value is clamped to this range. Returns [`Color::Reset`] if there is no color.
+    pub fn color(&self, value: f64) -> Color {
+        let value = value.clamp(0.0, 1.0);
+        match self {
+            Self::Gradient(colors) => {
+                let Some(last) = colors.len().checked_sub(1) else {
+                    return Color::Reset;
+                };
+                let position = value * last as f64;
+                let index = (position.floor() as usize).min(last);
+                let next = (index + 1).min(last);
+                interpolate(colors[index], colors[next], position - index as f64)
+            }
+            Self::Steps(colors) => {
+                let index = (value * colors.len() as f64) as usize;
+                colors
+                    .get(index.min(colors.len().saturating_sub(1)))
+                    .copied()
+                    .unwrap_or(Color::Reset)
+            }
+            Self::Custom(color) => color(value),
+        }
+    }
+}
+
+/// Returns the shades of a palette from the lightest to the darkest.
+const fn shades(palette: &Palette) -> [Color; 5] {
+    [
+        palette.c100,
+        palette.c300,
+        palette.c500,
+        palette.c700,
+        palette.c900,
+    ]
+}
+
+/// Returns the color at the given ratio between two colors, interpolating their RGB components, or
+/// the nearest color if they are not both RGB colors.
+fn interpolate(from: Color, to: Color, ratio: f64) -> Color {
+    match (from, to) {
+        (Color::Rgb(r1, g1, b1), Color::Rgb(r2, g2, b2)) => {
+            let mix =
+                |a: u8, b: u8| (f64::from(a) + (f64::from(b) - f64::from(a)) * ratio).round() as u8;
+            Color::Rgb(mix(r1, r2), mix(g1, g2), mix(b1, b2))
+        }
+        _ if ratio < 0.5 => from,
+        _ => to,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use ratatui_core::style::palette::tailwind;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::low(0.0, Color::Rgb(0, 0, 0))]
+    #[case::middle(0.5, Color::Rgb(100, 50, 0))]
+    #[case::quarter(0.25, Color::Rgb(50, 25, 0))]
+    #[case::high(1.0, Color::Rgb(200, 100, 0))]
+    #[case::clamped(2.0, Color::Rgb(200, 100, 0))]
+    fn gradient(#[case] value: f64, #[case] expected: Color) {
+        let scale = ColorScale::Gradient(vec![Color::Rgb(0, 0, 0), Color::Rgb(200, 100, 0)]);
+        assert_eq!(scale.color(value), expected);
+    }
+
+    #[test]
+    fn gradient_named_colors() {
+        let scale = ColorScale::Gradient(vec![Color::Red, Color::Blue]);
+        assert_eq!(scale.color(0.4), Color::Red);
+        assert_eq!(scale.color(0.6), Color::Blue);
+        assert_eq!(ColorScale::Gradient(vec![]).color(0.5), Color::Reset);
+    }
+
+    #[rstest]
+    #[case(0.0, Color::Green)]
+    #[case(0.4, Color::Yellow)]
+    #[case(0.7, Color::Red)]
+    #[case(1.0, Color::Red)]
+    fn steps(#[case] value: f64, #[case] expected: Color) {
+        let scale = ColorScale::Steps(vec![Color::Green, Color::Yellow, Color::Red]);
+        assert_eq!(scale.color(value), expected);
+    }
+
+    #[test]
+    fn palettes() {
+        let scale = ColorScale::sequential(&tailwind::BLUE);
+        assert_eq!(scale.color(0.0), tailwind::BLUE.c100);
+        assert_eq!(scale.color(1.0), tailwind::BLUE.c900);
+        let scale = ColorScale::diverging(&tailwind::RED, &tailwind::GREEN);
+        assert_eq!(scale.color(0.0), tailwind::RED.c900);
+        assert_eq!(scale.color(1.0), tailwind::GREEN.c900);
+    }
+
+    #[test]
+    fn custom() {
+        let scale = ColorScale::custom(|value| if value < 0.5 { Color::Cyan } else { Color::Red });
+        assert_eq!(scale.color(0.2), Color::Cyan);
+        assert_eq!(scale.color(2.0), Color::Red);
+        assert_eq!(scale, scale.clone());
+        assert_ne!(scale, ColorScale::custom(|_| Color::Cyan));
+    }
+}
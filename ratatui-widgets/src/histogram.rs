@@ -0,0 +1,381 @@
+//! The [`Histogram`] widget and its related types (e.g. [`Bins`]).
+
+use ratatui_core::{
+    buffer::Buffer,
+    layout::{Direction, Rect},
+    style::{Style, Styled},
+    symbols,
+    widgets::Widget,
+};
+
+pub(crate) use self::bins::quantile;
+pub use self::bins::Bins;
+use crate::{
+    block::{Block, BlockExt},
+    chart::{Axis, AxisScale},
+};
+
+mod bins;
+
+/// A widget showing the distribution of samples as bars
+///
+/// The samples are grouped into [`Bins`] of equal width, and the number of samples in each bin is
+/// displayed as a bar with the symbols of a [`BarChart`]. The bins are computed from the range of
+/// the samples, or from the bounds of the [`Axis`] if they are set.
+///
+/// The values of the samples are labelled with the [`Axis`] the same way as in a [`Chart`]: below
+/// the bars when they are vertical, or on their left when they are horizontal.
+///
+/// Here is a possible `Histogram` output.
+/// ```plain
+/// ┌────────────────────┐
+/// │        ██          │
+/// │      ▄▄██▄▄        │
+/// │    ▂▂██████▂▂      │
+/// │▁▁▁▁██████████▁▁▁▁▁▁│
+/// │0       5        10 │
+/// └────────────────────┘
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use ratatui::{
+///     style::{Color, Style},
+///     widgets::{Axis, Bins, Block, Histogram},
+/// };
+///
+/// let samples = [1.2, 2.5, 2.7, 3.1, 3.3, 3.8, 4.4, 5.0];
+/// Histogram::new(&samples)
+///     .block(Block::bordered().title("Latency"))
+///     .bins(Bins::Width(1.0))
+///     .axis(Axis::default().ticks(5))
+///     .bar_style(Style::new().fg(Color::Yellow));
+/// ```
+///
+/// [`BarChart`]: crate::barchart::BarChart
+/// [`Chart`]: crate::chart::Chart
+#[derive(Debug, Clone, PartialEq)]
+pub struct Histogram<'a> {
+    /// Block to wrap the widget in
+    block: Option<Block<'a>>,
+    /// The samples to group into bins
+    samples: &'a [f64],
+    /// How the samples are grouped into bins
+    bins: Bins,
+    /// The axis of the values of the samples
+    axis: Axis<'a>,
+    /// Set of symbols used to display the bars
+    bar_set: symbols::bar::Set,
+    /// Style of the bars
+    bar_style: Style,
+    /// Style for the widget
+    style: Style,
+    /// Direction of the bars
+    direction: Direction,
+}
+
+impl Default for Histogram<'_> {
+    fn default() -> Self {
+        Self {
+            block: None,
+            samples: &[],
+            bins: Bins::default(),
+            axis: Axis::default(),
+            bar_set: symbols::bar::NINE_LEVELS,
+            bar_style: Style::default(),
+            style: Style::default(),
+            direction: Direction::Vertical,
+        }
+    }
+}
+
+impl<'a> Histogram<'a> {
+    /// Creates a histogram of the given samples
+    ///
+    /// The samples which are not finite, e.g. `NaN`, are ignored.
+    pub fn new(samples: &'a [f64]) -> Self {
+        Self {
+            samples,
+            ..Self::default()
+        }
+    }
+
+    /// Sets the samples to group into bins
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn samples(mut self, samples: &'a [f64]) -> Self {
+        self.samples = samples;
+        self
+    }
+
+    /// Surrounds the `Histogram` with a [`Block`]
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets how the samples are grouped into bins
+    ///
+    /// Defaults to [`Bins::FreedmanDiaconis`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn bins(mut self, bins: Bins) -> Self {
+        self.bins = bins;
+        self
+    }
+
+    /// Sets the axis of the values of the samples
+    ///
+    /// If the bounds of the axis are set, the bins span them and the samples out of them are
+    /// ignored. Otherwise the bins span the range of the samples. The labels of the axis are
+    /// generated with [`Axis::ticks`], or evenly spaced along the bars with [`Axis::labels`].
+    ///
+    /// The values are always mapped linearly to the bars, whatever the [scale](Axis::scale) of the
+    /// axis.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn axis(mut self, axis: Axis<'a>) -> Self {
+        self.axis = axis.scale(AxisScale::Linear);
+        self
+    }
+
+    /// Sets the [`bar::Set`](symbols::bar::Set) used to display the bars
+    ///
+    /// If not set, the default is [`bar::NINE_LEVELS`](symbols::bar::NINE_LEVELS).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn bar_set(mut self, bar_set: symbols::bar::Set) -> Self {
+        self.bar_set = bar_set;
+        self
+    }
+
+    /// Sets the style of the bars
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn bar_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.bar_style = style.into();
+        self
+    }
+
+    /// Sets the style of the entire widget
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// The style will be applied to everything that isn't styled (borders, bars, labels, ...).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the direction of the bars
+    ///
+    /// [`Vertical`](Direction::Vertical) bars grow upwards from the values on the bottom, which is
+    /// the default. [`Horizontal`](Direction::Horizontal) bars grow rightwards from the values on
+    /// the left, the lowest value at the bottom.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+}
+
+impl Histogram<'_> {
+    fn render_vertical(&self, buf: &mut Buffer, area: Rect) {
+        let Some(binned) = self
+            .bins
+            .bin(self.samples, self.axis.fixed_bounds(), area.width.into())
+        else {
+            return;
+        };
+        let axis = self.axis.with_bounds(binned.bounds());
+        let label_height = u16::from(axis.has_labels());
+        let graph = Rect {
+            height: area.height.saturating_sub(label_height),
+            ..area
+        };
+        if label_height > 0 {
+            axis.render_column_labels(buf, area.bottom() - 1, area, graph);
+        }
+        let bars = bar_ticks(&binned.counts, graph.width, graph.height);
+        for (x, ticks) in (graph.left()..graph.right()).zip(bars) {
+            let mut ticks = ticks;
+            for y in (graph.top()..graph.bottom()).rev() {
+                let symbol = self.bar_set.symbol_for_eighths(ticks);
+                buf[(x, y)].set_symbol(symbol).set_style(self.bar_style);
+                ticks = ticks.saturating_sub(8);
+            }
+        }
+    }
+
+    fn render_horizontal(&self, buf: &mut Buffer, area: Rect) {
+        let Some(binned) =
+            self.bins
+                .bin(self.samples, self.axis.fixed_bounds(), area.height.into())
+        else {
+            return;
+        };
+        let axis = self.axis.with_bounds(binned.bounds());
+        let label_width = axis.labels_width().min(area.width / 3);
+        let margin = u16::from(label_width != 0);
+        let graph = Rect {
+            x: area.x + label_width + margin,
+            width: area.width - label_width - margin,
+            ..area
+        };
+        if label_width > 0 {
+            axis.render_row_labels(buf, area.x, label_width, graph);
+        }
+        let bars = bar_ticks(&binned.counts, graph.height, graph.width);
+        // the first bin is at the bottom
+        for (y, ticks) in (graph.top()..graph.bottom()).rev().zip(bars) {
+            let bar_length = (ticks / 8) as u16;
+            for x in 0..graph.width {
+                let symbol = if x < bar_length {
+                    self.bar_set.full
+                } else {
+                    self.bar_set.empty
+                };
+                buf[(graph.left() + x, y)]
+                    .set_symbol(symbol)
+                    .set_style(self.bar_style);
+            }
+        }
+    }
+}
+
+/// Returns the length of the bar in each of the `cells` cells along the axis, in eighths of a cell,
+/// the longest bar being `length` cells long.
+///
+/// The bins are spread over the cells, so each bin is displayed by one or more following cells.
+fn bar_ticks(counts: &[u64], cells: u16, length: u16) -> impl Iterator<Item = u64> + '_ {
+    let max = counts.iter().copied().max().unwrap_or_default().max(1);
+    let bins = counts.len();
+    (0..usize::from(cells)).map(move |cell| {
+        let count = counts[cell * bins / usize::from(cells)];
+        count * u64::from(length) * 8 / max
+    })
+}
+
+impl Widget for Histogram<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl Widget for &Histogram<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        buf.set_style(area, self.style);
+        self.block.as_ref().render(area, buf);
+        let inner = self.block.inner_if_some(area);
+        if inner.is_empty() {
+            return;
+        }
+        match self.direction {
+            Direction::Horizontal => self.render_horizontal(buf, inner),
+            Direction::Vertical => self.render_vertical(buf, inner),
+        }
+    }
+}
+
+impl Styled for Histogram<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::style::{Color, Stylize};
+
+    use super::*;
+
+    const SAMPLES: [f64; 9] = [1.0, 2.0, 2.0, 3.0, 3.0, 3.0, 4.0, 4.0, 5.0];
+
+    #[test]
+    fn render_vertical() {
+        let histogram = Histogram::new(&SAMPLES).bins(Bins::Count(5));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 4));
+        histogram.render(buf.area, &mut buf);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["    ██    ", "  ▅▅██▅▅  ", "▂▂██████▂▂", "██████████",])
+        );
+    }
+
+    #[test]
+    fn render_vertical_with_axis() {
+        let histogram = Histogram::new(&SAMPLES)
+            .bins(Bins::Count(5))
+            .axis(Axis::default().ticks(3));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 4));
+        histogram.render(buf.area, &mut buf);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["    ██    ", "  ██████  ", "██████████", "  2   4   ",])
+        );
+    }
+
+    #[test]
+    fn render_horizontal() {
+        let histogram = Histogram::new(&SAMPLES)
+            .bins(Bins::Count(5))
+            .axis(Axis::default().ticks(3))
+            .direction(Direction::Horizontal)
+            .bar_style(Color::Red);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 5));
+        histogram.render(buf.area, &mut buf);
+        let mut expected =
+            Buffer::with_lines(["  ██    ", "4 ████  ", "  ██████", "2 ████  ", "  ██    "]);
+        expected.set_style(Rect::new(2, 0, 6, 5), Style::new().red());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_fixed_bounds() {
+        let histogram = Histogram::new(&SAMPLES)
+            .bins(Bins::Width(2.0))
+            .axis(Axis::default().bounds([0.0, 8.0]).labels(["0", "8"]));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 3));
+        histogram.render(buf.area, &mut buf);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["  ██▁▁  ", "▃▃████  ", "0      8"])
+        );
+    }
+
+    #[test]
+    fn render_empty() {
+        let histogram = Histogram::default().block(Block::bordered());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 3));
+        histogram.render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["┌──┐", "│  │", "└──┘"]));
+    }
+}
@@ -0,0 +1,232 @@
+use crate::chart::scale::data_bounds;
+
+/// How the samples of a [`Histogram`] are grouped into bins
+///
+/// Whatever the rule, the number of bins is limited to the number of cells available to display
+/// them, so that each bin is at least one cell wide.
+///
+/// [`Histogram`]: super::Histogram
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum Bins {
+    /// A fixed number of bins of equal width, spanning the range of the samples
+    Count(usize),
+    /// Bins of a fixed width, starting at a multiple of the width
+    Width(f64),
+    /// Bins of the width given by the [Freedman–Diaconis rule], `2 × IQR / ∛n`
+    ///
+    /// This is robust to outliers, as it depends on the interquartile range of the samples. When
+    /// the interquartile range is zero, the number of bins is given by Sturges' rule instead.
+    ///
+    /// This is the default.
+    ///
+    /// [Freedman–Diaconis rule]: https://en.wikipedia.org/wiki/Freedman%E2%80%93Diaconis_rule
+    #[default]
+    FreedmanDiaconis,
+}
+
+/// The number of samples in each bin of a histogram
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Binned {
+    /// The lower edge of the first bin
+    pub(crate) start: f64,
+    /// The width of each bin
+    pub(crate) width: f64,
+    /// The number of samples in each bin
+    pub(crate) counts: Vec<u64>,
+}
+
+impl Binned {
+    /// Returns the lower edge of the first bin and the upper edge of the last one.
+    pub(crate) fn bounds(&self) -> [f64; 2] {
+        [
+            self.start,
+            self.start + self.width * self.counts.len() as f64,
+        ]
+    }
+}
+
+impl Bins {
+    /// Groups the finite samples into bins, at most `max_count` of them.
+    ///
+    /// The bins span the given range, or the range of the samples if there is none; the samples
+    /// out of the range are ignored. Each bin contains the samples greater or equal to its lower
+    /// edge and less than its upper edge, except the last one which also contains its upper edge.
+    ///
+    /// Returns `None` if there is no range nor finite sample.
+    pub(crate) fn bin(
+        self,
+        samples: &[f64],
+        range: Option<[f64; 2]>,
+        max_count: usize,
+    ) -> Option<Binned> {
+        let mut sorted: Vec<f64> = samples.iter().copied().filter(|v| v.is_finite()).collect();
+        sorted.sort_by(f64::total_cmp);
+        let [min, max] = range.or_else(|| data_bounds(sorted.iter().copied()))?;
+        sorted.retain(|&value| value >= min && value <= max);
+        if max <= min {
+            // a single bin centered on the value
+            return Some(Binned {
+                start: min - 0.5,
+                width: 1.0,
+                counts: vec![sorted.len() as u64],
+            });
+        }
+        let max_count = max_count.max(1);
+        let (start, width) = match self {
+            Self::Count(count) => (min, (max - min) / count.clamp(1, max_count) as f64),
+            Self::Width(width) if width > 0.0 && width.is_finite() => {
+                let start = (min / width).floor() * width;
+                // merge the bins if there are too many of them, keeping them on multiples of the
+                // width
+                let count = ((max - start) / width).ceil();
+                (start, width * (count / max_count as f64).ceil().max(1.0))
+            }
+            Self::Width(_) => (min, max - min),
+            Self::FreedmanDiaconis => {
+                let count = freedman_diaconis(&sorted, max - min).clamp(1, max_count);
+                (min, (max - min) / count as f64)
+            }
+        };
+        let count = (((max - start) / width - 1e-9).ceil() as usize).clamp(1, max_count);
+        let mut counts = vec![0; count];
+        for value in sorted {
+            let index = ((value - start) / width).floor() as usize;
+            counts[index.min(count - 1)] += 1;
+        }
+        Some(Binned {
+            start,
+            width,
+            counts,
+        })
+    }
+}
+
+/// Returns the number of bins covering the range given by the Freedman–Diaconis rule, or by
+/// Sturges' rule if the interquartile range of the sorted samples is zero.
+fn freedman_diaconis(sorted: &[f64], range: f64) -> usize {
+    let n = sorted.len();
+    if n == 0 {
+        return 1;
+    }
+    let iqr = quantile(sorted, 0.75) - quantile(sorted, 0.25);
+    if iqr > 0.0 {
+        let width = 2.0 * iqr / (n as f64).cbrt();
+        (range / width).ceil() as usize
+    } else {
+        (n as f64).log2().ceil() as usize + 1
+    }
+}
+
+/// Returns the quantile `q` (between 0 and 1) of sorted values, interpolated linearly between the
+/// two nearest values, or `NaN` if there is none.
+pub(crate) fn quantile(sorted: &[f64], q: f64) -> f64 {
+    let Some(last) = sorted.len().checked_sub(1) else {
+        return f64::NAN;
+    };
+    let position = q.clamp(0.0, 1.0) * last as f64;
+    let lower = position.floor() as usize;
+    let upper = position.ceil() as usize;
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (position - lower as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::min(0.0, 1.0)]
+    #[case::max(1.0, 4.0)]
+    #[case::median(0.5, 2.5)]
+    #[case::first_quartile(0.25, 1.75)]
+    fn quantile(#[case] q: f64, #[case] expected: f64) {
+        assert!((super::quantile(&[1.0, 2.0, 3.0, 4.0], q) - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn quantile_empty() {
+        assert!(super::quantile(&[], 0.5).is_nan());
+    }
+
+    #[test]
+    fn bin_count() {
+        let samples = [0.0, 1.0, 2.0, 3.0, 4.0, f64::NAN];
+        assert_eq!(
+            Bins::Count(2).bin(&samples, None, 10),
+            Some(Binned {
+                start: 0.0,
+                width: 2.0,
+                // the maximum is in the last bin
+                counts: vec![2, 3],
+            })
+        );
+        // limited to the available cells
+        assert_eq!(
+            Bins::Count(8).bin(&samples, None, 4).map(|b| b.counts),
+            Some(vec![1, 1, 1, 2])
+        );
+    }
+
+    #[test]
+    fn bin_width() {
+        let samples = [1.0, 2.0, 7.0, 12.0];
+        assert_eq!(
+            Bins::Width(5.0).bin(&samples, None, 10),
+            Some(Binned {
+                start: 0.0,
+                width: 5.0,
+                counts: vec![2, 1, 1],
+            })
+        );
+        // the bins are merged if there are too many of them
+        assert_eq!(
+            Bins::Width(5.0).bin(&samples, None, 2),
+            Some(Binned {
+                start: 0.0,
+                width: 10.0,
+                counts: vec![3, 1],
+            })
+        );
+    }
+
+    #[test]
+    fn bin_range() {
+        let samples = [-5.0, 1.0, 2.0, 3.0, 15.0];
+        assert_eq!(
+            Bins::Count(2).bin(&samples, Some([0.0, 4.0]), 10),
+            Some(Binned {
+                start: 0.0,
+                width: 2.0,
+                counts: vec![1, 2],
+            })
+        );
+    }
+
+    #[test]
+    fn bin_freedman_diaconis() {
+        let samples: Vec<f64> = (0..64).map(f64::from).collect();
+        // IQR = 31.5, width = 2 * 31.5 / 4 = 15.75, 63 / 15.75 = 4 bins
+        let binned = Bins::FreedmanDiaconis.bin(&samples, None, 100).unwrap();
+        assert_eq!(binned.counts.len(), 4);
+        assert_eq!(binned.counts.iter().sum::<u64>(), 64);
+        // Sturges' rule when the IQR is zero: log2(8) + 1 = 4 bins
+        let samples = [0.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 2.0];
+        let binned = Bins::FreedmanDiaconis.bin(&samples, None, 100).unwrap();
+        assert_eq!(binned.counts, [1, 0, 6, 1]);
+    }
+
+    #[test]
+    fn bin_single_value() {
+        assert_eq!(
+            Bins::default().bin(&[3.0, 3.0], None, 10),
+            Some(Binned {
+                start: 2.5,
+                width: 1.0,
+                counts: vec![2],
+            })
+        );
+        assert_eq!(Bins::default().bin(&[f64::NAN], None, 10), None);
+    }
+}
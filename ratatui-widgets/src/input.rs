@@ -0,0 +1,512 @@
+//! The [`Input`] widget is used to edit a single line of text.
+use std::{borrow::Cow, fmt, sync::Arc};
+
+use ratatui_core::{
+    buffer::Buffer,
+    layout::{Position, Rect},
+    style::{Color, Modifier, Style, Styled},
+    text::{Line, Masked},
+    widgets::{StatefulWidget, Widget},
+};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+pub use self::state::InputState;
+use crate::block::{Block, BlockExt};
+
+mod state;
+
+/// A function that validates the value of an [`Input`]
+///
+/// It returns an error message if the value is invalid. See [`Input::validator`].
+pub type Validator = Arc<dyn Fn(&str) -> Result<(), String> + Send + Sync>;
+
+/// A widget to edit a single line of text
+///
+/// The value, cursor, scroll offset and completion are stored in an [`InputState`], which is
+/// edited by the application in response to key events. `Input` only configures how the state is
+/// displayed. The value is rendered in the first row of the area, and scrolled horizontally to
+/// keep the cursor visible when it is longer than the area. This fits in a [`Block`] that is three
+/// rows high, or one row high without borders.
+///
+/// When rendered, the screen position of the cursor is stored in the state. Pass
+/// [`InputState::cursor_position`] to `Frame::set_cursor_position` to show the terminal cursor.
+///
+/// # Fluent setters
+///
+/// - [`Input::block`] wraps the input in a [`Block`].
+/// - [`Input::style`] sets the base style of the widget.
+/// - [`Input::placeholder`] sets the text displayed when the input is empty.
+/// - [`Input::placeholder_style`] sets the style of the placeholder.
+/// - [`Input::completion_style`] sets the style of the suggested completion.
+/// - [`Input::validator`] sets a function that validates the value.
+/// - [`Input::error_style`] sets the style of the input when the value is invalid.
+/// - [`Input::mask_char`] hides the value, e.g. for passwords.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     layout::Rect,
+///     widgets::{Block, Input, InputState},
+///     Frame,
+/// };
+///
+/// # fn ui(frame: &mut Frame) {
+/// # let area = Rect::default();
+/// // This should be stored outside of the function in your application state.
+/// let mut state = InputState::default();
+///
+/// let input = Input::new()
+///     .block(Block::bordered().title("Port"))
+///     .placeholder("8080")
+///     .validator(|value| {
+///         value
+///             .parse::<u16>()
+///             .map(|_| ())
+///             .map_err(|err| err.to_string())
+///     });
+/// frame.render_stateful_widget(input, area, &mut state);
+/// if let Some(position) = state.cursor_position() {
+///     frame.set_cursor_position(position);
+/// }
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct Input<'a> {
+    /// An optional block to wrap the widget in
+    block: Option<Block<'a>>,
+    /// Style used as a base style for the widget
+    style: Style,
+    /// Text displayed when the input is empty
+    placeholder: Line<'a>,
+    /// Style applied to the placeholder
+    placeholder_style: Style,
+    /// Style applied to the suggested completion
+    completion_style: Style,
+    /// Function that validates the value
+    validator: Option<Validator>,
+    /// Style applied to the whole input when the value is invalid
+    error_style: Style,
+    /// Character that replaces each character of the value
+    mask_char: Option<char>,
+}
+
+const DEFAULT_PLACEHOLDER_STYLE: Style = Style::new().add_modifier(Modifier::DIM);
+const DEFAULT_COMPLETION_STYLE: Style = Style::new().add_modifier(Modifier::DIM);
+const DEFAULT_ERROR_STYLE: Style = Style::new().fg(Color::Red);
+
+impl Default for Input<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for Input<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Input")
+            .field("block", &self.block)
+            .field("style", &self.style)
+            .field("placeholder", &self.placeholder)
+            .field("placeholder_style", &self.placeholder_style)
+            .field("completion_style", &self.completion_style)
+            .field("validator", &self.validator.as_ref().map(|_| "Fn"))
+            .field("error_style", &self.error_style)
+            .field("mask_char", &self.mask_char)
+            .finish()
+    }
+}
+
+impl<'a> Input<'a> {
+    /// Creates a new input with the default styles, no placeholder and no validation.
+    pub const fn new() -> Self {
+        Self {
+            block: None,
+            style: Style::new(),
+            placeholder: Line {
+                style: Style::new(),
+                alignment: None,
+                spans: Vec::new(),
+            },
+            placeholder_style: DEFAULT_PLACEHOLDER_STYLE,
+            completion_style: DEFAULT_COMPLETION_STYLE,
+            validator: None,
+            error_style: DEFAULT_ERROR_STYLE,
+            mask_char: None,
+        }
+    }
+
+    /// Wraps the input with the given [`Block`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the base style of the widget
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the text displayed when the input is empty
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn placeholder<T: Into<Line<'a>>>(mut self, placeholder: T) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Sets the style of the placeholder
+    ///
+    /// Defaults to dimmed text.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn placeholder_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.placeholder_style = style.into();
+        self
+    }
+
+    /// Sets the style of the completion that is suggested after the value
+    ///
+    /// See [`InputState::set_completion`]. Defaults to dimmed text.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn completion_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.completion_style = style.into();
+        self
+    }
+
+    /// Sets the function that validates the value
+    ///
+    /// When the function returns an error, the input is rendered with the
+    /// [`error_style`](Self::error_style). Use [`Input::validate`] to get the error message.
+    ///
+    /// The function may capture state, e.g. a list of values that are already taken.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Input, InputState};
+    ///
+    /// let taken = vec!["alice".to_string(), "bob".to_string()];
+    /// let input = Input::new().validator(move |value| {
+    ///     if taken.iter().any(|name| name == value) {
+    ///         Err(format!("{value} is already taken"))
+    ///     } else {
+    ///         Ok(())
+    ///     }
+    /// });
+    /// assert!(input.validate(&InputState::new("bob")).is_err());
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn validator<F>(mut self, validator: F) -> Self
+    where
+        F: Fn(&str) -> Result<(), String> + Send + Sync + 'static,
+    {
+        self.validator = Some(Arc::new(validator));
+        self
+    }
+
+    /// Sets the style of the input when the value is invalid
+    ///
+    /// The style is patched onto the whole area of the input, including the block. Defaults to red
+    /// text.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn error_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.error_style = style.into();
+        self
+    }
+
+    /// Masks the value by displaying each character as `mask_char`
+    ///
+    /// This is useful for password fields. The completion is not displayed in this mode.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn mask_char(mut self, mask_char: char) -> Self {
+        self.mask_char = Some(mask_char);
+        self
+    }
+
+    /// Validates the value of the state with the [`validator`](Self::validator).
+    ///
+    /// Returns `Ok(())` if no validator is set.
+    pub fn validate(&self, state: &InputState) -> Result<(), String> {
+        self.validator
+            .as_ref()
+            .map_or(Ok(()), |validate| validate(&state.value))
+    }
+
+    /// Returns the symbol that is displayed for a grapheme.
+    fn display_symbol<'s>(&self, grapheme: &'s str) -> Cow<'s, str> {
+        self.mask_char.map_or(Cow::Borrowed(grapheme), |mask_char| {
+            Masked::new(grapheme, mask_char).value()
+        })
+    }
+
+    /// Adjusts the scroll offset of the state so that the cursor is inside the area, and returns
+    /// the x coordinate of the cursor.
+    fn scroll_to_cursor(&self, area: Rect, state: &mut InputState) -> u16 {
+        let widths = state
+            .value
+            .graphemes(true)
+            .map(|grapheme| self.display_symbol(grapheme).width());
+        let cursor = widths.clone().take(state.cursor).sum::<usize>();
+        let cursor = u16::try_from(cursor).unwrap_or(u16::MAX);
+        let total = u16::try_from(widths.sum::<usize>()).unwrap_or(u16::MAX);
+        // don't leave empty columns on the left when the end of the value fits in the area
+        let max_offset = total.saturating_add(1).saturating_sub(area.width);
+        state.offset = state
+            .offset
+            .min(max_offset)
+            .clamp(cursor.saturating_sub(area.width - 1), cursor);
+        area.x + cursor - state.offset
+    }
+}
+
+impl StatefulWidget for Input<'_> {
+    type State = InputState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidget::render(&self, area, buf, state);
+    }
+}
+
+impl StatefulWidget for &Input<'_> {
+    type State = InputState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        buf.set_style(area, self.style);
+        self.block.as_ref().render(area, buf);
+        let inner = self.block.inner_if_some(area);
+        state.cursor_position = None;
+        if inner.is_empty() {
+            return;
+        }
+        let row = Rect { height: 1, ..inner };
+        state.cursor_position = Some(Position::new(self.scroll_to_cursor(row, state), row.y));
+
+        if state.value.is_empty() {
+            buf.set_style(row, self.placeholder_style);
+            (&self.placeholder).render(row, buf);
+        }
+
+        let mut x = 0;
+        for grapheme in state.value.graphemes(true) {
+            let symbol = self.display_symbol(grapheme);
+            let width = symbol.width() as u16;
+            if x >= state.offset && x + width - state.offset <= row.width && width > 0 {
+                buf.set_stringn(
+                    row.x + x - state.offset,
+                    row.y,
+                    symbol,
+                    width as usize,
+                    Style::new(),
+                );
+            }
+            x = x.saturating_add(width);
+        }
+
+        let completion = state.completion.as_deref().filter(|completion| {
+            self.mask_char.is_none() && !completion.is_empty() && state.cursor == state.len()
+        });
+        if let Some(completion) = completion {
+            let x = x - state.offset;
+            if x < row.width {
+                buf.set_stringn(
+                    row.x + x,
+                    row.y,
+                    completion,
+                    (row.width - x) as usize,
+                    self.completion_style,
+                );
+            }
+        }
+
+        if self.validate(state).is_err() {
+            buf.set_style(area, self.error_style);
+        }
+    }
+}
+
+impl Styled for Input<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use ratatui_core::style::Stylize;
+
+    use super::*;
+    use crate::text_area::CursorMove;
+
+    fn not_empty(value: &str) -> Result<(), String> {
+        if value.is_empty() {
+            Err("value is required".to_string())
+        } else {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn new() {
+        let input = Input::new();
+        assert_eq!(input.style, Style::new());
+        assert_eq!(input.placeholder, Line::default());
+        assert!(input.validator.is_none());
+        assert_eq!(input.placeholder_style, DEFAULT_PLACEHOLDER_STYLE);
+        assert_eq!(input.completion_style, DEFAULT_COMPLETION_STYLE);
+        assert_eq!(input.error_style, DEFAULT_ERROR_STYLE);
+    }
+
+    #[test]
+    fn render() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 3));
+        let mut state = InputState::new("héllo").with_cursor(2);
+        Input::new()
+            .block(Block::bordered())
+            .render(buf.area, &mut buf, &mut state);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["┌──────┐", "│héllo │", "└──────┘"])
+        );
+        assert_eq!(state.cursor_position(), Some(Position::new(3, 1)));
+    }
+
+    #[test]
+    fn render_scrolls_horizontally() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 1));
+        let mut state = InputState::new("abc日本");
+        state.move_cursor(CursorMove::LineEnd);
+        Input::new().render(buf.area, &mut buf, &mut state);
+        // the cursor is after the value, and the partially visible wide character is skipped
+        assert_eq!(state.offset(), 4);
+        assert_eq!(buf, Buffer::with_lines([" 本 "]));
+        assert_eq!(state.cursor_position(), Some(Position::new(3, 0)));
+
+        state.set_cursor(3);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 1));
+        Input::new().render(buf.area, &mut buf, &mut state);
+        assert_eq!(state.offset(), 3);
+        assert_eq!(buf, Buffer::with_lines(["日本"]));
+        assert_eq!(state.cursor_position(), Some(Position::new(0, 0)));
+
+        // the offset shrinks when the value gets shorter
+        state.move_cursor(CursorMove::LineEnd);
+        state.delete_backward();
+        state.delete_backward();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 1));
+        Input::new().render(buf.area, &mut buf, &mut state);
+        assert_eq!(state.offset(), 0);
+        assert_eq!(buf, Buffer::with_lines(["abc "]));
+    }
+
+    #[test]
+    fn render_placeholder() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+        let mut state = InputState::default();
+        Input::new()
+            .placeholder("name")
+            .render(buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines(["name  "]);
+        expected.set_style(buf.area, Style::new().dim());
+        assert_eq!(buf, expected);
+        assert_eq!(state.cursor_position(), Some(Position::new(0, 0)));
+    }
+
+    #[test]
+    fn render_masked() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+        let mut state = InputState::new("pässwörd").with_completion("!");
+        state.move_cursor(CursorMove::LineEnd);
+        Input::new()
+            .mask_char('*')
+            .render(buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["***** "]));
+        assert_eq!(state.cursor_position(), Some(Position::new(5, 0)));
+    }
+
+    #[test]
+    fn render_completion() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 1));
+        let mut state = InputState::new("hel").with_completion("lo world");
+        state.move_cursor(CursorMove::LineEnd);
+        Input::new().render(buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines(["hello wo"]);
+        expected.set_style(Rect::new(3, 0, 5, 1), Style::new().dim());
+        assert_eq!(buf, expected);
+        assert_eq!(state.cursor_position(), Some(Position::new(3, 0)));
+
+        // the completion is only displayed when the cursor is at the end
+        state.set_cursor(0);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 1));
+        Input::new().render(buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["hel     "]));
+    }
+
+    #[test]
+    fn render_invalid() {
+        let input = Input::new()
+            .block(Block::bordered())
+            .validator(not_empty)
+            .error_style(Style::new().red());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 3));
+        let mut state = InputState::default();
+        StatefulWidget::render(&input, buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines(["┌──┐", "│  │", "└──┘"]);
+        expected.set_style(Rect::new(1, 1, 2, 1), Style::new().dim());
+        expected.set_style(buf.area, Style::new().red());
+        assert_eq!(buf, expected);
+        assert_eq!(input.validate(&state), Err("value is required".to_string()));
+
+        state.insert_char('a');
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 3));
+        StatefulWidget::render(&input, buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["┌──┐", "│a │", "└──┘"]));
+        assert_eq!(input.validate(&state), Ok(()));
+    }
+
+    #[test]
+    fn render_empty_area() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 2, 2));
+        let mut state = InputState::new("a");
+        Input::new()
+            .block(Block::bordered())
+            .render(buf.area, &mut buf, &mut state);
+        assert_eq!(state.cursor_position(), None);
+    }
+}
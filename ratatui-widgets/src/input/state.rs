@@ -0,0 +1,368 @@
+use ratatui_core::layout::Position;
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::text_area::CursorMove;
+
+/// State of the [`Input`] widget
+///
+/// The state holds the value being edited, the cursor, the horizontal scroll offset and an
+/// optional completion. The cursor is the index of a grapheme in the value, so it never ends up in
+/// the middle of a multi-byte or multi-codepoint character.
+///
+/// When the input is rendered, the scroll offset is adjusted to keep the cursor visible, and the
+/// position of the cursor on the screen is stored in the state. Pass it to
+/// `Frame::set_cursor_position` to show the terminal cursor there.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::widgets::{CursorMove, InputState};
+///
+/// let mut state = InputState::new("héllo");
+/// state.move_cursor(CursorMove::LineEnd);
+/// state.insert_str(", world");
+/// state.move_cursor(CursorMove::WordLeft);
+/// state.delete_backward();
+/// assert_eq!(state.value(), "héllo,world");
+/// assert_eq!(state.cursor(), 6);
+/// ```
+///
+/// [`Input`]: super::Input
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InputState {
+    pub(crate) value: String,
+    pub(crate) cursor: usize,
+    pub(crate) offset: u16,
+    pub(crate) completion: Option<String>,
+    pub(crate) cursor_position: Option<Position>,
+}
+
+impl InputState {
+    /// Creates a new state with the given value and the cursor at the start.
+    ///
+    /// Line breaks in the value are removed.
+    pub fn new<T: AsRef<str>>(value: T) -> Self {
+        Self {
+            value: single_line(value.as_ref()),
+            ..Self::default()
+        }
+    }
+
+    /// Sets the cursor, clamped to the length of the value
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_cursor(mut self, cursor: usize) -> Self {
+        self.set_cursor(cursor);
+        self
+    }
+
+    /// Sets the completion that is suggested after the value
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_completion<T: Into<String>>(mut self, completion: T) -> Self {
+        self.set_completion(Some(completion));
+        self
+    }
+
+    /// Returns the value.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Replaces the value and moves the cursor to its end.
+    pub fn set_value<T: AsRef<str>>(&mut self, value: T) {
+        self.value = single_line(value.as_ref());
+        self.cursor = self.len();
+        self.completion = None;
+    }
+
+    /// Clears the value and returns the previous one.
+    pub fn take_value(&mut self) -> String {
+        self.cursor = 0;
+        self.offset = 0;
+        self.completion = None;
+        std::mem::take(&mut self.value)
+    }
+
+    /// Returns true if the value is empty.
+    pub fn is_empty(&self) -> bool {
+        self.value.is_empty()
+    }
+
+    /// Returns the number of graphemes in the value.
+    pub fn len(&self) -> usize {
+        self.value.graphemes(true).count()
+    }
+
+    /// Returns the cursor, as the index of the grapheme it is placed before.
+    pub const fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Moves the cursor to the given grapheme index, clamped to the length of the value.
+    pub fn set_cursor(&mut self, cursor: usize) {
+        self.cursor = cursor.min(self.len());
+    }
+
+    /// Returns the horizontal scroll offset of the input, in columns.
+    ///
+    /// The offset is updated when rendering to keep the cursor visible.
+    pub const fn offset(&self) -> u16 {
+        self.offset
+    }
+
+    /// Returns the position of the cursor on the screen, as of the last render.
+    ///
+    /// This is `None` before the input is rendered, or if it was rendered into an empty area. Pass
+    /// it to `Frame::set_cursor_position` to show the terminal cursor there.
+    pub const fn cursor_position(&self) -> Option<Position> {
+        self.cursor_position
+    }
+
+    /// Returns the completion that is suggested after the value.
+    pub fn completion(&self) -> Option<&str> {
+        self.completion.as_deref()
+    }
+
+    /// Sets the completion that is suggested after the value.
+    ///
+    /// The completion is the text that would be appended to the value, and is displayed in a
+    /// dimmed style after it while the cursor is at the end of the value. It is cleared on every
+    /// edit, so it should be set again after the value changes.
+    pub fn set_completion<T: Into<String>>(&mut self, completion: Option<T>) {
+        self.completion = completion.map(|completion| single_line(&completion.into()));
+    }
+
+    /// Appends the completion to the value and moves the cursor to the end.
+    ///
+    /// Returns false if there was no completion.
+    pub fn accept_completion(&mut self) -> bool {
+        let Some(completion) = self.completion.take() else {
+            return false;
+        };
+        self.value.push_str(&completion);
+        self.cursor = self.len();
+        true
+    }
+
+    /// Moves the cursor.
+    ///
+    /// As the input has a single line, [`CursorMove::Up`] and [`CursorMove::Top`] move to the
+    /// start like [`CursorMove::LineStart`], and [`CursorMove::Down`] and [`CursorMove::Bottom`]
+    /// move to the end like [`CursorMove::LineEnd`].
+    pub fn move_cursor(&mut self, movement: CursorMove) {
+        let graphemes: Vec<&str> = self.value.graphemes(true).collect();
+        let mut cursor = self.cursor;
+        match movement {
+            CursorMove::Left => cursor = cursor.saturating_sub(1),
+            CursorMove::Right => cursor = (cursor + 1).min(graphemes.len()),
+            CursorMove::WordLeft => {
+                while cursor > 0 && is_whitespace(graphemes[cursor - 1]) {
+                    cursor -= 1;
+                }
+                while cursor > 0 && !is_whitespace(graphemes[cursor - 1]) {
+                    cursor -= 1;
+                }
+            }
+            CursorMove::WordRight => {
+                while cursor < graphemes.len() && !is_whitespace(graphemes[cursor]) {
+                    cursor += 1;
+                }
+                while cursor < graphemes.len() && is_whitespace(graphemes[cursor]) {
+                    cursor += 1;
+                }
+            }
+            CursorMove::Up | CursorMove::Top | CursorMove::LineStart => cursor = 0,
+            CursorMove::Down | CursorMove::Bottom | CursorMove::LineEnd => {
+                cursor = graphemes.len();
+            }
+        }
+        self.cursor = cursor;
+    }
+
+    /// Inserts a character at the cursor and moves the cursor after it.
+    ///
+    /// Line breaks are ignored.
+    pub fn insert_char(&mut self, c: char) {
+        self.insert_str(c.encode_utf8(&mut [0; 4]));
+    }
+
+    /// Inserts text at the cursor and moves the cursor after it.
+    ///
+    /// Line breaks are removed from the text.
+    #[allow(clippy::string_slice)] // the index is a char boundary
+    pub fn insert_str(&mut self, text: &str) {
+        let text = single_line(text);
+        let index = self.byte_index(self.cursor);
+        self.value.insert_str(index, &text);
+        // inserting can merge graphemes, e.g. when inserting a combining character
+        self.cursor = self.value[..index + text.len()].graphemes(true).count();
+        self.completion = None;
+    }
+
+    /// Deletes the grapheme before the cursor. Returns false if the cursor is at the start.
+    pub fn delete_backward(&mut self) -> bool {
+        if self.cursor == 0 {
+            return false;
+        }
+        let range = self.byte_index(self.cursor - 1)..self.byte_index(self.cursor);
+        self.value.replace_range(range, "");
+        self.cursor -= 1;
+        self.completion = None;
+        true
+    }
+
+    /// Deletes the grapheme after the cursor. Returns false if the cursor is at the end.
+    pub fn delete_forward(&mut self) -> bool {
+        if self.cursor == self.len() {
+            return false;
+        }
+        let range = self.byte_index(self.cursor)..self.byte_index(self.cursor + 1);
+        self.value.replace_range(range, "");
+        self.completion = None;
+        true
+    }
+
+    /// Deletes from the start of the word before the cursor to the cursor.
+    ///
+    /// Returns false if the cursor is at the start.
+    pub fn delete_word_backward(&mut self) -> bool {
+        if self.cursor == 0 {
+            return false;
+        }
+        let end = self.byte_index(self.cursor);
+        self.move_cursor(CursorMove::WordLeft);
+        let start = self.byte_index(self.cursor);
+        self.value.replace_range(start..end, "");
+        self.completion = None;
+        true
+    }
+
+    /// Returns the byte index of the grapheme at the given index, or the length of the value.
+    fn byte_index(&self, index: usize) -> usize {
+        self.value
+            .grapheme_indices(true)
+            .nth(index)
+            .map_or(self.value.len(), |(index, _)| index)
+    }
+}
+
+impl<T: AsRef<str>> From<T> for InputState {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+fn single_line(text: &str) -> String {
+    text.chars().filter(|&c| c != '\n' && c != '\r').collect()
+}
+
+fn is_whitespace(grapheme: &str) -> bool {
+    grapheme.chars().all(char::is_whitespace)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn new() {
+        let state = InputState::new("a\r\nb");
+        assert_eq!(state.value(), "ab");
+        assert_eq!(state.cursor(), 0);
+        assert_eq!(state.len(), 2);
+        assert!(InputState::default().is_empty());
+    }
+
+    #[test]
+    fn insert() {
+        let mut state = InputState::default();
+        state.insert_char('a');
+        state.insert_char('\n');
+        state.insert_str("日本👩‍👧");
+        assert_eq!(state.value(), "a日本👩‍👧");
+        assert_eq!(state.cursor(), 4);
+        state.set_cursor(1);
+        state.insert_char('é');
+        assert_eq!(state.value(), "aé日本👩‍👧");
+        assert_eq!(state.cursor(), 2);
+    }
+
+    #[test]
+    fn insert_combining_character() {
+        let mut state = InputState::new("e").with_cursor(1);
+        state.insert_char('\u{301}');
+        assert_eq!(state.value(), "e\u{301}");
+        assert_eq!(state.cursor(), 1);
+    }
+
+    #[test]
+    fn delete() {
+        let mut state = InputState::new("a日b").with_cursor(2);
+        assert!(state.delete_backward());
+        assert_eq!(state.value(), "ab");
+        assert_eq!(state.cursor(), 1);
+        assert!(state.delete_forward());
+        assert_eq!(state.value(), "a");
+        assert!(!state.delete_forward());
+        assert!(state.delete_backward());
+        assert!(!state.delete_backward());
+    }
+
+    #[test]
+    fn delete_word_backward() {
+        let mut state = InputState::new("foo bar  ").with_cursor(9);
+        assert!(state.delete_word_backward());
+        assert_eq!(state.value(), "foo ");
+        assert_eq!(state.cursor(), 4);
+    }
+
+    #[test]
+    fn move_cursor() {
+        let mut state = InputState::new("foo bär");
+        state.move_cursor(CursorMove::Right);
+        assert_eq!(state.cursor(), 1);
+        state.move_cursor(CursorMove::WordRight);
+        assert_eq!(state.cursor(), 4);
+        state.move_cursor(CursorMove::LineEnd);
+        assert_eq!(state.cursor(), 7);
+        state.move_cursor(CursorMove::Right);
+        assert_eq!(state.cursor(), 7);
+        state.move_cursor(CursorMove::WordLeft);
+        assert_eq!(state.cursor(), 4);
+        state.move_cursor(CursorMove::Left);
+        assert_eq!(state.cursor(), 3);
+        state.move_cursor(CursorMove::Bottom);
+        assert_eq!(state.cursor(), 7);
+        state.move_cursor(CursorMove::Up);
+        assert_eq!(state.cursor(), 0);
+    }
+
+    #[test]
+    fn completion() {
+        let mut state = InputState::new("hel").with_completion("lo");
+        assert_eq!(state.completion(), Some("lo"));
+        assert!(state.accept_completion());
+        assert_eq!(state.value(), "hello");
+        assert_eq!(state.cursor(), 5);
+        assert!(!state.accept_completion());
+
+        // editing clears the completion
+        state.set_completion(Some("!"));
+        state.insert_char(' ');
+        assert_eq!(state.completion(), None);
+    }
+
+    #[test]
+    fn take_value() {
+        let mut state = InputState::new("hello").with_cursor(3);
+        assert_eq!(state.take_value(), "hello");
+        assert!(state.is_empty());
+        assert_eq!(state.cursor(), 0);
+    }
+}
@@ -28,35 +28,57 @@
 //!
 //! - [`BarChart`]: displays multiple datasets as bars with optional grouping.
 //! - [`Block`]: a basic widget that draws a block with optional borders, titles, and styles.
+//! - [`BoxPlot`]: displays the quartiles and outliers of distributions of samples.
 //! - [`calendar::Monthly`]: displays a single month.
 //! - [`Canvas`]: draws arbitrary shapes using drawing characters.
 //! - [`Chart`]: displays multiple datasets as lines or scatter graphs.
 //! - [`Clear`]: clears the area it occupies. Useful to render over previously drawn widgets.
 //! - [`Gauge`]: displays progress percentage using block characters.
+//! - [`Heatmap`]: displays a matrix of values as colored cells.
+//! - [`Histogram`]: displays the distribution of samples as bars.
+//! - [`Input`]: edits a single line of text.
 //! - [`LineGauge`]: displays progress as a line.
 //! - [`List`]: displays a list of items and allows selection.
+//! - [`Menu`]: displays a popup menu of actions with nested submenus.
+//! - [`MenuBar`]: displays a bar of menus opening dropdowns.
 //! - [`RatatuiLogo`]: displays the Ratatui logo.
 //! - [`Paragraph`]: displays a paragraph of optionally styled and wrapped text.
+//! - [`PieChart`]: displays the shares of a total as a pie or donut chart.
+//! - [`Popup`]: displays content in a popup positioned over other widgets.
+//! - [`Dialog`]: displays a modal popup with a row of buttons.
 //! - [`Scrollbar`]: displays a scrollbar.
 //! - [`Sparkline`]: displays a single dataset as a sparkline.
 //! - [`Table`]: displays multiple rows and columns in a grid and allows selection.
 //! - [`Tabs`]: displays a tab bar and allows selection.
+//! - [`TextArea`]: edits multi-line text.
+//! - [`Tree`]: displays hierarchical data with expandable nodes and allows selection.
 //!
 //! [`BarChart`]: crate::barchart::BarChart
 //! [`Block`]: crate::block::Block
+//! [`BoxPlot`]: crate::boxplot::BoxPlot
 //! [`calendar::Monthly`]: crate::calendar::Monthly
 //! [`Canvas`]: crate::canvas::Canvas
 //! [`Chart`]: crate::chart::Chart
 //! [`Clear`]: crate::clear::Clear
 //! [`Gauge`]: crate::gauge::Gauge
+//! [`Heatmap`]: crate::heatmap::Heatmap
+//! [`Histogram`]: crate::histogram::Histogram
+//! [`Input`]: crate::input::Input
 //! [`LineGauge`]: crate::gauge::LineGauge
 //! [`List`]: crate::list::List
+//! [`Menu`]: crate::menu::Menu
+//! [`MenuBar`]: crate::menu::MenuBar
 //! [`RatatuiLogo`]: crate::logo::RatatuiLogo
 //! [`Paragraph`]: crate::paragraph::Paragraph
+//! [`PieChart`]: crate::piechart::PieChart
+//! [`Popup`]: crate::popup::Popup
+//! [`Dialog`]: crate::popup::Dialog
 //! [`Scrollbar`]: crate::scrollbar::Scrollbar
 //! [`Sparkline`]: crate::sparkline::Sparkline
 //! [`Table`]: crate::table::Table
 //! [`Tabs`]: crate::tabs::Tabs
+//! [`TextArea`]: crate::text_area::TextArea
+//! [`Tree`]: crate::tree::Tree
 //!
 //! All these widgets are re-exported directly under `ratatui::widgets` in the `ratatui` crate.
 #![cfg_attr(feature = "document-features", doc = "\n## Features")]
@@ -73,19 +95,30 @@
 pub mod barchart;
 pub mod block;
 pub mod borders;
+pub mod boxplot;
 pub mod canvas;
 pub mod chart;
 pub mod clear;
 pub mod gauge;
+pub mod heatmap;
+pub mod histogram;
+pub mod input;
 pub mod list;
 pub mod logo;
+pub mod menu;
 pub mod paragraph;
+pub mod piechart;
+pub mod popup;
 pub mod scrollbar;
 pub mod sparkline;
 pub mod table;
 pub mod tabs;
+pub mod text_area;
+pub mod tree;
 
+mod marks;
 mod reflow;
+mod signed;
 
 #[cfg(feature = "calendar")]
 pub mod calendar;
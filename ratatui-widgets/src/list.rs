@@ -3,11 +3,17 @@
 use ratatui_core::style::{Style, Styled};
 use strum::{Display, EnumString};
 
-pub use self::{item::ListItem, state::ListState};
+pub use self::{
+    item::ListItem,
+    source::{ListSource, VirtualList},
+    state::ListState,
+};
+pub use crate::marks::Marks;
 use crate::{block::Block, table::HighlightSpacing};
 
 mod item;
 mod rendering;
+mod source;
 mod state;
 
 /// A widget to display several items among which one can be selected (optional)
@@ -36,6 +42,8 @@ mod state;
 /// - [`List::highlight_symbol`] sets the symbol to be displayed in front of the selected item.
 /// - [`List::repeat_highlight_symbol`] sets whether to repeat the symbol and style over selected
 ///   multi-line items
+/// - [`List::marked_style`] sets the style of the marked items.
+/// - [`List::mark_symbol`] sets the symbol to be displayed in front of the marked items.
 /// - [`List::direction`] sets the list direction
 ///
 /// # Examples
@@ -119,6 +127,10 @@ pub struct List<'a> {
     pub(crate) highlight_symbol: Option<&'a str>,
     /// Whether to repeat the highlight symbol for each line of the selected item
     pub(crate) repeat_highlight_symbol: bool,
+    /// Style used to render the marked items
+    pub(crate) marked_style: Style,
+    /// Symbol in front of the marked items
+    pub(crate) mark_symbol: Option<&'a str>,
     /// Decides when to allocate spacing for the selection symbol
     pub(crate) highlight_spacing: HighlightSpacing,
     /// How many items to try to keep visible before and after the selected item
@@ -344,6 +356,61 @@ impl<'a> List<'a> {
         self
     }
 
+    /// Set the style of the marked items
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// The items marked in the [`ListState`] are the ones picked for an operation when several
+    /// items can be selected, see [`ListState::marks_mut`]. This style is applied to the entire item,
+    /// and the [highlight style](List::highlight_style) is applied over it when the marked item is
+    /// also the selected one.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::{
+    ///     style::{Style, Stylize},
+    ///     widgets::List,
+    /// };
+    ///
+    /// let items = ["Item 1", "Item 2"];
+    /// let list = List::new(items).marked_style(Style::new().yellow());
+    /// ```
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    /// [`ListState`]: crate::list::ListState
+    /// [`ListState::marks_mut`]: crate::list::ListState::marks_mut
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn marked_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.marked_style = style.into();
+        self
+    }
+
+    /// Set the symbol to be displayed in front of the marked items
+    ///
+    /// The symbol is displayed in the same column as the
+    /// [highlight symbol](List::highlight_symbol), which wins for the selected item. The column is
+    /// as wide as the widest of the two symbols. By default there are no mark symbol.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::List;
+    ///
+    /// let items = ["Item 1", "Item 2"];
+    /// let list = List::new(items).highlight_symbol("> ").mark_symbol("* ");
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn mark_symbol(mut self, mark_symbol: &'a str) -> Self {
+        self.mark_symbol = Some(mark_symbol);
+        self
+    }
+
     /// Set when to show the highlight spacing
     ///
     /// The highlight spacing is the spacing that is allocated for the selection symbol (if enabled)
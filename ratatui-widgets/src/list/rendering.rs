@@ -7,7 +7,7 @@ use unicode_width::UnicodeWidthStr;
 
 use crate::{
     block::BlockExt,
-    list::{List, ListDirection, ListState},
+    list::{source::Items, List, ListDirection, ListState},
 };
 
 impl Widget for List<'_> {
@@ -35,6 +35,24 @@ impl StatefulWidget for &List<'_> {
     type State = ListState;
 
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        self.render_items(self.items.as_slice(), area, buf, state);
+    }
+}
+
+impl List<'_> {
+    /// Renders the items, which are either the items of the list or a [`ListSource`].
+    ///
+    /// Only the items in the visible window are accessed, apart from their heights which are
+    /// queried around the offset and the selected item to compute the window.
+    ///
+    /// [`ListSource`]: crate::list::ListSource
+    pub(crate) fn render_items<I: Items + ?Sized>(
+        &self,
+        items: &I,
+        area: Rect,
+        buf: &mut Buffer,
+        state: &mut ListState,
+    ) {
         buf.set_style(area, self.style);
         self.block.as_ref().render(area, buf);
         let list_area = self.block.inner_if_some(area);
@@ -43,106 +61,124 @@ impl StatefulWidget for &List<'_> {
             return;
         }
 
-        if self.items.is_empty() {
+        // Forget the marks of the items that don't exist anymore
+        state.marks.truncate(items.len());
+
+        if items.len() == 0 {
             state.select(None);
             return;
         }
 
         // If the selected index is out of bounds, set it to the last item
-        if state.selected.is_some_and(|s| s >= self.items.len()) {
-            state.select(Some(self.items.len().saturating_sub(1)));
+        if state.selected.is_some_and(|s| s >= items.len()) {
+            state.select(Some(items.len().saturating_sub(1)));
         }
 
         let list_height = list_area.height as usize;
 
         let (first_visible_index, last_visible_index) =
-            self.get_items_bounds(state.selected, state.offset, list_height);
+            self.get_items_bounds(items, state.selected, state.offset, list_height);
 
         // Important: this changes the state's offset to be the beginning of the now viewable items
         state.offset = first_visible_index;
 
-        // Get our set highlighted symbol (if one was set)
+        // Get our set highlighted and mark symbols (if they were set)
         let highlight_symbol = self.highlight_symbol.unwrap_or("");
-        let blank_symbol = " ".repeat(highlight_symbol.width());
+        let mark_symbol = self.mark_symbol.unwrap_or("");
+        let symbol_width = highlight_symbol.width().max(mark_symbol.width());
+        let blank_symbol = " ".repeat(symbol_width);
 
         let mut current_height = 0;
-        let selection_spacing = self.highlight_spacing.should_add(state.selected.is_some());
-        for (i, item) in self
-            .items
-            .iter()
-            .enumerate()
-            .skip(state.offset)
-            .take(last_visible_index - first_visible_index)
-        {
-            let (x, y) = if self.direction == ListDirection::BottomToTop {
-                current_height += item.height() as u16;
-                (list_area.left(), list_area.bottom() - current_height)
-            } else {
-                let pos = (list_area.left(), list_area.top() + current_height);
-                current_height += item.height() as u16;
-                pos
-            };
-
-            let row_area = Rect {
-                x,
-                y,
-                width: list_area.width,
-                height: item.height() as u16,
-            };
-
-            let item_style = self.style.patch(item.style);
-            buf.set_style(row_area, item_style);
-
-            let is_selected = state.selected.map_or(false, |s| s == i);
-
-            let item_area = if selection_spacing {
-                let highlight_symbol_width = self.highlight_symbol.unwrap_or("").width() as u16;
-                Rect {
-                    x: row_area.x + highlight_symbol_width,
-                    width: row_area.width.saturating_sub(highlight_symbol_width),
-                    ..row_area
-                }
-            } else {
-                row_area
-            };
-            Widget::render(&item.content, item_area, buf);
-
-            for j in 0..item.content.height() {
-                // if the item is selected, we need to display the highlight symbol:
-                // - either for the first line of the item only,
-                // - or for each line of the item if the appropriate option is set
-                let symbol = if is_selected && (j == 0 || self.repeat_highlight_symbol) {
-                    highlight_symbol
+        let selection_spacing = self
+            .highlight_spacing
+            .should_add(state.selected.is_some() || !state.marks.is_empty());
+        for i in first_visible_index..last_visible_index {
+            items.with_item(i, |item| {
+                let (x, y) = if self.direction == ListDirection::BottomToTop {
+                    current_height += item.height() as u16;
+                    (list_area.left(), list_area.bottom() - current_height)
+                } else {
+                    let pos = (list_area.left(), list_area.top() + current_height);
+                    current_height += item.height() as u16;
+                    pos
+                };
+
+                let row_area = Rect {
+                    x,
+                    y,
+                    width: list_area.width,
+                    height: item.height() as u16,
+                };
+
+                let item_style = self.style.patch(item.style);
+                buf.set_style(row_area, item_style);
+
+                let is_selected = state.selected.map_or(false, |s| s == i);
+                let is_marked = state.marks.contains(i);
+
+                let item_area = if selection_spacing {
+                    let symbol_width = symbol_width as u16;
+                    Rect {
+                        x: row_area.x + symbol_width,
+                        width: row_area.width.saturating_sub(symbol_width),
+                        ..row_area
+                    }
                 } else {
-                    &blank_symbol
+                    row_area
                 };
-                if selection_spacing {
-                    buf.set_stringn(
-                        x,
-                        y + j as u16,
-                        symbol,
-                        list_area.width as usize,
-                        item_style,
-                    );
+                Widget::render(&item.content, item_area, buf);
+
+                for j in 0..item.content.height() {
+                    // if the item is selected, we need to display the highlight symbol:
+                    // - either for the first line of the item only,
+                    // - or for each line of the item if the appropriate option is set
+                    // otherwise, the mark symbol is displayed in the same way for marked items
+                    let symbol = if j > 0 && !self.repeat_highlight_symbol {
+                        &blank_symbol
+                    } else if is_selected {
+                        highlight_symbol
+                    } else if is_marked {
+                        mark_symbol
+                    } else {
+                        &blank_symbol
+                    };
+                    if selection_spacing {
+                        buf.set_stringn(
+                            x,
+                            y + j as u16,
+                            &blank_symbol,
+                            list_area.width as usize,
+                            item_style,
+                        );
+                        buf.set_stringn(
+                            x,
+                            y + j as u16,
+                            symbol,
+                            list_area.width as usize,
+                            item_style,
+                        );
+                    }
                 }
-            }
 
-            if is_selected {
-                buf.set_style(row_area, self.highlight_style);
-            }
+                if is_marked {
+                    buf.set_style(row_area, self.marked_style);
+                }
+                if is_selected {
+                    buf.set_style(row_area, self.highlight_style);
+                }
+            });
         }
     }
-}
 
-impl List<'_> {
     /// Given an offset, calculate which items can fit in a given area
-    fn get_items_bounds(
+    fn get_items_bounds<I: Items + ?Sized>(
         &self,
+        items: &I,
         selected: Option<usize>,
         offset: usize,
         max_height: usize,
     ) -> (usize, usize) {
-        let offset = offset.min(self.items.len().saturating_sub(1));
+        let offset = offset.min(items.len().saturating_sub(1));
 
         // Note: visible here implies visible in the given area
         let mut first_visible_index = offset;
@@ -153,12 +189,13 @@ impl List<'_> {
 
         // Calculate the last visible index and total height of the items
         // that will fit in the available space
-        for item in self.items.iter().skip(offset) {
-            if height_from_offset + item.height() > max_height {
+        for index in offset..items.len() {
+            let height = items.height(index);
+            if height_from_offset + height > max_height {
                 break;
             }
 
-            height_from_offset += item.height();
+            height_from_offset += height;
 
             last_visible_index += 1;
         }
@@ -168,6 +205,7 @@ impl List<'_> {
         // None.
         let index_to_display = self
             .apply_scroll_padding_to_selected_index(
+                items,
                 selected,
                 max_height,
                 first_visible_index,
@@ -178,38 +216,40 @@ impl List<'_> {
         // Recall that last_visible_index is the index of what we
         // can render up to in the given space after the offset
         // If we have an item selected that is out of the viewable area (or
-        // the offset is still set), we still need to show this item
-        while index_to_display >= last_visible_index {
-            height_from_offset =
-                height_from_offset.saturating_add(self.items[last_visible_index].height());
-
-            last_visible_index += 1;
-
-            // Now we need to hide previous items since we didn't have space
-            // for the selected/offset item
-            while height_from_offset > max_height {
-                height_from_offset =
-                    height_from_offset.saturating_sub(self.items[first_visible_index].height());
-
-                // Remove this item to view by starting at the next item index
-                first_visible_index += 1;
+        // the offset is still set), we still need to show this item. The window then ends with
+        // that item, and we fill the available space with the items before it, without going
+        // back past the first visible item.
+        if index_to_display >= last_visible_index {
+            let offset = first_visible_index;
+            last_visible_index = index_to_display + 1;
+            first_visible_index = last_visible_index;
+            height_from_offset = 0;
+            while first_visible_index > offset {
+                let height = items.height(first_visible_index - 1);
+                if height_from_offset + height > max_height {
+                    break;
+                }
+                height_from_offset += height;
+                first_visible_index -= 1;
             }
         }
 
         // Here we're doing something similar to what we just did above
-        // If the selected item index is not in the viewable area, let's try to show the item
-        while index_to_display < first_visible_index {
-            first_visible_index -= 1;
-
-            height_from_offset =
-                height_from_offset.saturating_add(self.items[first_visible_index].height());
-
-            // Don't show an item if it is beyond our viewable height
-            while height_from_offset > max_height {
-                last_visible_index -= 1;
-
-                height_from_offset =
-                    height_from_offset.saturating_sub(self.items[last_visible_index].height());
+        // If the selected item index is not in the viewable area, the window starts with the
+        // item, and we fill the available space with the items after it, without going past the
+        // last visible item.
+        if index_to_display < first_visible_index {
+            let end = last_visible_index;
+            first_visible_index = index_to_display;
+            last_visible_index = first_visible_index;
+            height_from_offset = 0;
+            while last_visible_index < end {
+                let height = items.height(last_visible_index);
+                if height_from_offset + height > max_height {
+                    break;
+                }
+                height_from_offset += height;
+                last_visible_index += 1;
             }
         }
 
@@ -220,14 +260,15 @@ impl List<'_> {
     /// selected item on screen even with items of inconsistent sizes
     ///
     /// This function is sensitive to how the bounds checking function handles item height
-    fn apply_scroll_padding_to_selected_index(
+    fn apply_scroll_padding_to_selected_index<I: Items + ?Sized>(
         &self,
+        items: &I,
         selected: Option<usize>,
         max_height: usize,
         first_visible_index: usize,
         last_visible_index: usize,
     ) -> Option<usize> {
-        let last_valid_index = self.items.len().saturating_sub(1);
+        let last_valid_index = items.len().saturating_sub(1);
         let selected = selected?.min(last_valid_index);
 
         // The bellow loop handles situations where the list item sizes may not be consistent,
@@ -242,7 +283,7 @@ impl List<'_> {
                     .saturating_add(scroll_padding)
                     .min(last_valid_index)
             {
-                height_around_selected += self.items[index].height();
+                height_around_selected += items.height(index);
             }
             if height_around_selected <= max_height {
                 break;
@@ -785,6 +826,37 @@ mod tests {
         }
     }
 
+    #[test]
+    fn marked_items() {
+        let list = List::new(["Item 0", "Item 1\nLine 2", "Item 2", "Item 3"])
+            .highlight_symbol(">>")
+            .mark_symbol("*")
+            .marked_style(Style::default().fg(Color::Blue))
+            .highlight_style(Style::default().bg(Color::Yellow));
+        let mut state = ListState::default()
+            .with_selected(Some(2))
+            .with_marked([1, 2, 7]);
+        let buffer = stateful_widget(list, &mut state, 10, 5);
+        let expected = Buffer::with_lines([
+            "  Item 0  ".into(),
+            "* Item 1  ".blue(),
+            "  Line 2  ".blue(),
+            ">>Item 2  ".blue().on_yellow(),
+            "  Item 3  ".into(),
+        ]);
+        assert_eq!(buffer, expected);
+        // the marks out of bounds are removed
+        assert_eq!(state.marks().iter().collect::<Vec<_>>(), [1, 2]);
+    }
+
+    #[test]
+    fn marks_allocate_highlight_spacing() {
+        let list = List::new(["Item 0", "Item 1"]).mark_symbol("+");
+        let mut state = ListState::default().with_marked([1]);
+        let buffer = stateful_widget(list, &mut state, 8, 2);
+        assert_eq!(buffer, Buffer::with_lines([" Item 0 ", "+Item 1 "]));
+    }
+
     #[test]
     fn repeat_highlight_symbol() {
         let list = List::new(["Item 0\nLine 2", "Item 1", "Item 2"])
@@ -0,0 +1,322 @@
+use ratatui_core::{
+    buffer::Buffer,
+    layout::Rect,
+    widgets::{StatefulWidget, Widget},
+};
+
+use crate::list::{List, ListItem, ListState};
+
+/// A lazy source of items for a [`List`]
+///
+/// A source only has to know how many items there are and to produce the items that are
+/// displayed, which allows rendering lists of millions of items without building a [`ListItem`]
+/// for each of them on every frame. When rendering, [`ListSource::item`] is only called for the
+/// items in the visible window, and [`ListSource::item_height`] for the items around the offset
+/// and the selected item that are needed to compute that window.
+///
+/// Use [`List::source`] to render a list from a source.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     layout::Rect,
+///     widgets::{List, ListItem, ListSource, ListState},
+///     Frame,
+/// };
+///
+/// struct Numbers(usize);
+///
+/// impl ListSource for Numbers {
+///     fn len(&self) -> usize {
+///         self.0
+///     }
+///
+///     fn item(&self, index: usize) -> ListItem<'_> {
+///         ListItem::new(format!("Item {index}"))
+///     }
+/// }
+///
+/// # fn ui(frame: &mut Frame) {
+/// # let area = Rect::default();
+/// let mut state = ListState::default();
+/// state.select_last();
+/// let list = List::default().highlight_symbol(">>").source(Numbers(5_000_000));
+/// frame.render_stateful_widget(list, area, &mut state);
+/// # }
+/// ```
+pub trait ListSource {
+    /// Returns the number of items.
+    fn len(&self) -> usize;
+
+    /// Returns true if the source contains no items.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the item at the given index, which is always lower than [`ListSource::len`].
+    fn item(&self, index: usize) -> ListItem<'_>;
+
+    /// Returns the height of the item at the given index.
+    ///
+    /// The default implementation builds the item to measure it. Override it if the height is
+    /// known without building the item, e.g. when all the items are single lines.
+    fn item_height(&self, index: usize) -> usize {
+        self.item(index).height()
+    }
+}
+
+impl<S: ListSource + ?Sized> ListSource for &S {
+    fn len(&self) -> usize {
+        S::len(self)
+    }
+
+    fn is_empty(&self) -> bool {
+        S::is_empty(self)
+    }
+
+    fn item(&self, index: usize) -> ListItem<'_> {
+        S::item(self, index)
+    }
+
+    fn item_height(&self, index: usize) -> usize {
+        S::item_height(self, index)
+    }
+}
+
+/// A [`List`] that renders its items from a [`ListSource`]
+///
+/// This is created with [`List::source`]. The items of the list are ignored, everything else
+/// (block, styles, highlight symbol, direction, scroll padding...) is used as is.
+///
+/// It is a [`StatefulWidget`] that works with the same [`ListState`] as the [`List`], so the
+/// selection and the offset can be moved with the usual methods, e.g. [`ListState::select_last`].
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct VirtualList<'a, S> {
+    list: List<'a>,
+    source: S,
+}
+
+impl<'a> List<'a> {
+    /// Renders the list from a lazy [`ListSource`] instead of its items
+    ///
+    /// Only the visible items are queried from the source, so this is suitable for lists with a
+    /// very large number of items. The items of the list are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{List, ListItem, ListSource};
+    ///
+    /// struct Lines(Vec<String>);
+    ///
+    /// impl ListSource for Lines {
+    ///     fn len(&self) -> usize {
+    ///         self.0.len()
+    ///     }
+    ///
+    ///     fn item(&self, index: usize) -> ListItem<'_> {
+    ///         ListItem::new(self.0[index].as_str())
+    ///     }
+    /// }
+    ///
+    /// let lines = Lines(vec!["a".into(), "b".into()]);
+    /// let list = List::default().source(&lines);
+    /// ```
+    pub const fn source<S: ListSource>(self, source: S) -> VirtualList<'a, S> {
+        VirtualList { list: self, source }
+    }
+}
+
+impl<S: ListSource> VirtualList<'_, S> {
+    /// Returns the source of the items.
+    pub const fn source(&self) -> &S {
+        &self.source
+    }
+}
+
+impl<S: ListSource> Widget for VirtualList<'_, S> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl<S: ListSource> Widget for &VirtualList<'_, S> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut state = ListState::default();
+        StatefulWidget::render(self, area, buf, &mut state);
+    }
+}
+
+impl<S: ListSource> StatefulWidget for VirtualList<'_, S> {
+    type State = ListState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidget::render(&self, area, buf, state);
+    }
+}
+
+impl<S: ListSource> StatefulWidget for &VirtualList<'_, S> {
+    type State = ListState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        self.list
+            .render_items(&SourceItems(&self.source), area, buf, state);
+    }
+}
+
+/// The items rendered by a list, either a slice of [`ListItem`]s or a [`ListSource`]
+pub(crate) trait Items {
+    /// Returns the number of items.
+    fn len(&self) -> usize;
+
+    /// Returns the height of the item at the given index.
+    fn height(&self, index: usize) -> usize;
+
+    /// Calls `f` with the item at the given index.
+    fn with_item<R>(&self, index: usize, f: impl FnOnce(&ListItem<'_>) -> R) -> R;
+}
+
+impl Items for [ListItem<'_>] {
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    fn height(&self, index: usize) -> usize {
+        self[index].height()
+    }
+
+    fn with_item<R>(&self, index: usize, f: impl FnOnce(&ListItem<'_>) -> R) -> R {
+        f(&self[index])
+    }
+}
+
+struct SourceItems<'a, S: ?Sized>(&'a S);
+
+impl<S: ListSource + ?Sized> Items for SourceItems<'_, S> {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn height(&self, index: usize) -> usize {
+        self.0.item_height(index)
+    }
+
+    fn with_item<R>(&self, index: usize, f: impl FnOnce(&ListItem<'_>) -> R) -> R {
+        f(&self.0.item(index))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    /// A source of single line items that records which items were built
+    struct Numbers {
+        len: usize,
+        built: RefCell<Vec<usize>>,
+    }
+
+    impl Numbers {
+        const fn new(len: usize) -> Self {
+            Self {
+                len,
+                built: RefCell::new(Vec::new()),
+            }
+        }
+    }
+
+    impl ListSource for Numbers {
+        fn len(&self) -> usize {
+            self.len
+        }
+
+        fn item(&self, index: usize) -> ListItem<'_> {
+            self.built.borrow_mut().push(index);
+            ListItem::new(format!("Item {index}"))
+        }
+
+        fn item_height(&self, _index: usize) -> usize {
+            1
+        }
+    }
+
+    #[test]
+    fn renders_only_visible_items() {
+        let source = Numbers::new(5_000_000);
+        let mut state = ListState::default().with_offset(1_000);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 9, 3));
+        StatefulWidget::render(
+            List::default().source(&source),
+            buf.area,
+            &mut buf,
+            &mut state,
+        );
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["Item 1000", "Item 1001", "Item 1002"])
+        );
+        assert_eq!(*source.built.borrow(), [1_000, 1_001, 1_002]);
+    }
+
+    #[test]
+    fn select_last() {
+        let source = Numbers::new(5_000_000);
+        let mut state = ListState::default();
+        state.select_last();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 13, 3));
+        let list = List::default().highlight_symbol(">").source(&source);
+        StatefulWidget::render(&list, buf.area, &mut buf, &mut state);
+        assert_eq!(
+            buf,
+            Buffer::with_lines([" Item 4999997", " Item 4999998", ">Item 4999999"])
+        );
+        assert_eq!(state.selected(), Some(4_999_999));
+        assert_eq!(state.offset(), 4_999_997);
+        assert_eq!(*source.built.borrow(), [4_999_997, 4_999_998, 4_999_999]);
+
+        state.select_first();
+        source.built.borrow_mut().clear();
+        StatefulWidget::render(&list, buf.area, &mut buf, &mut state);
+        assert_eq!(state.offset(), 0);
+        assert_eq!(*source.built.borrow(), [0, 1, 2]);
+    }
+
+    #[test]
+    fn item_heights() {
+        struct Tall;
+
+        impl ListSource for Tall {
+            fn len(&self) -> usize {
+                10
+            }
+
+            fn item(&self, index: usize) -> ListItem<'_> {
+                ListItem::new(format!("{index}\n-"))
+            }
+        }
+
+        let mut state = ListState::default().with_selected(Some(3));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 2, 4));
+        StatefulWidget::render(List::default().source(Tall), buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["2 ", "- ", "3 ", "- "]));
+        assert_eq!(state.offset(), 2);
+    }
+
+    #[test]
+    fn empty_source() {
+        let mut state = ListState::default().with_selected(Some(1));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 2));
+        StatefulWidget::render(
+            List::default().source(Numbers::new(0)),
+            buf.area,
+            &mut buf,
+            &mut state,
+        );
+        assert_eq!(state.selected(), None);
+    }
+}
@@ -1,3 +1,5 @@
+use crate::marks::Marks;
+
 /// State of the [`List`] widget
 ///
 /// This state can be used to scroll through items and select one. When the list is rendered as a
@@ -5,12 +7,19 @@
 /// that the selected item is visible. This will modify the [`ListState`] object passed to the
 /// `Frame::render_stateful_widget` method.
 ///
-/// The state consists of two fields:
+/// The state consists of these fields:
 /// - [`offset`]: the index of the first item to be displayed
 /// - [`selected`]: the index of the selected item, which can be `None` if no item is selected
+/// - [`marks`]: the indexes of the marked items, for lists where several items can be selected
 ///
 /// [`offset`]: ListState::offset()
 /// [`selected`]: ListState::selected()
+/// [`marks`]: ListState::marks()
+///
+/// The selected item is the one the cursor is on, while the marked items are the ones picked for
+/// an operation, e.g. with the space key. They are displayed with [`List::marked_style`] and
+/// [`List::mark_symbol`]. Ranges of items can be marked from an anchor, like with shift-click,
+/// with [`ListState::mark_range_to`].
 ///
 /// See the list in the [Examples] directory for a more in depth example of the various
 /// configuration options and for how to handle state.
@@ -42,11 +51,18 @@
 /// ```
 ///
 /// [`List`]: super::List
+/// [`List::marked_style`]: super::List::marked_style
+/// [`List::mark_symbol`]: super::List::mark_symbol
 #[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ListState {
     pub(crate) offset: usize,
     pub(crate) selected: Option<usize>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Marks::is_empty")
+    )]
+    pub(crate) marks: Marks,
 }
 
 impl ListState {
@@ -84,6 +100,23 @@ impl ListState {
         self
     }
 
+    /// Sets the indexes of the marked items
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ListState;
+    ///
+    /// let state = ListState::default().with_marked([1, 3]);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_marked<I: IntoIterator<Item = usize>>(mut self, marked: I) -> Self {
+        self.marks = marked.into_iter().collect();
+        self
+    }
+
     /// Index of the first item to be displayed
     ///
     /// # Examples
@@ -233,6 +266,62 @@ impl ListState {
         self.select(Some(usize::MAX));
     }
 
+    /// The marked items
+    ///
+    /// The indexes that are out of bounds are removed when the list is rendered.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ListState;
+    ///
+    /// let state = ListState::default().with_marked([3, 1]);
+    /// assert_eq!(state.marks().iter().collect::<Vec<_>>(), [1, 3]);
+    /// ```
+    pub const fn marks(&self) -> &Marks {
+        &self.marks
+    }
+
+    /// Mutable reference to the marked items
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ListState;
+    ///
+    /// let mut state = ListState::default().with_selected(Some(2));
+    /// if let Some(index) = state.selected() {
+    ///     state.marks_mut().toggle(index);
+    /// }
+    /// ```
+    pub fn marks_mut(&mut self) -> &mut Marks {
+        &mut self.marks
+    }
+
+    /// Marks the items from the anchor to the given index, both included, and selects the item at
+    /// the given index
+    ///
+    /// This is [`Marks::mark_range_to`], starting from the selected item if there is no anchor.
+    ///
+    /// The index must be lower than the number of items.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::ListState;
+    ///
+    /// let mut state = ListState::default().with_selected(Some(2));
+    /// state.mark_range_to(5);
+    /// assert_eq!(state.marks().len(), 4);
+    /// state.mark_range_to(3);
+    /// assert_eq!(state.marks().len(), 2);
+    /// ```
+    pub fn mark_range_to(&mut self, index: usize) {
+        let from = self.selected.unwrap_or(index);
+        self.marks.mark_range(from, index, None);
+        self.selected = Some(index);
+    }
+
     /// Scrolls down by a specified `amount` in the list.
     ///
     /// This method updates the selected index by moving it down by the given `amount`.
@@ -278,6 +367,28 @@ mod tests {
 
     use crate::list::ListState;
 
+    fn marked(state: &ListState) -> Vec<usize> {
+        state.marks().iter().collect()
+    }
+
+    #[test]
+    fn mark_range_to() {
+        // the range starts from the selected item without an anchor
+        let mut state = ListState::default().with_selected(Some(3));
+        state.mark_range_to(5);
+        assert_eq!(marked(&state), [3, 4, 5]);
+        assert_eq!(state.selected(), Some(5));
+
+        // the range shrinks and grows on the other side of the anchor
+        state.mark_range_to(1);
+        assert_eq!(marked(&state), [1, 2, 3]);
+
+        // marks outside of the range are kept, and a toggled item is the new anchor
+        state.marks_mut().toggle(7);
+        state.mark_range_to(8);
+        assert_eq!(marked(&state), [1, 2, 3, 7, 8]);
+    }
+
     #[test]
     fn selected() {
         let mut state = ListState::default();
@@ -0,0 +1,407 @@
+//! Internal module for the marked items of lists and tables.
+use std::{ops::Range, slice};
+
+/// The marked items of a [`List`] or rows of a [`Table`]
+///
+/// The selected item is the one the cursor is on, while the marked items are the ones picked for
+/// an operation, e.g. with the space key. The marks are stored in the [`ListState`] and the
+/// [`TableState`], see [`ListState::marks_mut`] and [`TableState::marks_mut`].
+///
+/// The marks are stored as ranges of consecutive indexes, so marking all the items, inverting the
+/// marks or marking a range is cheap even for a [`VirtualList`] or [`VirtualTable`] of millions
+/// of items.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui::widgets::Marks;
+///
+/// let mut marks = Marks::default();
+/// marks.mark(1);
+/// marks.toggle(2);
+/// assert_eq!(marks.iter().collect::<Vec<_>>(), [1, 2]);
+///
+/// marks.invert(5);
+/// assert_eq!(marks.iter().collect::<Vec<_>>(), [0, 3, 4]);
+///
+/// marks.mark_all(1_000_000);
+/// assert_eq!(marks.len(), 1_000_000);
+/// ```
+///
+/// [`List`]: crate::list::List
+/// [`Table`]: crate::table::Table
+/// [`ListState`]: crate::list::ListState
+/// [`TableState`]: crate::table::TableState
+/// [`ListState::marks_mut`]: crate::list::ListState::marks_mut
+/// [`TableState::marks_mut`]: crate::table::TableState::marks_mut
+/// [`VirtualList`]: crate::list::VirtualList
+/// [`VirtualTable`]: crate::table::VirtualTable
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Marks {
+    /// The marked indexes, as sorted ranges that neither overlap nor touch each other
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Vec::is_empty",
+            deserialize_with = "deserialize_ranges"
+        )
+    )]
+    ranges: Vec<Range<usize>>,
+    /// The index a range of marks starts from, and the end of the last range marked from it
+    #[cfg_attr(feature = "serde", serde(skip))]
+    anchor: Option<(usize, usize)>,
+}
+
+impl Marks {
+    /// Creates a new [`Marks`] with no marked item
+    pub const fn new() -> Self {
+        Self {
+            ranges: Vec::new(),
+            anchor: None,
+        }
+    }
+
+    /// Returns true if the item at the given index is marked
+    pub fn contains(&self, index: usize) -> bool {
+        let i = self.ranges.partition_point(|range| range.end <= index);
+        self.ranges.get(i).is_some_and(|range| range.start <= index)
+    }
+
+    /// Returns the number of marked items
+    pub fn len(&self) -> usize {
+        self.ranges.iter().map(ExactSizeIterator::len).sum()
+    }
+
+    /// Returns true if no item is marked
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Returns the indexes of the marked items, in ascending order
+    pub fn iter(&self) -> Iter<'_> {
+        Iter {
+            ranges: self.ranges.iter(),
+            range: 0..0,
+        }
+    }
+
+    /// Marks the item at the given index
+    ///
+    /// The item becomes the anchor of the ranges marked with [`Marks::mark_range_to`].
+    pub fn mark(&mut self, index: usize) {
+        self.insert(index..index + 1);
+        self.anchor = Some((index, index));
+    }
+
+    /// Unmarks the item at the given index
+    pub fn unmark(&mut self, index: usize) {
+        self.remove(index..index + 1);
+    }
+
+    /// Marks the item at the given index if it is not marked, or unmarks it
+    ///
+    /// The item becomes the anchor of the ranges marked with [`Marks::mark_range_to`].
+    pub fn toggle(&mut self, index: usize) {
+        if self.contains(index) {
+            self.remove(index..index + 1);
+        } else {
+            self.insert(index..index + 1);
+        }
+        self.anchor = Some((index, index));
+    }
+
+    /// Marks the items from the anchor to the given index, both included
+    ///
+    /// The anchor is the item last marked with [`Marks::mark`] or [`Marks::toggle`], or the given
+    /// index if there is none. Like a shift-click, marking a new range from the same anchor
+    /// replaces the previous one, so the range can grow and shrink while the cursor moves.
+    ///
+    /// [`ListState::mark_range_to`] and [`TableState::mark_range_to`] also move the selection, and
+    /// start the range from the selected item if there is no anchor.
+    ///
+    /// [`ListState::mark_range_to`]: crate::list::ListState::mark_range_to
+    /// [`TableState::mark_range_to`]: crate::table::TableState::mark_range_to
+    pub fn mark_range_to(&mut self, index: usize) {
+        self.mark_range(index, index, None);
+    }
+
+    /// Marks all the items of a list of the given length
+    pub fn mark_all(&mut self, len: usize) {
+        self.ranges.clear();
+        self.insert(0..len);
+    }
+
+    /// Marks the unmarked items and unmarks the marked items of a list of the given length
+    ///
+    /// The marks out of bounds are removed.
+    pub fn invert(&mut self, len: usize) {
+        let mut start = 0;
+        let mut inverted = Vec::with_capacity(self.ranges.len() + 1);
+        for range in self.ranges.iter().take_while(|range| range.start < len) {
+            if start < range.start {
+                inverted.push(start..range.start);
+            }
+            start = range.end;
+        }
+        if start < len {
+            inverted.push(start..len);
+        }
+        self.ranges = inverted;
+    }
+
+    /// Unmarks all the items and forgets the anchor of the ranges
+    pub fn clear(&mut self) {
+        self.ranges.clear();
+        self.anchor = None;
+    }
+
+    /// Removes the marks of the items at or after the given index
+    pub(crate) fn truncate(&mut self, len: usize) {
+        self.remove(len..usize::MAX);
+    }
+
+    /// Marks the items from the anchor, or `from` if there is none, to the given index
+    ///
+    /// The range follows the order of the `view` if both ends are in it.
+    pub(crate) fn mark_range(&mut self, from: usize, index: usize, view: Option<&[usize]>) {
+        let (anchor, end) = self.anchor.unwrap_or((from, from));
+        for range in between(anchor, end, view) {
+            self.remove(range);
+        }
+        for range in between(anchor, index, view) {
+            self.insert(range);
+        }
+        self.anchor = Some((anchor, index));
+    }
+
+    /// Marks the indexes of the range, merging it with the ranges it overlaps or touches
+    fn insert(&mut self, range: Range<usize>) {
+        if range.is_empty() {
+            return;
+        }
+        let first = self.ranges.partition_point(|r| r.end < range.start);
+        let last = self.ranges.partition_point(|r| r.start <= range.end);
+        let merged = if first < last {
+            self.ranges[first].start.min(range.start)..self.ranges[last - 1].end.max(range.end)
+        } else {
+            range
+        };
+        self.ranges.splice(first..last, [merged]);
+    }
+
+    /// Unmarks the indexes of the range, splitting the ranges it overlaps
+    fn remove(&mut self, range: Range<usize>) {
+        if range.is_empty() {
+            return;
+        }
+        let first = self.ranges.partition_point(|r| r.end <= range.start);
+        let last = self.ranges.partition_point(|r| r.start < range.end);
+        if first >= last {
+            return;
+        }
+        let before = self.ranges[first].start..range.start;
+        let after = range.end..self.ranges[last - 1].end;
+        let kept = [before, after].into_iter().filter(|r| !r.is_empty());
+        self.ranges.splice(first..last, kept);
+    }
+}
+
+/// Returns the ranges of indexes between two indexes, both included, in the order of the view if
+/// both are in it.
+fn between(from: usize, to: usize, view: Option<&[usize]>) -> Vec<Range<usize>> {
+    let mut ranges: Vec<Range<usize>> = Vec::new();
+    match view.and_then(|view| view_between(view, from, to)) {
+        // the consecutive indexes of the view are grouped in a single range
+        Some(view) => {
+            for &index in view {
+                match ranges.last_mut() {
+                    Some(range) if range.end == index => range.end += 1,
+                    _ => ranges.push(index..index + 1),
+                }
+            }
+        }
+        None => ranges.push(from.min(to)..from.max(to) + 1),
+    }
+    ranges
+}
+
+/// Returns the part of the view between two indexes, both included, if both are in it.
+fn view_between(view: &[usize], from: usize, to: usize) -> Option<&[usize]> {
+    let mut positions = view
+        .iter()
+        .enumerate()
+        .filter(|(_, &index)| index == from || index == to)
+        .map(|(position, _)| position);
+    let first = positions.next()?;
+    let last = if from == to { first } else { positions.next()? };
+    Some(&view[first..=last])
+}
+
+/// Deserializes the ranges of [`Marks`], sorting and merging them.
+#[cfg(feature = "serde")]
+fn deserialize_ranges<'de, D>(deserializer: D) -> Result<Vec<Range<usize>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let mut marks = Marks::new();
+    for range in <Vec<Range<usize>> as serde::Deserialize>::deserialize(deserializer)? {
+        marks.insert(range);
+    }
+    Ok(marks.ranges)
+}
+
+impl FromIterator<usize> for Marks {
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut marks = Self::new();
+        marks.extend(iter);
+        marks
+    }
+}
+
+impl Extend<usize> for Marks {
+    /// Marks the items at the given indexes, without changing the anchor
+    fn extend<I: IntoIterator<Item = usize>>(&mut self, iter: I) {
+        for index in iter {
+            self.insert(index..index + 1);
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a Marks {
+    type Item = usize;
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the indexes of the marked items, in ascending order
+///
+/// This is created with [`Marks::iter`].
+#[derive(Debug, Clone)]
+pub struct Iter<'a> {
+    /// The ranges that are not iterated yet
+    ranges: slice::Iter<'a, Range<usize>>,
+    /// The rest of the range being iterated
+    range: Range<usize>,
+}
+
+impl Iterator for Iter<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.range.is_empty() {
+            self.range = self.ranges.next()?.clone();
+        }
+        self.range.next()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn marked(marks: &Marks) -> Vec<usize> {
+        marks.iter().collect()
+    }
+
+    #[test]
+    fn mark_and_toggle() {
+        let mut marks = Marks::from_iter([4]);
+        marks.mark(1);
+        marks.toggle(2);
+        marks.toggle(4);
+        marks.unmark(1);
+        assert_eq!(marked(&marks), [2]);
+        assert!(marks.contains(2));
+        assert_eq!(marks.len(), 1);
+    }
+
+    #[test]
+    fn mark_all_and_invert() {
+        let mut marks = Marks::from_iter([1, 9]);
+        marks.invert(4);
+        assert_eq!(marked(&marks), [0, 2, 3]);
+        marks.unmark(2);
+        marks.mark(6);
+        assert_eq!(marked(&marks), [0, 3, 6]);
+        marks.invert(8);
+        assert_eq!(marked(&marks), [1, 2, 4, 5, 7]);
+        marks.mark_all(5);
+        assert_eq!(marked(&marks), [0, 1, 2, 3, 4]);
+        marks.clear();
+        assert!(marks.is_empty());
+    }
+
+    #[test]
+    fn mark_all_stores_a_range() {
+        let mut marks = Marks::default();
+        marks.mark_all(usize::MAX);
+        marks.unmark(3);
+        assert_eq!(marks.len(), usize::MAX - 1);
+        assert!(!marks.contains(3));
+        assert!(marks.contains(usize::MAX - 1));
+        assert_eq!(marks.ranges, [0..3, 4..usize::MAX]);
+
+        marks.invert(usize::MAX);
+        assert_eq!(marked(&marks), [3]);
+    }
+
+    #[test]
+    fn truncate() {
+        let mut marks = Marks::from_iter([1, 2, 9]);
+        marks.truncate(3);
+        assert_eq!(marked(&marks), [1, 2]);
+
+        marks.mark_all(10);
+        marks.unmark(1);
+        marks.truncate(3);
+        assert_eq!(marked(&marks), [0, 2]);
+    }
+
+    #[test]
+    fn mark_range_to() {
+        // the range starts from the index without an anchor
+        let mut marks = Marks::default();
+        marks.mark_range_to(3);
+        assert_eq!(marked(&marks), [3]);
+
+        // the range shrinks and grows on the other side of the anchor
+        marks.mark(3);
+        marks.mark_range_to(5);
+        assert_eq!(marked(&marks), [3, 4, 5]);
+        marks.mark_range_to(1);
+        assert_eq!(marked(&marks), [1, 2, 3]);
+
+        // marks outside of the range are kept, and a toggled item is the new anchor
+        marks.toggle(7);
+        marks.mark_range_to(8);
+        assert_eq!(marked(&marks), [1, 2, 3, 7, 8]);
+    }
+
+    #[test]
+    fn mark_range_stores_a_range() {
+        let mut marks = Marks::from_iter([2, 5, 6]);
+        marks.mark(usize::MAX - 10);
+        marks.mark_range_to(0);
+        assert_eq!(marks.len(), usize::MAX - 9);
+        assert_eq!(marks.ranges.len(), 1);
+        marks.mark_range_to(usize::MAX - 12);
+        assert_eq!(
+            marked(&marks),
+            [usize::MAX - 12, usize::MAX - 11, usize::MAX - 10]
+        );
+    }
+
+    #[test]
+    fn mark_range_in_view() {
+        let mut marks = Marks::default();
+        marks.mark_range(4, 3, Some(&[4, 0, 3, 1]));
+        assert_eq!(marked(&marks), [0, 3, 4]);
+    }
+}
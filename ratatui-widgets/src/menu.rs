@@ -0,0 +1,416 @@
+//! The [`Menu`] and [`MenuBar`] widgets display menus of actions with nested submenus.
+use ratatui_core::{
+    buffer::Buffer,
+    layout::{Rect, Size},
+    style::{Modifier, Style, Styled},
+    symbols::line,
+    text::Line,
+    widgets::{StatefulWidget, Widget},
+};
+
+pub use self::{bar::MenuBar, item::MenuItem, state::MenuState};
+use crate::{block::Block, clear::Clear, popup::Anchor};
+
+mod bar;
+mod item;
+mod state;
+
+/// A widget to display a popup menu of actions, with nested submenus
+///
+/// A menu is a list of [`MenuItem`]s drawn as a popup: the area is cleared with [`Clear`] and
+/// surrounded with a [`Block`]. The menu is sized to fit its items and positioned relative to an
+/// [`Anchor`] in the area it is rendered in, e.g. [`Anchor::Cursor`] for a context menu. Like a
+/// [`Popup`], the area given to the menu is the area it may be displayed in, usually the whole
+/// frame, and the menu is flipped to the other side of its anchor or moved inside the area when it
+/// would overflow it.
+///
+/// Each item is drawn on its own row, with its shortcut hint aligned to the right. Items with
+/// children display the submenu symbol instead, and their submenu is drawn next to them when it is
+/// open in the [`MenuState`], on the left of the menu if it doesn't fit on the right. Separators are
+/// drawn as horizontal lines and disabled items with the disabled style.
+///
+/// [`Menu`] is a [`StatefulWidget`], which means you can use it with [`MenuState`] to allow the
+/// user to navigate between the items and to open the submenus.
+///
+/// See [`MenuBar`] for a horizontal bar of menus opening dropdowns.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     layout::Position,
+///     widgets::{Anchor, Menu, MenuItem, MenuState},
+///     Frame,
+/// };
+///
+/// # fn ui(frame: &mut Frame) {
+/// let items = [
+///     MenuItem::new("Cut").shortcut("Ctrl+X"),
+///     MenuItem::new("Copy").shortcut("Ctrl+C"),
+///     MenuItem::separator(),
+///     MenuItem::new("Transform").children([
+///         MenuItem::new("Uppercase"),
+///         MenuItem::new("Lowercase"),
+///     ]),
+/// ];
+/// let menu = Menu::new(items).anchor(Anchor::Cursor(Position::new(10, 5)));
+///
+/// // This should be stored outside of the function in your application state.
+/// let mut state = MenuState::default().with_path(vec![3, 0]);
+///
+/// frame.render_stateful_widget(menu, frame.area(), &mut state);
+/// # }
+/// ```
+///
+/// [`Popup`]: crate::popup::Popup
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Menu<'a> {
+    items: Vec<MenuItem<'a>>,
+    anchor: Anchor,
+    block: Block<'a>,
+    style: Style,
+    highlight_style: Style,
+    disabled_style: Style,
+    submenu_symbol: &'a str,
+}
+
+const DEFAULT_HIGHLIGHT_STYLE: Style = Style::new().add_modifier(Modifier::REVERSED);
+const DEFAULT_DISABLED_STYLE: Style = Style::new().add_modifier(Modifier::DIM);
+
+impl Default for Menu<'_> {
+    fn default() -> Self {
+        Self {
+            items: Vec::new(),
+            anchor: Anchor::default(),
+            block: Block::bordered(),
+            style: Style::new(),
+            highlight_style: DEFAULT_HIGHLIGHT_STYLE,
+            disabled_style: DEFAULT_DISABLED_STYLE,
+            submenu_symbol: "▶",
+        }
+    }
+}
+
+impl<'a> Menu<'a> {
+    /// Creates a new menu with the given items.
+    ///
+    /// `items` is an iterator of any type that is convertible to [`MenuItem`], such as strings.
+    pub fn new<I>(items: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<MenuItem<'a>>,
+    {
+        Self {
+            items: items.into_iter().map(Into::into).collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Sets the items of the menu
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn items<I>(mut self, items: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<MenuItem<'a>>,
+    {
+        self.items = items.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the position of the menu relative to the area it is rendered in
+    ///
+    /// Defaults to [`Anchor::Center`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn anchor(mut self, anchor: Anchor) -> Self {
+        self.anchor = anchor;
+        self
+    }
+
+    /// Sets the block surrounding the menu and its submenus
+    ///
+    /// Defaults to a block with all borders.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = block;
+        self
+    }
+
+    /// Sets the base style of the menu
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the highlighted items
+    ///
+    /// Defaults to reversed colors.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn highlight_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.highlight_style = style.into();
+        self
+    }
+
+    /// Sets the style of the disabled items
+    ///
+    /// Defaults to dim text.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn disabled_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.disabled_style = style.into();
+        self
+    }
+
+    /// Sets the symbol displayed on the right of the items with a submenu
+    ///
+    /// Defaults to `"▶"`.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn submenu_symbol(mut self, symbol: &'a str) -> Self {
+        self.submenu_symbol = symbol;
+        self
+    }
+
+    /// Returns the size of the popup displaying the given items.
+    fn popup_size(&self, items: &[MenuItem<'_>]) -> Size {
+        let submenu_symbol = Line::from(self.submenu_symbol);
+        let label_width = items.iter().map(|item| item.label.width()).max();
+        let right_width = items
+            .iter()
+            .map(|item| {
+                if item.children.is_empty() {
+                    item.shortcut.width()
+                } else {
+                    submenu_symbol.width()
+                }
+            })
+            .max()
+            .unwrap_or_default();
+        // one cell of padding on each side, and two cells between the labels and the shortcuts
+        let gap = if right_width > 0 { 2 } else { 0 };
+        let content_width = label_width.unwrap_or_default() + gap + right_width + 2;
+        let (left, right) = self.block.horizontal_space();
+        let (top, bottom) = self.block.vertical_space();
+        let width = u16::try_from(content_width)
+            .unwrap_or(u16::MAX)
+            .saturating_add(left)
+            .saturating_add(right);
+        let height = u16::try_from(items.len())
+            .unwrap_or(u16::MAX)
+            .saturating_add(top)
+            .saturating_add(bottom);
+        Size::new(width, height)
+    }
+
+    /// Renders the popup displaying the given items placed relative to the anchor in the bounds,
+    /// and the open submenus of the path.
+    ///
+    /// The first index of the path is the highlighted item of the popup, the rest is the path in
+    /// its submenu. Returns the area of the popup.
+    pub(crate) fn render_popup(
+        &self,
+        items: &[MenuItem<'_>],
+        path: &[usize],
+        anchor: Anchor,
+        bounds: Rect,
+        buf: &mut Buffer,
+    ) -> Rect {
+        let popup = anchor.place(self.popup_size(items), bounds);
+        Clear.render(popup, buf);
+        buf.set_style(popup, self.style);
+        (&self.block).render(popup, buf);
+        let inner = self.block.inner(popup);
+
+        let highlighted = path.first().copied();
+        let rows = (inner.top()..inner.bottom()).map(|y| Rect::new(inner.x, y, inner.width, 1));
+        let mut submenu = None;
+        for (index, (item, row)) in items.iter().zip(rows).enumerate() {
+            if item.separator {
+                let separator = line::HORIZONTAL.repeat(usize::from(row.width));
+                buf.set_string(row.x, row.y, separator, self.style);
+                continue;
+            }
+            self.render_item(item, row, buf);
+            if item.disabled {
+                buf.set_style(row, self.disabled_style);
+            }
+            if highlighted == Some(index) {
+                buf.set_style(row, self.highlight_style);
+                if path.len() > 1 && !item.children.is_empty() {
+                    submenu = Some((item, row));
+                }
+            }
+        }
+
+        if let Some((item, row)) = submenu {
+            // align the first item of the submenu with the item it belongs to
+            let y = row.y.saturating_sub(self.block.vertical_space().0);
+            let anchor = Anchor::Right(Rect { y, ..popup });
+            self.render_popup(&item.children, &path[1..], anchor, bounds, buf);
+        }
+        popup
+    }
+
+    /// Renders the label of the item and its shortcut or submenu symbol in the row.
+    fn render_item(&self, item: &MenuItem<'_>, row: Rect, buf: &mut Buffer) {
+        let content = Rect {
+            x: row.x.saturating_add(1),
+            width: row.width.saturating_sub(2),
+            ..row
+        };
+        let right = if item.children.is_empty() {
+            item.shortcut.clone()
+        } else {
+            Line::from(self.submenu_symbol)
+        };
+        let right_width = u16::try_from(right.width()).unwrap_or(u16::MAX);
+        let right_x = content.right().saturating_sub(right_width).max(content.x);
+        buf.set_line(content.x, content.y, &item.label, content.width);
+        buf.set_line(right_x, content.y, &right, content.right() - right_x);
+    }
+}
+
+impl Styled for Menu<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+impl Widget for Menu<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl Widget for &Menu<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut state = MenuState::default();
+        StatefulWidget::render(self, area, buf, &mut state);
+    }
+}
+
+impl StatefulWidget for Menu<'_> {
+    type State = MenuState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidget::render(&self, area, buf, state);
+    }
+}
+
+impl StatefulWidget for &Menu<'_> {
+    type State = MenuState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let bounds = area.intersection(*buf.area());
+        self.render_popup(&self.items, &state.path, self.anchor, bounds, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use ratatui_core::layout::Position;
+
+    use super::*;
+
+    fn items() -> Vec<MenuItem<'static>> {
+        vec![
+            MenuItem::new("Copy").shortcut("^C"),
+            MenuItem::separator(),
+            MenuItem::new("Save").disabled(true),
+            MenuItem::new("More").children([MenuItem::new("One"), MenuItem::new("Two")]),
+        ]
+    }
+
+    #[test]
+    fn render() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 14, 6));
+        Widget::render(Menu::new(items()), buf.area, &mut buf);
+        let mut expected = Buffer::with_lines([
+            " ┌──────────┐ ",
+            " │ Copy  ^C │ ",
+            " │──────────│ ",
+            " │ Save     │ ",
+            " │ More   ▶ │ ",
+            " └──────────┘ ",
+        ]);
+        expected.set_style(Rect::new(2, 3, 10, 1), DEFAULT_DISABLED_STYLE);
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_submenu() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 20, 7));
+        let mut state = MenuState::default().with_path(vec![3, 1]);
+        let menu = Menu::new(items())
+            .anchor(Anchor::Cursor(Position::new(0, 0)))
+            .disabled_style(Style::new());
+        StatefulWidget::render(menu, buf.area, &mut buf, &mut state);
+        // the submenu is moved up to fit in the buffer
+        let mut expected = Buffer::with_lines([
+            "                    ",
+            "┌──────────┐        ",
+            "│ Copy  ^C │        ",
+            "│──────────│┌─────┐ ",
+            "│ Save     ││ One │ ",
+            "│ More   ▶ ││ Two │ ",
+            "└──────────┘└─────┘ ",
+        ]);
+        expected.set_style(Rect::new(1, 5, 10, 1), DEFAULT_HIGHLIGHT_STYLE);
+        expected.set_style(Rect::new(13, 5, 5, 1), DEFAULT_HIGHLIGHT_STYLE);
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_submenu_flipped() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 20, 4));
+        let items = [MenuItem::new("A").children([MenuItem::new("B")])];
+        let mut state = MenuState::default().with_path(vec![0, 0]);
+        let menu = Menu::new(items)
+            .anchor(Anchor::Below(Rect::new(10, 0, 7, 1)))
+            .highlight_style(Style::new());
+        StatefulWidget::render(menu, Rect::new(0, 0, 17, 4), &mut buf, &mut state);
+        // the menu is aligned with the right of its anchor to fit in the area, and the submenu
+        // doesn't fit on its right
+        let expected = Buffer::with_lines([
+            "                    ",
+            "    ┌───┐┌──────┐   ",
+            "    │ B ││ A  ▶ │   ",
+            "    └───┘└──────┘   ",
+        ]);
+        assert_eq!(buf, expected);
+    }
+}
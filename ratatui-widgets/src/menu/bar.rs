@@ -0,0 +1,277 @@
+use ratatui_core::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Style, Styled},
+    widgets::{StatefulWidget, Widget},
+};
+
+use super::{Menu, MenuItem, MenuState};
+use crate::{block::Block, popup::Anchor};
+
+/// A widget to display a horizontal bar of menus, opening dropdowns with nested submenus
+///
+/// The bar displays the label of each of its [`MenuItem`]s on its first row. The children of an
+/// item are displayed in a dropdown [`Menu`] below its label when the item is open in the
+/// [`MenuState`]: the first index of the path of the state is the highlighted menu of the bar, the
+/// rest is the path in its dropdown. The dropdowns are drawn inside the area the bar is rendered
+/// in, usually the whole frame, over the widgets rendered below the bar, so the bar should be
+/// rendered last.
+///
+/// [`MenuBar`] is a [`StatefulWidget`], which means you can use it with [`MenuState`] to allow the
+/// user to navigate between the menus with [`MenuState::highlight_next_menu`] and
+/// [`MenuState::highlight_previous_menu`], and in the dropdowns with the other methods of the
+/// state.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     style::{Style, Stylize},
+///     widgets::{MenuBar, MenuItem, MenuState},
+///     Frame,
+/// };
+///
+/// # fn ui(frame: &mut Frame) {
+/// let items = [
+///     MenuItem::new("File").children([
+///         MenuItem::new("Open").shortcut("Ctrl+O"),
+///         MenuItem::new("Quit").shortcut("Ctrl+Q"),
+///     ]),
+///     MenuItem::new("Help").children([MenuItem::new("About")]),
+/// ];
+/// let menu_bar = MenuBar::new(items).style(Style::new().on_blue());
+///
+/// // This should be stored outside of the function in your application state.
+/// let mut state = MenuState::default().with_path(vec![0, 1]);
+///
+/// frame.render_stateful_widget(menu_bar, frame.area(), &mut state);
+/// # }
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct MenuBar<'a> {
+    /// The dropdowns are rendered as popups of this menu, which holds the items of the bar
+    menu: Menu<'a>,
+}
+
+impl<'a> MenuBar<'a> {
+    /// Creates a new menu bar with the given items.
+    ///
+    /// `items` is an iterator of any type that is convertible to [`MenuItem`], such as strings.
+    pub fn new<I>(items: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<MenuItem<'a>>,
+    {
+        Self {
+            menu: Menu::new(items),
+        }
+    }
+
+    /// Sets the items of the menu bar
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn items<I>(mut self, items: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<MenuItem<'a>>,
+    {
+        self.menu = self.menu.items(items);
+        self
+    }
+
+    /// Sets the block surrounding the dropdowns and their submenus
+    ///
+    /// Defaults to a block with all borders.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn menu_block(mut self, block: Block<'a>) -> Self {
+        self.menu = self.menu.block(block);
+        self
+    }
+
+    /// Sets the base style of the bar and of the dropdowns
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.menu = self.menu.style(style);
+        self
+    }
+
+    /// Sets the style of the highlighted items, in the bar and in the dropdowns
+    ///
+    /// Defaults to reversed colors.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn highlight_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.menu = self.menu.highlight_style(style);
+        self
+    }
+
+    /// Sets the style of the disabled items, in the bar and in the dropdowns
+    ///
+    /// Defaults to dim text.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn disabled_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.menu = self.menu.disabled_style(style);
+        self
+    }
+
+    /// Sets the symbol displayed on the right of the items with a submenu in the dropdowns
+    ///
+    /// Defaults to `"▶"`.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn submenu_symbol(mut self, symbol: &'a str) -> Self {
+        self.menu.submenu_symbol = symbol;
+        self
+    }
+}
+
+impl Styled for MenuBar<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.menu.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+impl Widget for MenuBar<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl Widget for &MenuBar<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut state = MenuState::default();
+        StatefulWidget::render(self, area, buf, &mut state);
+    }
+}
+
+impl StatefulWidget for MenuBar<'_> {
+    type State = MenuState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidget::render(&self, area, buf, state);
+    }
+}
+
+impl StatefulWidget for &MenuBar<'_> {
+    type State = MenuState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let area = area.intersection(*buf.area());
+        if area.is_empty() {
+            return;
+        }
+        let menu = &self.menu;
+        let bar = Rect { height: 1, ..area };
+        buf.set_style(bar, menu.style);
+
+        let highlighted = state.path.first().copied();
+        let mut dropdown = None;
+        let mut x = bar.x;
+        for (index, item) in menu.items.iter().enumerate() {
+            // separators have no meaning in a bar, they are not displayed
+            if item.separator || x >= bar.right() {
+                continue;
+            }
+            // one cell of padding on each side of the labels
+            let label_x = x.saturating_add(1).min(bar.right());
+            let (end, _) = buf.set_line(label_x, bar.y, &item.label, bar.right() - label_x);
+            let label = Rect::new(x, bar.y, end.saturating_add(1).min(bar.right()) - x, 1);
+            if item.disabled {
+                buf.set_style(label, menu.disabled_style);
+            }
+            if highlighted == Some(index) {
+                buf.set_style(label, menu.highlight_style);
+                if state.path.len() > 1 && !item.children.is_empty() {
+                    dropdown = Some((item, label));
+                }
+            }
+            x = label.right();
+        }
+
+        if let Some((item, label)) = dropdown {
+            let anchor = Anchor::Below(label);
+            menu.render_popup(&item.children, &state.path[1..], anchor, area, buf);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::menu::{DEFAULT_DISABLED_STYLE, DEFAULT_HIGHLIGHT_STYLE};
+
+    fn menu_bar() -> MenuBar<'static> {
+        MenuBar::new([
+            MenuItem::new("File").children([MenuItem::new("Open"), MenuItem::new("Quit")]),
+            MenuItem::new("Edit").disabled(true),
+            MenuItem::new("Help").children([MenuItem::new("About").shortcut("F1")]),
+        ])
+    }
+
+    #[test]
+    fn default() {
+        let menu_bar = MenuBar::default();
+        assert_eq!(menu_bar.menu.highlight_style, DEFAULT_HIGHLIGHT_STYLE);
+        assert_eq!(menu_bar.menu.disabled_style, DEFAULT_DISABLED_STYLE);
+    }
+
+    #[test]
+    fn render_closed() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 20, 1));
+        Widget::render(menu_bar(), buf.area, &mut buf);
+        let mut expected = Buffer::with_lines([" File  Edit  Help   "]);
+        expected.set_style(Rect::new(6, 0, 6, 1), DEFAULT_DISABLED_STYLE);
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_dropdown() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 20, 5));
+        let mut state = MenuState::default().with_path(vec![2, 0]);
+        let menu_bar = menu_bar().disabled_style(Style::new());
+        StatefulWidget::render(menu_bar, buf.area, &mut buf, &mut state);
+        // the dropdown is aligned with the right of the label to fit in the buffer
+        let mut expected = Buffer::with_lines([
+            " File  Edit  Help   ",
+            "     ┌───────────┐  ",
+            "     │ About  F1 │  ",
+            "     └───────────┘  ",
+            "                    ",
+        ]);
+        expected.set_style(Rect::new(12, 0, 6, 1), DEFAULT_HIGHLIGHT_STYLE);
+        expected.set_style(Rect::new(6, 2, 11, 1), DEFAULT_HIGHLIGHT_STYLE);
+        assert_eq!(buf, expected);
+    }
+}
@@ -0,0 +1,147 @@
+use ratatui_core::text::Line;
+
+/// An entry of a [`Menu`] or a [`MenuBar`]
+///
+/// An item is either an action with a label, or a separator drawn as a horizontal line between
+/// groups of actions. An action can have a shortcut hint, which is displayed on the right of the
+/// label, and children, which are displayed in a submenu. A disabled item is displayed with the
+/// disabled style and can't be highlighted nor activated.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui::widgets::MenuItem;
+///
+/// let items = [
+///     MenuItem::new("New").shortcut("Ctrl+N"),
+///     MenuItem::new("Open Recent").children([
+///         MenuItem::new("notes.md"),
+///         MenuItem::new("todo.txt"),
+///     ]),
+///     MenuItem::separator(),
+///     MenuItem::new("Save").shortcut("Ctrl+S").disabled(true),
+/// ];
+/// ```
+///
+/// [`Menu`]: super::Menu
+/// [`MenuBar`]: super::MenuBar
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct MenuItem<'a> {
+    pub(crate) label: Line<'a>,
+    pub(crate) shortcut: Line<'a>,
+    pub(crate) disabled: bool,
+    pub(crate) separator: bool,
+    pub(crate) children: Vec<Self>,
+}
+
+impl<'a> MenuItem<'a> {
+    /// Creates a new item with the given label.
+    ///
+    /// `label` accepts any type that is convertible to [`Line`].
+    pub fn new<T: Into<Line<'a>>>(label: T) -> Self {
+        Self {
+            label: label.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Creates a separator, drawn as a horizontal line between groups of items.
+    pub fn separator() -> Self {
+        Self {
+            separator: true,
+            ..Self::default()
+        }
+    }
+
+    /// Sets the shortcut hint displayed on the right of the label
+    ///
+    /// The hint is only displayed: the keys must be handled by the application.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn shortcut<T: Into<Line<'a>>>(mut self, shortcut: T) -> Self {
+        self.shortcut = shortcut.into();
+        self
+    }
+
+    /// Sets whether the item is disabled
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Sets the items of the submenu of the item
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn children<I>(mut self, children: I) -> Self
+    where
+        I: IntoIterator<Item = Self>,
+    {
+        self.children = children.into_iter().collect();
+        self
+    }
+
+    /// Returns the label of the item.
+    pub const fn label(&self) -> &Line<'a> {
+        &self.label
+    }
+
+    /// Returns true if the item is a separator.
+    pub const fn is_separator(&self) -> bool {
+        self.separator
+    }
+
+    /// Returns true if the item is disabled.
+    pub const fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    /// Returns true if the item can be highlighted, i.e. it is neither a separator nor disabled.
+    pub(crate) const fn is_selectable(&self) -> bool {
+        !self.separator && !self.disabled
+    }
+}
+
+impl<'a, T> From<T> for MenuItem<'a>
+where
+    T: Into<Line<'a>>,
+{
+    fn from(label: T) -> Self {
+        Self::new(label)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn new() {
+        let item = MenuItem::new("Open").shortcut("Ctrl+O");
+        assert_eq!(item.label(), &Line::from("Open"));
+        assert_eq!(item.shortcut, Line::from("Ctrl+O"));
+        assert!(item.is_selectable());
+        assert!(item.children.is_empty());
+    }
+
+    #[test]
+    fn separator_and_disabled() {
+        assert!(MenuItem::separator().is_separator());
+        assert!(!MenuItem::separator().is_selectable());
+        let item = MenuItem::new("Save").disabled(true);
+        assert!(item.is_disabled());
+        assert!(!item.is_selectable());
+    }
+
+    #[test]
+    fn children() {
+        let item = MenuItem::new("Recent").children([MenuItem::new("a.txt")]);
+        assert_eq!(item.children, [MenuItem::new("a.txt")]);
+    }
+}
@@ -0,0 +1,302 @@
+use std::mem;
+
+use super::MenuItem;
+
+/// State of the [`Menu`] and [`MenuBar`] widgets
+///
+/// The state is the path of the highlighted item at each open level: the first index is the
+/// highlighted item of the top level (the items of a [`Menu`], or the menus of a [`MenuBar`]), the
+/// second one is the highlighted item of its submenu, and so on. The submenu of each item of the
+/// path but the last one is open. An empty path means that nothing is highlighted, i.e. the menu
+/// bar is not focused.
+///
+/// Navigating between the items requires the items of the menu, as separators and disabled items
+/// are skipped. Activating an item with [`MenuState::activate`] returns its path, which the
+/// application uses to run the matching action.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::widgets::{MenuItem, MenuState};
+///
+/// let items = [
+///     MenuItem::new("File").children([
+///         MenuItem::new("New"),
+///         MenuItem::separator(),
+///         MenuItem::new("Quit"),
+///     ]),
+///     MenuItem::new("Edit").children([MenuItem::new("Copy")]),
+/// ];
+///
+/// // This should be stored outside of the function in your application state.
+/// let mut state = MenuState::default();
+/// state.highlight_next(&items); // focus the "File" menu
+/// state.open_submenu(&items); // open it, highlighting "New"
+/// state.highlight_next(&items); // skip the separator
+/// assert_eq!(state.path(), [0, 2]);
+/// assert_eq!(state.activate(&items), Some(vec![0, 2]));
+/// assert!(!state.is_open());
+/// ```
+///
+/// [`Menu`]: super::Menu
+/// [`MenuBar`]: super::MenuBar
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MenuState {
+    pub(crate) path: Vec<usize>,
+}
+
+impl MenuState {
+    /// Sets the path of the highlighted item
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_path(mut self, path: Vec<usize>) -> Self {
+        self.path = path;
+        self
+    }
+
+    /// Path of the highlighted item, from the top level to the deepest open submenu
+    pub fn path(&self) -> &[usize] {
+        &self.path
+    }
+
+    /// Returns true if an item is highlighted.
+    pub fn is_open(&self) -> bool {
+        !self.path.is_empty()
+    }
+
+    /// Closes all the menus and clears the highlighted item
+    pub fn close(&mut self) {
+        self.path.clear();
+    }
+
+    /// Highlights the next item of the deepest open menu, wrapping around to the first one
+    ///
+    /// Highlights the first item of the top level if nothing is highlighted. Returns false if
+    /// nothing changed.
+    pub fn highlight_next(&mut self, items: &[MenuItem<'_>]) -> bool {
+        self.highlight_sibling(items, true)
+    }
+
+    /// Highlights the previous item of the deepest open menu, wrapping around to the last one
+    ///
+    /// Highlights the last item of the top level if nothing is highlighted. Returns false if
+    /// nothing changed.
+    pub fn highlight_previous(&mut self, items: &[MenuItem<'_>]) -> bool {
+        self.highlight_sibling(items, false)
+    }
+
+    /// Highlights the next item of the top level, e.g. the next menu of a [`MenuBar`]
+    ///
+    /// If a submenu was open, the submenu of the new item is opened instead. Returns false if
+    /// nothing changed.
+    ///
+    /// [`MenuBar`]: super::MenuBar
+    pub fn highlight_next_menu(&mut self, items: &[MenuItem<'_>]) -> bool {
+        self.highlight_menu(items, true)
+    }
+
+    /// Highlights the previous item of the top level, e.g. the previous menu of a [`MenuBar`]
+    ///
+    /// If a submenu was open, the submenu of the new item is opened instead. Returns false if
+    /// nothing changed.
+    ///
+    /// [`MenuBar`]: super::MenuBar
+    pub fn highlight_previous_menu(&mut self, items: &[MenuItem<'_>]) -> bool {
+        self.highlight_menu(items, false)
+    }
+
+    /// Opens the submenu of the highlighted item and highlights its first item
+    ///
+    /// Returns false if the highlighted item has no submenu.
+    pub fn open_submenu(&mut self, items: &[MenuItem<'_>]) -> bool {
+        let Some(item) = find(items, &self.path) else {
+            return false;
+        };
+        let Some(first) = next_selectable(&item.children, None, true) else {
+            return false;
+        };
+        self.path.push(first);
+        true
+    }
+
+    /// Closes the deepest open submenu, highlighting the item it belongs to
+    ///
+    /// The top level is never closed, use [`MenuState::close`] for that. Returns false if no
+    /// submenu is open.
+    pub fn close_submenu(&mut self) -> bool {
+        if self.path.len() > 1 {
+            self.path.pop();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Activates the highlighted item
+    ///
+    /// If the item has a submenu, the submenu is opened and `None` is returned. Otherwise, all the
+    /// menus are closed and the path of the item is returned, for the application to run the
+    /// matching action.
+    pub fn activate(&mut self, items: &[MenuItem<'_>]) -> Option<Vec<usize>> {
+        let item = find(items, &self.path)?;
+        if !item.children.is_empty() {
+            self.open_submenu(items);
+            return None;
+        }
+        item.is_selectable().then(|| mem::take(&mut self.path))
+    }
+
+    /// Highlights the next or previous item of the deepest open menu.
+    fn highlight_sibling(&mut self, items: &[MenuItem<'_>], forward: bool) -> bool {
+        let Some((&current, parent)) = self.path.split_last() else {
+            return self.highlight_menu(items, forward);
+        };
+        let siblings = match parent {
+            [] => items,
+            parent => match find(items, parent) {
+                Some(item) => &item.children,
+                None => return false,
+            },
+        };
+        match next_selectable(siblings, Some(current), forward) {
+            Some(next) if next != current => {
+                *self.path.last_mut().expect("the path is not empty") = next;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Highlights the next or previous item of the top level, keeping its submenu open if the
+    /// submenu of the previous item was.
+    fn highlight_menu(&mut self, items: &[MenuItem<'_>], forward: bool) -> bool {
+        let current = self.path.first().copied();
+        let Some(next) = next_selectable(items, current, forward) else {
+            return false;
+        };
+        if current == Some(next) {
+            return false;
+        }
+        let was_open = self.path.len() > 1;
+        self.path = vec![next];
+        if was_open {
+            self.open_submenu(items);
+        }
+        true
+    }
+}
+
+/// Returns the item with the given path.
+pub(crate) fn find<'i, 'a>(items: &'i [MenuItem<'a>], path: &[usize]) -> Option<&'i MenuItem<'a>> {
+    let (&first, rest) = path.split_first()?;
+    let item = items.get(first)?;
+    if rest.is_empty() {
+        Some(item)
+    } else {
+        find(&item.children, rest)
+    }
+}
+
+/// Returns the index of the next selectable item after `current`, wrapping around, or of the
+/// first one if `current` is `None`. The items are searched backwards if `forward` is false.
+fn next_selectable(items: &[MenuItem<'_>], current: Option<usize>, forward: bool) -> Option<usize> {
+    let len = items.len();
+    (1..=len)
+        .map(|step| match (current, forward) {
+            (Some(current), true) => (current + step) % len,
+            (Some(current), false) => (current + len - step % len) % len,
+            (None, true) => step - 1,
+            (None, false) => len - step,
+        })
+        .find(|&index| items[index].is_selectable())
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn items() -> Vec<MenuItem<'static>> {
+        vec![
+            MenuItem::new("File").children([
+                MenuItem::new("New"),
+                MenuItem::new("Recent").children([MenuItem::new("a"), MenuItem::new("b")]),
+                MenuItem::separator(),
+                MenuItem::new("Save").disabled(true),
+                MenuItem::new("Quit"),
+            ]),
+            MenuItem::new("Edit").disabled(true),
+            MenuItem::new("View").children([MenuItem::new("Zoom")]),
+        ]
+    }
+
+    #[test]
+    fn highlight_next_and_previous() {
+        let items = items();
+        let mut state = MenuState::default();
+        assert!(state.highlight_next(&items));
+        assert_eq!(state.path(), [0]);
+        // the disabled item is skipped
+        assert!(state.highlight_next(&items));
+        assert_eq!(state.path(), [2]);
+        // wraps around
+        assert!(state.highlight_next(&items));
+        assert_eq!(state.path(), [0]);
+        assert!(state.highlight_previous(&items));
+        assert_eq!(state.path(), [2]);
+
+        let mut state = MenuState::default();
+        state.highlight_previous(&items);
+        assert_eq!(state.path(), [2]);
+    }
+
+    #[test]
+    fn submenus() {
+        let items = items();
+        let mut state = MenuState::default().with_path(vec![0]);
+        assert!(state.open_submenu(&items));
+        assert_eq!(state.path(), [0, 0]);
+        state.highlight_next(&items);
+        assert!(state.open_submenu(&items));
+        assert_eq!(state.path(), [0, 1, 0]);
+        assert!(!state.open_submenu(&items));
+
+        assert!(state.close_submenu());
+        // the separator and the disabled item are skipped
+        state.highlight_next(&items);
+        assert_eq!(state.path(), [0, 4]);
+        assert!(state.close_submenu());
+        assert!(!state.close_submenu());
+        assert_eq!(state.path(), [0]);
+    }
+
+    #[test]
+    fn highlight_next_menu_keeps_submenu_open() {
+        let items = items();
+        let mut state = MenuState::default().with_path(vec![0, 4]);
+        assert!(state.highlight_next_menu(&items));
+        assert_eq!(state.path(), [2, 0]);
+
+        let mut state = MenuState::default().with_path(vec![2]);
+        assert!(state.highlight_previous_menu(&items));
+        assert_eq!(state.path(), [0]);
+    }
+
+    #[test]
+    fn activate() {
+        let items = items();
+        let mut state = MenuState::default().with_path(vec![0, 1]);
+        assert_eq!(state.activate(&items), None);
+        assert_eq!(state.path(), [0, 1, 0]);
+        assert_eq!(state.activate(&items), Some(vec![0, 1, 0]));
+        assert!(!state.is_open());
+
+        // disabled items can't be activated
+        let mut state = MenuState::default().with_path(vec![0, 3]);
+        assert_eq!(state.activate(&items), None);
+        assert_eq!(state.path(), [0, 3]);
+    }
+}
@@ -3,17 +3,28 @@
 use ratatui_core::{
     buffer::Buffer,
     layout::{Alignment, Position, Rect},
-    style::{Style, Styled},
+    style::{Modifier, Style, Styled},
     text::{Line, StyledGrapheme, Text},
     widgets::Widget,
 };
+use unicode_segmentation::UnicodeSegmentation;
 use unicode_width::UnicodeWidthStr;
 
+pub use self::{
+    layout::{ParagraphLayout, RenderedLine},
+    selection::{Selection, TextPosition},
+};
+pub use crate::reflow::WrapMode;
 use crate::{
     block::{Block, BlockExt},
-    reflow::{LineComposer, LineTruncator, WordWrapper, WrappedLine},
+    reflow::{BreakWrapper, LineComposer, LineTruncator, WordWrapper, WrapOptions, WrappedLine},
 };
 
+mod layout;
+mod selection;
+
+const DEFAULT_SELECTION_STYLE: Style = Style::new().add_modifier(Modifier::REVERSED);
+
 const fn get_line_offset(line_width: u16, text_area_width: u16, alignment: Alignment) -> u16 {
     match alignment {
         Alignment::Center => (text_area_width / 2).saturating_sub(line_width / 2),
@@ -43,6 +54,9 @@ const fn get_line_offset(line_width: u16, text_area_width: u16, alignment: Align
 /// The text can be surrounded by a [`Block`] with a title and borders. The block can be configured
 /// with the [`block`] method.
 ///
+/// Part of the text can be selected with the [`selection`] method. The selected text is rendered
+/// with the [`selection_style`], and can be extracted as plain text with [`selected_text`].
+///
 /// The style of the text can be set with the [`style`] method. This style will be applied to the
 /// entire widget, including the block if one is present. Any style set on the block or text will be
 /// added to this style. See the [`Style`] type for more information on how styles are combined.
@@ -59,6 +73,9 @@ const fn get_line_offset(line_width: u16, text_area_width: u16, alignment: Align
 /// [`scroll`]: Self::scroll
 /// [`block`]: Self::block
 /// [`style`]: Self::style
+/// [`selection`]: Self::selection
+/// [`selection_style`]: Self::selection_style
+/// [`selected_text`]: Self::selected_text
 ///
 /// # Example
 ///
@@ -87,7 +104,7 @@ const fn get_line_offset(line_width: u16, text_area_width: u16, alignment: Align
 /// ```
 ///
 /// [`Span`]: ratatui_core::text::Span
-#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub struct Paragraph<'a> {
     /// A block to wrap the widget in
     block: Option<Block<'a>>,
@@ -95,16 +112,38 @@ pub struct Paragraph<'a> {
     style: Style,
     /// How to wrap the text
     wrap: Option<Wrap>,
+    /// Where wrapped lines may be broken
+    wrap_mode: WrapMode,
+    /// Whether wrapped lines may be broken at soft hyphens
+    hyphenate: bool,
+    /// Whether continuation lines repeat the indentation of the line they were wrapped from
+    preserve_indent: bool,
     /// The text to display
     text: Text<'a>,
     /// Scroll
     scroll: Position,
     /// Alignment of the text
     alignment: Alignment,
+    /// The selected part of the text
+    selection: Option<Selection>,
+    /// Style of the selected text
+    selection_style: Style,
+}
+
+impl Default for Paragraph<'_> {
+    /// Returns an empty `Paragraph`.
+    ///
+    /// The selection style is set to reversed, like in [`Paragraph::new`].
+    fn default() -> Self {
+        Self::new(Text::default())
+    }
 }
 
 /// Describes how to wrap text across lines.
 ///
+/// By default, lines are broken on whitespace. See [`Paragraph::wrap_mode`],
+/// [`Paragraph::hyphenate`] and [`Paragraph::preserve_indent`] for other ways to break lines.
+///
 /// ## Examples
 ///
 /// ```
@@ -173,9 +212,14 @@ impl<'a> Paragraph<'a> {
             block: None,
             style: Style::default(),
             wrap: None,
+            wrap_mode: WrapMode::Word,
+            hyphenate: false,
+            preserve_indent: false,
             text: text.into(),
             scroll: Position::ORIGIN,
             alignment: Alignment::Left,
+            selection: None,
+            selection_style: DEFAULT_SELECTION_STYLE,
         }
     }
 
@@ -220,6 +264,75 @@ impl<'a> Paragraph<'a> {
         self
     }
 
+    /// Sets the selected part of the text.
+    ///
+    /// The selection is given in positions of the text rather than positions on the screen, so it
+    /// follows the text when it is wrapped or scrolled. The selected text is rendered with the
+    /// [`selection_style`](Self::selection_style). Passing `None` clears the selection.
+    ///
+    /// The [`Selection`] is usually kept in the application state and updated as the user moves the
+    /// cursor, e.g. with [`Selection::extend_to`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Paragraph, Selection};
+    ///
+    /// let selection = Selection::new((0, 0), (0, 5));
+    /// let paragraph = Paragraph::new("Hello, world!").selection(selection);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn selection<S: Into<Option<Selection>>>(mut self, selection: S) -> Self {
+        self.selection = selection.into();
+        self
+    }
+
+    /// Sets the style of the selected text.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// The style is patched onto the style of the text selected with [`selection`]. This defaults
+    /// to a style with the [`Modifier::REVERSED`] modifier added.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::{style::Stylize, widgets::Paragraph};
+    ///
+    /// let paragraph = Paragraph::new("Hello, world!").selection_style(Style::new().on_blue());
+    /// # use ratatui::style::Style;
+    /// ```
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    /// [`selection`]: Self::selection
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn selection_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.selection_style = style.into();
+        self
+    }
+
+    /// Returns the plain text covered by the [`selection`], if any.
+    ///
+    /// Selected parts of different lines are joined with a newline. This is useful for copying the
+    /// selected text to the clipboard, e.g. with `Backend::set_clipboard`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Paragraph, Selection};
+    ///
+    /// let paragraph = Paragraph::new("Hello, world!").selection(Selection::new((0, 0), (0, 5)));
+    /// assert_eq!(paragraph.selected_text().as_deref(), Some("Hello"));
+    /// ```
+    ///
+    /// [`selection`]: Self::selection
+    pub fn selected_text(&self) -> Option<String> {
+        self.selection
+            .as_ref()
+            .map(|selection| selection.extract(&self.text))
+    }
+
     /// Sets the wrapping configuration for the widget.
     ///
     /// See [`Wrap`] for more information on the different options.
@@ -237,6 +350,76 @@ impl<'a> Paragraph<'a> {
         self
     }
 
+    /// Sets where wrapped lines may be broken.
+    ///
+    /// By default, lines are broken on whitespace ([`WrapMode::Word`]). Other [`WrapMode`]s break
+    /// lines between any two characters, or at the break opportunities of the Unicode line breaking
+    /// algorithm, which also handles CJK text and URLs.
+    ///
+    /// This has no effect unless wrapping is enabled with [`Paragraph::wrap`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Paragraph, Wrap, WrapMode};
+    ///
+    /// let paragraph = Paragraph::new("see https://example.com/some/long/path")
+    ///     .wrap(Wrap { trim: true })
+    ///     .wrap_mode(WrapMode::UnicodeLineBreak);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn wrap_mode(mut self, mode: WrapMode) -> Self {
+        self.wrap_mode = mode;
+        self
+    }
+
+    /// Sets whether wrapped lines may be broken at soft hyphens (`U+00AD`).
+    ///
+    /// A soft hyphen is invisible, unless the line is broken at it, in which case a hyphen is
+    /// rendered at the end of the line.
+    ///
+    /// This has no effect unless wrapping is enabled with [`Paragraph::wrap`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Paragraph, Wrap};
+    ///
+    /// let paragraph = Paragraph::new("hyphen\u{ad}ation")
+    ///     .wrap(Wrap { trim: true })
+    ///     .hyphenate(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn hyphenate(mut self, hyphenate: bool) -> Self {
+        self.hyphenate = hyphenate;
+        self
+    }
+
+    /// Sets whether continuation lines repeat the indentation of the line they were wrapped from.
+    ///
+    /// The indentation is kept on the first line even if [`Wrap::trim`] is set.
+    ///
+    /// This has no effect unless wrapping is enabled with [`Paragraph::wrap`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Paragraph, Wrap};
+    ///
+    /// let paragraph = Paragraph::new("    - First thing goes here and is long so that it wraps")
+    ///     .wrap(Wrap { trim: true })
+    ///     .preserve_indent(true);
+    /// // With a width of 30 chars:
+    /// //     - First thing goes here
+    /// //     and is long so that it
+    /// //     wraps
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn preserve_indent(mut self, preserve_indent: bool) -> Self {
+        self.preserve_indent = preserve_indent;
+        self
+    }
+
     /// Set the scroll offset for the given paragraph
     ///
     /// The scroll offset is a tuple of (y, x) offset. The y offset is the number of lines to
@@ -358,7 +541,7 @@ impl<'a> Paragraph<'a> {
             .map(Block::vertical_space)
             .unwrap_or_default();
 
-        let count = if let Some(Wrap { trim }) = self.wrap {
+        let count = if let Some(wrap) = self.wrap_options() {
             let styled = self.text.iter().map(|line| {
                 let graphemes = line
                     .spans
@@ -367,12 +550,11 @@ impl<'a> Paragraph<'a> {
                 let alignment = line.alignment.unwrap_or(self.alignment);
                 (graphemes, alignment)
             });
-            let mut line_composer = WordWrapper::new(styled, width, trim);
-            let mut count = 0;
-            while line_composer.next_line().is_some() {
-                count += 1;
+            if wrap.is_word_wrap() {
+                count_lines(WordWrapper::new(styled, width, wrap.trim))
+            } else {
+                count_lines(BreakWrapper::new(styled, width, wrap))
             }
-            count
         } else {
             self.text.height()
         };
@@ -415,6 +597,76 @@ impl<'a> Paragraph<'a> {
             .saturating_add(left as usize)
             .saturating_add(right as usize)
     }
+
+    /// Returns the layout of the paragraph when rendered in the given area.
+    ///
+    /// The [`ParagraphLayout`] describes each rendered line: the source line it comes from, the
+    /// range of graphemes and bytes of the source line it contains, and the x offset of each
+    /// grapheme. It can be used to map positions in the text to the screen and back, e.g. to place
+    /// a cursor or to find the text under a mouse click.
+    ///
+    /// The layout accounts for wrapping, alignment, scrolling and the [`Block`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::{
+    ///     layout::Rect,
+    ///     widgets::{Block, Paragraph, Wrap},
+    /// };
+    ///
+    /// let paragraph = Paragraph::new("Hello, world!\nSecond line")
+    ///     .block(Block::bordered())
+    ///     .wrap(Wrap::default());
+    /// let layout = paragraph.layout(Rect::new(0, 0, 10, 6));
+    /// let lines = layout.lines();
+    ///
+    /// assert_eq!(lines.len(), 4);
+    /// assert_eq!((lines[1].line, lines[1].graphemes.clone()), (0, 7..13));
+    /// assert_eq!(lines[1].x_offsets, [0, 1, 2, 3, 4, 5, 6]);
+    /// ```
+    pub fn layout(&self, area: Rect) -> ParagraphLayout {
+        let text_area = self.block.inner_if_some(area);
+        let mut lines = vec![];
+        if !text_area.is_empty() {
+            for (index, line) in self.text.iter().enumerate() {
+                let mut graphemes = vec![];
+                let mut span_offset = 0;
+                for span in &line.spans {
+                    // the same graphemes as `Span::styled_graphemes`, which skips newlines
+                    let content = span.content.grapheme_indices(true);
+                    for (offset, grapheme) in content.filter(|(_, grapheme)| *grapheme != "\n") {
+                        let start = span_offset + offset;
+                        graphemes.push(start..start + grapheme.len());
+                    }
+                    span_offset += span.content.len();
+                }
+                let alignment = line.alignment.unwrap_or(self.alignment);
+                let styled = std::iter::once((line.styled_graphemes(self.text.style), alignment));
+                let width = text_area.width;
+                match self.wrap_options() {
+                    Some(wrap) if wrap.is_word_wrap() => {
+                        let composer = WordWrapper::new(styled, width, wrap.trim);
+                        layout::layout_line(composer, index, &graphemes, width, &mut lines);
+                    }
+                    Some(wrap) => {
+                        let composer = BreakWrapper::new(styled, width, wrap);
+                        layout::layout_line(composer, index, &graphemes, width, &mut lines);
+                    }
+                    None => {
+                        let mut composer = LineTruncator::new(styled, width);
+                        composer.set_horizontal_offset(self.scroll.x);
+                        layout::layout_line(composer, index, &graphemes, width, &mut lines);
+                    }
+                }
+            }
+        }
+        ParagraphLayout {
+            area: text_area,
+            scroll: self.scroll,
+            lines,
+        }
+    }
 }
 
 impl Widget for Paragraph<'_> {
@@ -433,21 +685,49 @@ impl Widget for &Paragraph<'_> {
 }
 
 impl Paragraph<'_> {
+    /// Returns the options of the line composer, if the text is wrapped.
+    const fn wrap_options(&self) -> Option<WrapOptions> {
+        match self.wrap {
+            Some(Wrap { trim }) => Some(WrapOptions {
+                trim,
+                mode: self.wrap_mode,
+                hyphenate: self.hyphenate,
+                preserve_indent: self.preserve_indent,
+            }),
+            None => None,
+        }
+    }
+
     fn render_paragraph(&self, text_area: Rect, buf: &mut Buffer) {
         if text_area.is_empty() {
             return;
         }
 
         buf.set_style(text_area, self.style);
-        let styled = self.text.iter().map(|line| {
-            let graphemes = line.styled_graphemes(self.text.style);
+        let selection = self.selection.filter(|selection| !selection.is_empty());
+        let styled = self.text.iter().enumerate().map(|(line_index, line)| {
+            let graphemes = line.styled_graphemes(self.text.style).enumerate().map(
+                move |(column, mut grapheme)| {
+                    // the selection is applied before wrapping so it follows the wrapped lines
+                    let position = TextPosition::new(line_index, column);
+                    if selection.is_some_and(|selection| selection.contains(position)) {
+                        grapheme.style = grapheme.style.patch(self.selection_style);
+                    }
+                    grapheme
+                },
+            );
             let alignment = line.alignment.unwrap_or(self.alignment);
             (graphemes, alignment)
         });
 
-        if let Some(Wrap { trim }) = self.wrap {
-            let line_composer = WordWrapper::new(styled, text_area.width, trim);
-            self.render_text(line_composer, text_area, buf);
+        if let Some(wrap) = self.wrap_options() {
+            if wrap.is_word_wrap() {
+                let line_composer = WordWrapper::new(styled, text_area.width, wrap.trim);
+                self.render_text(line_composer, text_area, buf);
+            } else {
+                let line_composer = BreakWrapper::new(styled, text_area.width, wrap);
+                self.render_text(line_composer, text_area, buf);
+            }
         } else {
             let mut line_composer = LineTruncator::new(styled, text_area.width);
             line_composer.set_horizontal_offset(self.scroll.x);
@@ -463,6 +743,7 @@ impl<'a> Paragraph<'a> {
             line: current_line,
             width: current_line_width,
             alignment: current_line_alignment,
+            ..
         }) = composer.next_line()
         {
             if y >= self.scroll.y {
@@ -489,6 +770,14 @@ impl<'a> Paragraph<'a> {
     }
 }
 
+fn count_lines<'a, C: LineComposer<'a>>(mut composer: C) -> usize {
+    let mut count = 0;
+    while composer.next_line().is_some() {
+        count += 1;
+    }
+    count
+}
+
 impl<'a> Styled for Paragraph<'a> {
     type Item = Self;
 
@@ -505,14 +794,14 @@ impl<'a> Styled for Paragraph<'a> {
 mod tests {
     use ratatui_core::{
         buffer::Buffer,
-        layout::{Alignment, Rect},
+        layout::{Alignment, Position, Rect},
         style::{Color, Modifier, Style, Stylize},
         text::{Line, Span, Text},
         widgets::Widget,
     };
 
     use super::*;
-    use crate::{block::Position, borders::Borders};
+    use crate::{block::Position as TitlePosition, borders::Borders};
 
     /// Tests the [`Paragraph`] widget against the expected [`Buffer`] by rendering it onto an equal
     /// area and comparing the rendered and expected content.
@@ -709,7 +998,7 @@ mod tests {
     fn test_render_paragraph_with_block_with_bottom_title_and_border() {
         let block = Block::new()
             .borders(Borders::BOTTOM)
-            .title_position(Position::Bottom)
+            .title_position(TitlePosition::Bottom)
             .title("Title");
         let paragraph = Paragraph::new("Hello, world!").block(block);
         test_case(
@@ -777,6 +1066,222 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_render_paragraph_with_wrap_modes() {
+        let text = "日本語の文章を折り返す https://ratatui.rs/concepts/layout";
+        let character = Paragraph::new(text)
+            .wrap(Wrap { trim: true })
+            .wrap_mode(WrapMode::Character);
+        let unicode = Paragraph::new(text)
+            .wrap(Wrap { trim: true })
+            .wrap_mode(WrapMode::UnicodeLineBreak);
+
+        test_case(
+            &character,
+            &Buffer::with_lines([
+                "日本語の文章を折",
+                "り返す https://r",
+                "atatui.rs/concep",
+                "ts/layout       ",
+            ]),
+        );
+        test_case(
+            &unicode,
+            &Buffer::with_lines([
+                "日本語の文章を折",
+                "り返す https:// ",
+                "ratatui.rs/     ",
+                "concepts/layout ",
+            ]),
+        );
+    }
+
+    #[test]
+    fn test_render_paragraph_with_hyphenation_and_indent() {
+        let text = "  - a hyper\u{ad}ac\u{ad}tive item";
+        let paragraph = Paragraph::new(text)
+            .wrap(Wrap { trim: true })
+            .hyphenate(true)
+            .preserve_indent(true);
+        test_case(
+            &paragraph,
+            &Buffer::with_lines(["  - a hyper-", "  active    ", "  item      "]),
+        );
+    }
+
+    #[test]
+    fn test_render_paragraph_with_selection() {
+        let paragraph = Paragraph::new("Hello, world!\nSecond line")
+            .wrap(Wrap::default())
+            .selection(Selection::new((1, 3), (0, 4)));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 3));
+        paragraph.render(buffer.area, &mut buffer);
+
+        let mut expected = Buffer::with_lines(["Hello,  ", "world!  ", "Second  "]);
+        expected.set_style(Rect::new(4, 0, 2, 1), Style::new().reversed());
+        expected.set_style(Rect::new(0, 1, 6, 1), Style::new().reversed());
+        expected.set_style(Rect::new(0, 2, 3, 1), Style::new().reversed());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn test_render_paragraph_with_selection_style() {
+        let paragraph = Paragraph::new("Hello, world!")
+            .selection(Selection::new((0, 7), (0, 12)))
+            .selection_style(Style::new().on_blue());
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 13, 1));
+        paragraph.render(buffer.area, &mut buffer);
+
+        let mut expected = Buffer::with_lines(["Hello, world!"]);
+        expected.set_style(Rect::new(7, 0, 5, 1), Style::new().on_blue());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn selected_text() {
+        let paragraph = Paragraph::new("Hello, world!\nSecond line");
+        assert_eq!(paragraph.selected_text(), None);
+        let paragraph = paragraph.selection(Selection::new((1, 6), (0, 7)));
+        assert_eq!(paragraph.selected_text().as_deref(), Some("world!\nSecond"));
+    }
+
+    #[test]
+    fn layout_wrapped() {
+        let paragraph = Paragraph::new("Hello, world!\n\nSecond").wrap(Wrap { trim: true });
+        let layout = paragraph.layout(Rect::new(0, 0, 8, 4));
+        assert_eq!(
+            layout.lines(),
+            [
+                RenderedLine {
+                    line: 0,
+                    graphemes: 0..6,
+                    bytes: 0..6,
+                    x_offsets: vec![0, 1, 2, 3, 4, 5, 6],
+                },
+                RenderedLine {
+                    line: 0,
+                    graphemes: 7..13,
+                    bytes: 7..13,
+                    x_offsets: vec![0, 1, 2, 3, 4, 5, 6],
+                },
+                RenderedLine {
+                    line: 1,
+                    graphemes: 0..0,
+                    bytes: 0..0,
+                    x_offsets: vec![0],
+                },
+                RenderedLine {
+                    line: 2,
+                    graphemes: 0..6,
+                    bytes: 0..6,
+                    x_offsets: vec![0, 1, 2, 3, 4, 5, 6],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn layout_wide_graphemes_and_bytes() {
+        let paragraph = Paragraph::new(Line::from(vec!["日本".red(), "語 ok".into()]))
+            .wrap(Wrap::default())
+            .centered();
+        let layout = paragraph.layout(Rect::new(0, 0, 6, 2));
+        assert_eq!(
+            layout.lines(),
+            [
+                RenderedLine {
+                    line: 0,
+                    graphemes: 0..3,
+                    bytes: 0..9,
+                    x_offsets: vec![0, 2, 4, 6],
+                },
+                RenderedLine {
+                    line: 0,
+                    graphemes: 4..6,
+                    bytes: 10..12,
+                    x_offsets: vec![2, 3, 4],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn layout_hyphen_and_indent() {
+        let paragraph = Paragraph::new("  hyper\u{ad}active")
+            .wrap(Wrap { trim: true })
+            .hyphenate(true)
+            .preserve_indent(true);
+        let layout = paragraph.layout(Rect::new(0, 0, 9, 2));
+        let lines = layout.lines();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].graphemes, 0..8);
+        assert_eq!(lines[1].graphemes, 8..14);
+        // the continuation line starts after the repeated indentation
+        assert_eq!(lines[1].x_offsets, [2, 3, 4, 5, 6, 7, 8]);
+    }
+
+    #[test]
+    fn layout_position_mapping() {
+        let paragraph = Paragraph::new("first line\nsecond line")
+            .block(Block::bordered())
+            .wrap(Wrap::default())
+            .scroll((1, 0));
+        let layout = paragraph.layout(Rect::new(0, 0, 9, 4));
+        assert_eq!(layout.area(), Rect::new(1, 1, 7, 2));
+        assert_eq!(layout.lines().len(), 4);
+        assert_eq!(layout.visible_lines(), &layout.lines()[1..3]);
+
+        // scrolled out of view
+        assert_eq!(layout.position_of(TextPosition::new(0, 0)), None);
+        assert_eq!(
+            layout.position_of(TextPosition::new(0, 7)),
+            Some(Position::new(2, 1))
+        );
+        // the end of a line, and the trimmed space at the line break
+        assert_eq!(
+            layout.position_of(TextPosition::new(0, 10)),
+            Some(Position::new(5, 1))
+        );
+        assert_eq!(
+            layout.position_of(TextPosition::new(1, 6)),
+            Some(Position::new(7, 2))
+        );
+        assert_eq!(layout.position_of(TextPosition::new(2, 0)), None);
+
+        assert_eq!(
+            layout.text_position_at(Position::new(2, 1)),
+            Some(TextPosition::new(0, 7))
+        );
+        assert_eq!(
+            layout.text_position_at(Position::new(7, 1)),
+            Some(TextPosition::new(0, 10))
+        );
+        assert_eq!(
+            layout.text_position_at(Position::new(1, 2)),
+            Some(TextPosition::new(1, 0))
+        );
+        assert_eq!(layout.text_position_at(Position::new(0, 0)), None);
+    }
+
+    #[test]
+    fn layout_horizontal_scroll() {
+        let paragraph = Paragraph::new("Hello, world!").scroll((0, 7));
+        let layout = paragraph.layout(Rect::new(0, 0, 4, 1));
+        assert_eq!(
+            layout.lines(),
+            [RenderedLine {
+                line: 0,
+                graphemes: 7..11,
+                bytes: 7..11,
+                x_offsets: vec![0, 1, 2, 3, 4],
+            }]
+        );
+        assert_eq!(
+            layout.text_position_at(Position::new(1, 0)),
+            Some(TextPosition::new(0, 8))
+        );
+    }
+
     #[test]
     fn test_render_paragraph_with_line_truncation() {
         let text = "This is a long line of text that should be truncated.";
@@ -1041,6 +1546,29 @@ mod tests {
         assert_eq!(paragraph.line_count(6), 200);
     }
 
+    #[test]
+    fn widgets_paragraph_count_rendered_lines_with_wrap_modes() {
+        let text = "日本語 の文章を折り返す";
+        for mode in [
+            WrapMode::Word,
+            WrapMode::Character,
+            WrapMode::UnicodeLineBreak,
+        ] {
+            let paragraph = Paragraph::new(text)
+                .wrap(Wrap { trim: true })
+                .wrap_mode(mode);
+            let expected = if mode == WrapMode::Word { 3 } else { 2 };
+            assert_eq!(paragraph.line_count(12), expected, "{mode:?}");
+        }
+
+        let paragraph = Paragraph::new("  indented hyphen\u{ad}ation")
+            .wrap(Wrap { trim: true })
+            .hyphenate(true)
+            .preserve_indent(true);
+        assert_eq!(paragraph.line_count(12), 3);
+        assert_eq!(paragraph.line_count(30), 1);
+    }
+
     #[test]
     fn widgets_paragraph_rendered_line_count_accounts_block() {
         let block = Block::new();
@@ -0,0 +1,221 @@
+use std::ops::Range;
+
+use ratatui_core::layout::{Position, Rect};
+use unicode_width::UnicodeWidthStr;
+
+use super::{get_line_offset, TextPosition};
+use crate::reflow::{LineComposer, WrappedLine};
+
+/// A row of a rendered [`Paragraph`], and the part of the source text that is rendered in it.
+///
+/// A source line of the paragraph's text results in one or more rendered lines when it is wrapped.
+/// Graphemes that are not rendered, such as whitespace that is trimmed at a line break, do not
+/// belong to any rendered line.
+///
+/// [`Paragraph`]: super::Paragraph
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct RenderedLine {
+    /// The index of the source [`Line`] in the paragraph's text
+    ///
+    /// [`Line`]: ratatui_core::text::Line
+    pub line: usize,
+    /// The range of graphemes of the source line that is rendered in this line
+    pub graphemes: Range<usize>,
+    /// The range of bytes of the source line that is rendered in this line
+    ///
+    /// The byte offsets are relative to the concatenated content of the spans of the source line.
+    pub bytes: Range<usize>,
+    /// The x offset of each grapheme in [`graphemes`], relative to the left of the text area
+    ///
+    /// This contains one extra value at the end, the offset just past the last grapheme, so the
+    /// columns covered by grapheme `i` are `x_offsets[i]..x_offsets[i + 1]`.
+    ///
+    /// [`graphemes`]: Self::graphemes
+    pub x_offsets: Vec<u16>,
+}
+
+impl RenderedLine {
+    /// Returns the x offset of the given grapheme of the source line, relative to the left of the
+    /// text area.
+    ///
+    /// Graphemes before this line are placed at its start, and graphemes after it at its end.
+    fn x_offset(&self, column: usize) -> u16 {
+        let index = column
+            .saturating_sub(self.graphemes.start)
+            .min(self.x_offsets.len() - 1);
+        self.x_offsets[index]
+    }
+
+    /// Returns the grapheme of the source line at the given x offset.
+    fn column(&self, x: u16) -> usize {
+        let index = self
+            .x_offsets
+            .windows(2)
+            .position(|offsets| offsets[0] <= x && x < offsets[1]);
+        match index {
+            Some(index) => self.graphemes.start + index,
+            None if x < self.x_offsets[0] => self.graphemes.start,
+            None => self.graphemes.end,
+        }
+    }
+}
+
+/// The layout of a rendered [`Paragraph`], mapping positions in its text to the screen and back.
+///
+/// This is created by [`Paragraph::layout`] and takes wrapping, alignment, scrolling and the
+/// [`Block`] of the paragraph into account. It is useful for building editors, search highlighting
+/// and click-to-position on top of a `Paragraph`.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     layout::{Position, Rect},
+///     widgets::{Paragraph, TextPosition, Wrap},
+/// };
+///
+/// let paragraph = Paragraph::new("Hello, world!").wrap(Wrap::default());
+/// let layout = paragraph.layout(Rect::new(0, 0, 8, 2));
+///
+/// assert_eq!(layout.lines().len(), 2);
+/// assert_eq!(
+///     layout.position_of(TextPosition::new(0, 9)),
+///     Some(Position::new(2, 1))
+/// );
+/// assert_eq!(
+///     layout.text_position_at(Position::new(2, 1)),
+///     Some(TextPosition::new(0, 9))
+/// );
+/// ```
+///
+/// [`Paragraph`]: super::Paragraph
+/// [`Paragraph::layout`]: super::Paragraph::layout
+/// [`Block`]: crate::block::Block
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct ParagraphLayout {
+    pub(super) area: Rect,
+    pub(super) scroll: Position,
+    pub(super) lines: Vec<RenderedLine>,
+}
+
+impl ParagraphLayout {
+    /// Returns the area the text is rendered in, excluding the block.
+    pub const fn area(&self) -> Rect {
+        self.area
+    }
+
+    /// Returns all rendered lines, including those that are scrolled out of view.
+    ///
+    /// The index of a rendered line minus the vertical scroll offset of the paragraph is its row in
+    /// the [`area`](Self::area).
+    pub fn lines(&self) -> &[RenderedLine] {
+        &self.lines
+    }
+
+    /// Returns the rendered lines that are visible in the [`area`](Self::area).
+    pub fn visible_lines(&self) -> &[RenderedLine] {
+        let start = (self.scroll.y as usize).min(self.lines.len());
+        let end = (start + self.area.height as usize).min(self.lines.len());
+        &self.lines[start..end]
+    }
+
+    /// Returns the screen position of the given position in the text.
+    ///
+    /// A position past the end of a line is placed just after its last grapheme, and positions
+    /// that are not rendered (e.g. whitespace trimmed at a line break) are placed at the end of the
+    /// preceding rendered line. The returned x coordinate is clamped to the area.
+    ///
+    /// Returns `None` if the line does not exist or is scrolled out of view vertically.
+    pub fn position_of(&self, position: TextPosition) -> Option<Position> {
+        let (index, line) = self
+            .lines
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| line.line == position.line)
+            .take_while(|(_, line)| line.graphemes.start <= position.column)
+            .last()
+            .or_else(|| {
+                self.lines
+                    .iter()
+                    .enumerate()
+                    .find(|(_, line)| line.line == position.line)
+            })?;
+        let row = index.checked_sub(self.scroll.y as usize)?;
+        if row >= self.area.height as usize {
+            return None;
+        }
+        let x = line
+            .x_offset(position.column)
+            .min(self.area.width.saturating_sub(1));
+        Some(Position::new(self.area.x + x, self.area.y + row as u16))
+    }
+
+    /// Returns the position in the text that is rendered at the given screen position.
+    ///
+    /// A screen position after the end of a rendered line maps to the position just after its last
+    /// grapheme.
+    ///
+    /// Returns `None` if the screen position is outside the area, or below the last line.
+    pub fn text_position_at(&self, position: Position) -> Option<TextPosition> {
+        if !self.area.contains(position) {
+            return None;
+        }
+        let index = (position.y - self.area.y) as usize + self.scroll.y as usize;
+        let line = self.lines.get(index)?;
+        let column = line.column(position.x - self.area.x);
+        Some(TextPosition::new(line.line, column))
+    }
+}
+
+/// Appends the rendered lines produced by `composer` for the source line `line`.
+///
+/// `graphemes` contains the byte range of each grapheme of the source line. The rendered graphemes
+/// are matched to the source graphemes by the indices the composer reports for them. Symbols that
+/// are added by the composer, such as the hyphen at a soft hyphen, or the repeated indentation of a
+/// line, don't match any source grapheme.
+pub(super) fn layout_line<'a, C: LineComposer<'a>>(
+    mut composer: C,
+    line: usize,
+    graphemes: &[Range<usize>],
+    area_width: u16,
+    lines: &mut Vec<RenderedLine>,
+) {
+    let mut next = 0;
+    while let Some(WrappedLine {
+        line: wrapped,
+        indices,
+        width,
+        alignment,
+    }) = composer.next_line()
+    {
+        let mut x = get_line_offset(width, area_width, alignment);
+        let mut start = None;
+        let mut x_offsets = vec![];
+        for (grapheme, index) in wrapped.iter().zip(indices) {
+            if let Some(index) = *index {
+                // graphemes that were skipped by the composer are placed at the current offset
+                let count = if start.is_some() { index + 1 - next } else { 1 };
+                x_offsets.extend(std::iter::repeat(x).take(count));
+                start.get_or_insert(index);
+                next = index + 1;
+            }
+            x += grapheme.symbol.width() as u16;
+        }
+        x_offsets.push(x);
+        let start = start.unwrap_or(next);
+        let byte_start = graphemes.get(start).map_or_else(
+            || graphemes.last().map_or(0, |bytes| bytes.end),
+            |bytes| bytes.start,
+        );
+        let byte_end = graphemes[..next]
+            .last()
+            .map_or(byte_start, |bytes| bytes.end)
+            .max(byte_start);
+        lines.push(RenderedLine {
+            line,
+            graphemes: start..next,
+            bytes: byte_start..byte_end,
+            x_offsets,
+        });
+    }
+}
@@ -0,0 +1,213 @@
+use ratatui_core::text::Text;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// A position in the text of a [`Paragraph`], independent of how the text is wrapped.
+///
+/// The `line` is the index of a [`Line`] in the paragraph's [`Text`], and the `column` is the index
+/// of a grapheme in that line (not a byte offset or a screen column).
+///
+/// [`Paragraph`]: super::Paragraph
+/// [`Line`]: ratatui_core::text::Line
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TextPosition {
+    /// The index of the line in the text
+    pub line: usize,
+    /// The index of the grapheme in the line
+    pub column: usize,
+}
+
+impl TextPosition {
+    /// Creates a new `TextPosition` from a line and column index.
+    pub const fn new(line: usize, column: usize) -> Self {
+        Self { line, column }
+    }
+}
+
+impl From<(usize, usize)> for TextPosition {
+    fn from((line, column): (usize, usize)) -> Self {
+        Self { line, column }
+    }
+}
+
+/// A range of selected text in a [`Paragraph`].
+///
+/// The selection is set on the paragraph with [`Paragraph::selection`]. It is usually stored in the
+/// application state and updated in response to user input.
+///
+/// A selection is made up of an `anchor`, the position where the selection was started, and a
+/// `cursor`, the position the selection was extended to. The cursor can be before or after the
+/// anchor. The grapheme at the anchor is included in the selection, and the selection extends up to
+/// (but not including) the grapheme at the cursor.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::widgets::{Selection, TextPosition};
+///
+/// let mut selection = Selection::caret(TextPosition::new(0, 6));
+/// selection.extend_to(TextPosition::new(1, 3));
+/// assert!(selection.contains(TextPosition::new(0, 10)));
+/// assert!(!selection.contains(TextPosition::new(1, 3)));
+/// ```
+///
+/// [`Paragraph`]: super::Paragraph
+/// [`Paragraph::selection`]: super::Paragraph::selection
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Selection {
+    /// The position where the selection was started
+    pub anchor: TextPosition,
+    /// The position the selection was extended to
+    pub cursor: TextPosition,
+}
+
+impl Selection {
+    /// Creates a new selection from `anchor` to `cursor`.
+    ///
+    /// `anchor` and `cursor` accept any type that is convertible to [`TextPosition`] (e.g. a tuple
+    /// of `(line, column)`).
+    pub fn new<A: Into<TextPosition>, C: Into<TextPosition>>(anchor: A, cursor: C) -> Self {
+        Self {
+            anchor: anchor.into(),
+            cursor: cursor.into(),
+        }
+    }
+
+    /// Creates an empty selection with both the anchor and cursor at `position`.
+    pub const fn caret(position: TextPosition) -> Self {
+        Self {
+            anchor: position,
+            cursor: position,
+        }
+    }
+
+    /// Moves the cursor of the selection to `position`, keeping the anchor in place.
+    pub fn extend_to<P: Into<TextPosition>>(&mut self, position: P) {
+        self.cursor = position.into();
+    }
+
+    /// Returns the start and end of the selection, in text order.
+    pub fn range(&self) -> (TextPosition, TextPosition) {
+        if self.anchor <= self.cursor {
+            (self.anchor, self.cursor)
+        } else {
+            (self.cursor, self.anchor)
+        }
+    }
+
+    /// Returns true if the selection does not contain any text.
+    pub fn is_empty(&self) -> bool {
+        self.anchor == self.cursor
+    }
+
+    /// Returns true if the grapheme at `position` is selected.
+    pub fn contains(&self, position: TextPosition) -> bool {
+        let (start, end) = self.range();
+        start <= position && position < end
+    }
+
+    /// Returns the plain text covered by the selection in the given [`Text`].
+    ///
+    /// Selected parts of different lines are joined with a newline. Positions past the end of a
+    /// line or the text are clamped.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::{text::Text, widgets::Selection};
+    ///
+    /// let text = Text::from("Hello, world!\nSecond line");
+    /// let selection = Selection::new((0, 7), (1, 6));
+    /// assert_eq!(selection.extract(&text), "world!\nSecond");
+    /// ```
+    pub fn extract(&self, text: &Text<'_>) -> String {
+        let (start, end) = self.range();
+        let mut selected = String::new();
+        for (index, line) in text.lines.iter().enumerate() {
+            if index < start.line || index > end.line {
+                continue;
+            }
+            if index > start.line {
+                selected.push('\n');
+            }
+            let first = if index == start.line { start.column } else { 0 };
+            let last = if index == end.line {
+                end.column
+            } else {
+                usize::MAX
+            };
+            let graphemes = line
+                .spans
+                .iter()
+                .flat_map(|span| span.content.graphemes(true))
+                // newlines are not rendered, so they don't occupy a column
+                .filter(|grapheme| *grapheme != "\n");
+            selected.extend(graphemes.skip(first).take(last.saturating_sub(first)));
+        }
+        selected
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui_core::text::Line;
+
+    use super::*;
+
+    #[test]
+    fn selection_range_is_ordered() {
+        let forward = Selection::new((0, 2), (1, 1));
+        let backward = Selection::new((1, 1), (0, 2));
+        let expected = (TextPosition::new(0, 2), TextPosition::new(1, 1));
+        assert_eq!(forward.range(), expected);
+        assert_eq!(backward.range(), expected);
+    }
+
+    #[test]
+    fn selection_contains() {
+        let selection = Selection::new((1, 1), (0, 2));
+        assert!(!selection.contains(TextPosition::new(0, 1)));
+        assert!(selection.contains(TextPosition::new(0, 2)));
+        assert!(selection.contains(TextPosition::new(0, 100)));
+        assert!(selection.contains(TextPosition::new(1, 0)));
+        assert!(!selection.contains(TextPosition::new(1, 1)));
+        assert!(Selection::caret(TextPosition::new(0, 0)).is_empty());
+    }
+
+    #[test]
+    fn extract_single_line() {
+        let text = Text::from("Hello, world!");
+        assert_eq!(Selection::new((0, 7), (0, 12)).extract(&text), "world");
+        assert_eq!(Selection::new((0, 12), (0, 7)).extract(&text), "world");
+        assert_eq!(Selection::new((0, 7), (0, 100)).extract(&text), "world!");
+        assert_eq!(Selection::new((0, 3), (0, 3)).extract(&text), "");
+    }
+
+    #[test]
+    fn extract_multiple_lines() {
+        let text = Text::from(vec![
+            Line::from(vec!["first ".into(), "line".into()]),
+            Line::from("second line"),
+            Line::from("third line"),
+        ]);
+        let selection = Selection::new((0, 6), (2, 5));
+        assert_eq!(selection.extract(&text), "line\nsecond line\nthird");
+    }
+
+    #[test]
+    fn extract_graphemes() {
+        let text = Text::from("日本語のテキスト e\u{301}");
+        assert_eq!(
+            Selection::new((0, 4), (0, 10)).extract(&text),
+            "テキスト e\u{301}"
+        );
+    }
+
+    #[test]
+    fn extract_skips_newlines_in_spans() {
+        let text = Text::from(Line::from(vec!["ab\n".into(), "cd".into()]));
+        assert_eq!(Selection::new((0, 1), (0, 3)).extract(&text), "bc");
+        assert_eq!(Selection::new((0, 0), (0, 100)).extract(&text), "abcd");
+    }
+}
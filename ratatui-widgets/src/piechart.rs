@@ -0,0 +1,528 @@
+//! The [`PieChart`] widget and its related types (e.g. [`Slice`]).
+
+use std::f64::consts::TAU;
+
+use ratatui_core::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style, Styled},
+    symbols::{self, Marker},
+    text::{Line, Span},
+    widgets::Widget,
+};
+
+use crate::{
+    block::{Block, BlockExt},
+    canvas::{Canvas, Painter, Shape},
+    chart::{LabelFormat, LegendPosition},
+};
+
+/// The colors of the slices without a color, in order.
+const DEFAULT_COLORS: [Color; 6] = [
+    Color::Blue,
+    Color::Green,
+    Color::Yellow,
+    Color::Magenta,
+    Color::Cyan,
+    Color::Red,
+];
+
+/// A chart showing the shares of a total as the slices of a pie or a donut
+///
+/// Each [`Slice`] has an angle proportional to its value, starting at the top and going clockwise.
+/// The pie is drawn on a [`Canvas`] with the given [`Marker`], and is kept circular by taking into
+/// account that the cells of the terminal are about twice as high as wide.
+///
+/// A legend listing the label, the color and the share of each slice can be displayed next to the
+/// pie, at the given [`LegendPosition`]. It is hidden if it would take more than half of the area.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::{
+///     style::Color,
+///     widgets::{Block, LegendPosition, PieChart, Slice},
+/// };
+///
+/// PieChart::new([
+///     Slice::new("system", 12.0).color(Color::Red),
+///     Slice::new("home", 48.0).color(Color::Blue),
+///     Slice::new("free", 40.0).color(Color::DarkGray),
+/// ])
+/// .block(Block::bordered().title("Disk usage"))
+/// .inner_radius(0.5)
+/// .legend_position(Some(LegendPosition::Right));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct PieChart<'a> {
+    /// Block to wrap the widget in
+    block: Option<Block<'a>>,
+    /// The slices of the pie, clockwise from the top
+    slices: Vec<Slice<'a>>,
+    /// The radius of the hole of a donut, as a fraction of the radius of the pie
+    inner_radius: f64,
+    /// The marker used to draw the pie
+    marker: Marker,
+    /// The position of the legend, if displayed
+    legend_position: Option<LegendPosition>,
+    /// Style for the widget
+    style: Style,
+}
+
+/// A slice of a [`PieChart`]
+///
+/// The slices with a value which is not finite or not positive are not displayed.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Slice<'a> {
+    /// The label displayed in the legend
+    label: Line<'a>,
+    /// The value of the slice
+    value: f64,
+    /// The color of the slice, if not the default one
+    color: Option<Color>,
+}
+
+impl<'a> Slice<'a> {
+    /// Creates a slice with the given label and value
+    pub fn new<T: Into<Line<'a>>>(label: T, value: f64) -> Self {
+        Self {
+            label: label.into(),
+            value,
+            color: None,
+        }
+    }
+
+    /// Sets the color of the slice
+    ///
+    /// If not set, the slices take the colors blue, green, yellow, magenta, cyan and red in turn.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Returns true if the slice is displayed, i.e. if its value is finite and positive.
+    fn is_visible(&self) -> bool {
+        self.value.is_finite() && self.value > 0.0
+    }
+}
+
+impl<'a, T: Into<Line<'a>>> From<(T, f64)> for Slice<'a> {
+    fn from((label, value): (T, f64)) -> Self {
+        Self::new(label, value)
+    }
+}
+
+impl Default for PieChart<'_> {
+    fn default() -> Self {
+        Self {
+            block: None,
+            slices: Vec::new(),
+            inner_radius: 0.0,
+            marker: Marker::HalfBlock,
+            legend_position: Some(LegendPosition::Right),
+            style: Style::default(),
+        }
+    }
+}
+
+impl<'a> PieChart<'a> {
+    /// Creates a pie chart of the given slices, clockwise from the top
+    ///
+    /// The slices can be [`Slice`]s or `(label, value)` tuples.
+    pub fn new<T>(slices: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: Into<Slice<'a>>,
+    {
+        Self {
+            slices: slices.into_iter().map(Into::into).collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Surrounds the `PieChart` with a [`Block`]
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the radius of the hole of a donut chart, as a fraction of the radius of the pie
+    ///
+    /// Defaults to `0.0`, i.e. a pie without hole. The radius is clamped between `0.0` and `1.0`.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn inner_radius(mut self, radius: f64) -> Self {
+        self.inner_radius = radius;
+        self
+    }
+
+    /// Sets the marker used to draw the pie
+    ///
+    /// Defaults to [`Marker::HalfBlock`], which fills the slices. [`Marker::Braille`] has a higher
+    /// resolution, with a dotted texture.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn marker(mut self, marker: Marker) -> Self {
+        self.marker = marker;
+        self
+    }
+
+    /// Sets the position of the legend or hides it
+    ///
+    /// The legend is displayed on the side of the pie given by the position, e.g. on the right for
+    /// [`LegendPosition::TopRight`], [`LegendPosition::Right`] and [`LegendPosition::BottomRight`].
+    /// Defaults to [`LegendPosition::Right`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn legend_position(mut self, position: Option<LegendPosition>) -> Self {
+        self.legend_position = position;
+        self
+    }
+
+    /// Sets the style of the entire widget
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// The style will be applied to everything that isn't styled (borders, legend, ...).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+impl PieChart<'_> {
+    /// Returns the visible slices with their color, and the fraction of the total they start and
+    /// end at.
+    fn sectors(&self) -> Vec<Sector> {
+        let total: f64 = self
+            .slices
+            .iter()
+            .filter(|slice| slice.is_visible())
+            .map(|slice| slice.value)
+            .sum();
+        let mut start = 0.0;
+        self.slices
+            .iter()
+            .enumerate()
+            .filter(|(_, slice)| slice.is_visible())
+            .map(|(index, slice)| {
+                let end = start + slice.value / total;
+                let sector = Sector {
+                    start,
+                    end,
+                    color: slice
+                        .color
+                        .unwrap_or(DEFAULT_COLORS[index % DEFAULT_COLORS.len()]),
+                };
+                start = end;
+                sector
+            })
+            .collect()
+    }
+
+    /// Returns the lines of the legend: the color, the label and the share of each slice.
+    fn legend_lines(&self, sectors: &[Sector]) -> Vec<Line<'_>> {
+        let slices = self.slices.iter().filter(|slice| slice.is_visible());
+        slices
+            .zip(sectors)
+            .map(|(slice, sector)| {
+                let share = LabelFormat::Percent.format(sector.end - sector.start, 0.01);
+                let mut line = Line::from(Span::styled(symbols::block::FULL, sector.color));
+                line.push_span(" ");
+                line.extend(slice.label.clone());
+                line.push_span(format!(" {share}"));
+                line
+            })
+            .collect()
+    }
+
+    /// Returns the area of the pie and the area of the legend, if it fits.
+    fn layout(&self, area: Rect, lines: &[Line]) -> (Rect, Option<Rect>) {
+        let Some(position) = self.legend_position else {
+            return (area, None);
+        };
+        let width = lines.iter().map(Line::width).max().unwrap_or_default() as u16 + 2;
+        let height = lines.len() as u16 + 2;
+        let (pie, strip) = match position {
+            LegendPosition::Left | LegendPosition::TopLeft | LegendPosition::BottomLeft
+                if width <= area.width / 2 =>
+            {
+                let strip = Rect { width, ..area };
+                let pie = Rect {
+                    x: area.x + width,
+                    width: area.width - width,
+                    ..area
+                };
+                (pie, strip)
+            }
+            LegendPosition::Right | LegendPosition::TopRight | LegendPosition::BottomRight
+                if width <= area.width / 2 =>
+            {
+                let strip = Rect {
+                    x: area.right() - width,
+                    width,
+                    ..area
+                };
+                (
+                    Rect {
+                        width: area.width - width,
+                        ..area
+                    },
+                    strip,
+                )
+            }
+            LegendPosition::Top if height <= area.height / 2 => {
+                let strip = Rect { height, ..area };
+                let pie = Rect {
+                    y: area.y + height,
+                    height: area.height - height,
+                    ..area
+                };
+                (pie, strip)
+            }
+            LegendPosition::Bottom if height <= area.height / 2 => {
+                let strip = Rect {
+                    y: area.bottom() - height,
+                    height,
+                    ..area
+                };
+                (
+                    Rect {
+                        height: area.height - height,
+                        ..area
+                    },
+                    strip,
+                )
+            }
+            _ => return (area, None),
+        };
+        (pie, position.layout(strip, width, height, 0, 0))
+    }
+}
+
+/// The part of the pie of a slice
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Sector {
+    /// The fraction of the turn where the slice starts, clockwise from the top
+    start: f64,
+    /// The fraction of the turn where the slice ends, clockwise from the top
+    end: f64,
+    /// The color of the slice
+    color: Color,
+}
+
+/// A shape filling the slices of a pie centered on the origin
+struct Pie<'a> {
+    /// The slices of the pie
+    sectors: &'a [Sector],
+    /// The radius of the hole in the middle
+    inner_radius: f64,
+    /// The radius of the pie
+    outer_radius: f64,
+}
+
+impl Shape for Pie<'_> {
+    fn draw(&self, painter: &mut Painter) {
+        let ([left, right], [bottom, top]) = painter.bounds();
+        let (width, height) = painter.resolution();
+        let x_step = (right - left) / (width - 1.0).max(1.0);
+        let y_step = (top - bottom) / (height - 1.0).max(1.0);
+        for row in 0..height as usize {
+            let y = top - row as f64 * y_step;
+            for column in 0..width as usize {
+                let x = left + column as f64 * x_step;
+                let radius = x.hypot(y);
+                if radius < self.inner_radius || radius > self.outer_radius {
+                    continue;
+                }
+                // clockwise from the top
+                let turn = x.atan2(y).rem_euclid(TAU) / TAU;
+                if let Some(sector) = self
+                    .sectors
+                    .iter()
+                    .find(|sector| turn >= sector.start && turn < sector.end)
+                    .or_else(|| self.sectors.last())
+                {
+                    painter.paint(column, row, sector.color);
+                }
+            }
+        }
+    }
+}
+
+impl Widget for PieChart<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl Widget for &PieChart<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        buf.set_style(area, self.style);
+        self.block.as_ref().render(area, buf);
+        let inner = self.block.inner_if_some(area);
+        if inner.is_empty() {
+            return;
+        }
+        let sectors = self.sectors();
+        let lines = self.legend_lines(&sectors);
+        let (pie_area, legend_area) = self.layout(inner, &lines);
+
+        if !pie_area.is_empty() {
+            // a cell is about twice as high as wide, so a unit of the canvas is the width of a cell
+            // horizontally and half its height vertically
+            let half_width = f64::from(pie_area.width) / 2.0;
+            let half_height = f64::from(pie_area.height);
+            let outer_radius = half_width.min(half_height);
+            let pie = Pie {
+                sectors: &sectors,
+                inner_radius: outer_radius * self.inner_radius.clamp(0.0, 1.0),
+                outer_radius,
+            };
+            Canvas::default()
+                .background_color(self.style.bg.unwrap_or(Color::Reset))
+                .x_bounds([-half_width, half_width])
+                .y_bounds([-half_height, half_height])
+                .marker(self.marker)
+                .paint(|ctx| ctx.draw(&pie))
+                .render(pie_area, buf);
+        }
+
+        if let Some(legend_area) = legend_area {
+            Block::bordered().render(legend_area, buf);
+            for (line, y) in lines
+                .iter()
+                .zip(legend_area.top() + 1..legend_area.bottom() - 1)
+            {
+                let line_area = Rect {
+                    x: legend_area.x + 1,
+                    y,
+                    width: legend_area.width - 2,
+                    height: 1,
+                };
+                line.render(line_area, buf);
+            }
+        }
+    }
+}
+
+impl Styled for PieChart<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use ratatui_core::buffer::Cell;
+
+    use super::*;
+
+    #[test]
+    fn sectors() {
+        let chart = PieChart::new([
+            Slice::new("a", 1.0).color(Color::Red),
+            Slice::new("b", -2.0),
+            Slice::new("c", 3.0),
+        ]);
+        assert_eq!(
+            chart.sectors(),
+            [
+                Sector {
+                    start: 0.0,
+                    end: 0.25,
+                    color: Color::Red,
+                },
+                Sector {
+                    start: 0.25,
+                    end: 1.0,
+                    color: Color::Yellow,
+                },
+            ]
+        );
+    }
+
+    /// Returns the symbols of each line of the buffer.
+    fn symbols(buf: &Buffer) -> Vec<String> {
+        buf.content
+            .chunks(usize::from(buf.area.width))
+            .map(|line| line.iter().map(Cell::symbol).collect())
+            .collect()
+    }
+
+    #[test]
+    fn render_pie() {
+        let chart = PieChart::new([("a", 1.0), ("b", 1.0)]).legend_position(None);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 4));
+        chart.render(buf.area, &mut buf);
+        assert_eq!(
+            symbols(&buf),
+            ["  ▄▄▄▄  ", " ██████ ", " ██████ ", "  ▀▀▀▀  "]
+        );
+        // the first slice is on the right of the top, the second one on the left
+        assert_eq!(buf[(5, 1)].fg, Color::Blue);
+        assert_eq!(buf[(2, 2)].fg, Color::Green);
+    }
+
+    #[test]
+    fn render_donut() {
+        let chart = PieChart::new([("a", 1.0)])
+            .inner_radius(0.6)
+            .legend_position(None);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 4));
+        chart.render(buf.area, &mut buf);
+        assert_eq!(
+            symbols(&buf),
+            ["  ▄▄▄▄  ", " █▀  ▀█ ", " █▄  ▄█ ", "  ▀▀▀▀  "]
+        );
+    }
+
+    #[test]
+    fn render_legend() {
+        let chart = PieChart::new([("a", 1.0), ("b", 3.0)]);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 18, 4));
+        chart.render(buf.area, &mut buf);
+        assert_eq!(
+            symbols(&buf),
+            [
+                "  ▄▄█▄▄  ┌───────┐",
+                " ███████ │█ a 25%│",
+                " ███████ │█ b 75%│",
+                "  ▀▀█▀▀  └───────┘",
+            ]
+        );
+        assert_eq!(buf[(10, 1)].fg, Color::Blue);
+        assert_eq!(buf[(10, 2)].fg, Color::Green);
+    }
+
+    #[test]
+    fn render_legend_too_wide() {
+        let chart = PieChart::new([("a", 1.0)]).legend_position(Some(LegendPosition::Left));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 4));
+        chart.render(buf.area, &mut buf);
+        assert_eq!(
+            symbols(&buf),
+            ["  ▄▄▄▄  ", " ██████ ", " ██████ ", "  ▀▀▀▀  "]
+        );
+    }
+}
@@ -0,0 +1,286 @@
+//! The [`Popup`] and [`Dialog`] widgets display content over other widgets.
+use ratatui_core::{
+    buffer::Buffer,
+    layout::{Rect, Size},
+    style::{Style, Styled},
+    text::Text,
+    widgets::Widget,
+};
+
+pub use self::{
+    anchor::Anchor,
+    dialog::{Dialog, DialogState},
+};
+use crate::{block::Block, clear::Clear};
+
+mod anchor;
+mod dialog;
+
+/// A widget to display content in a popup over other widgets
+///
+/// The popup is rendered over the widgets previously rendered in the area: it is positioned
+/// relative to an [`Anchor`] in the area, cleared with [`Clear`], optionally drawn with a shadow,
+/// and surrounded with a [`Block`]. It is sized to fit its content unless a size is given, and
+/// flipped to the other side of its anchor or moved inside the area when it would overflow it.
+///
+/// The area given to the popup is the area it may be displayed in, usually the whole frame, not the
+/// area of the popup itself. Use [`Popup::area`] to know where it is displayed, e.g. to render
+/// another widget inside the block or to handle mouse clicks.
+///
+/// See [`Dialog`] for a popup with a row of buttons.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     layout::Rect,
+///     style::{Style, Stylize},
+///     widgets::{Anchor, Block, Popup},
+///     Frame,
+/// };
+///
+/// # fn ui(frame: &mut Frame) {
+/// let popup = Popup::new("The file was saved.")
+///     .block(Block::bordered().title("Info"))
+///     .shadow(Style::new().on_dark_gray())
+///     .anchor(Anchor::Center);
+///
+/// frame.render_widget(popup, frame.area());
+/// # }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Popup<'a> {
+    content: Text<'a>,
+    anchor: Anchor,
+    size: Option<Size>,
+    block: Block<'a>,
+    style: Style,
+    shadow: Option<Style>,
+}
+
+impl Default for Popup<'_> {
+    fn default() -> Self {
+        Self {
+            content: Text::default(),
+            anchor: Anchor::default(),
+            size: None,
+            block: Block::bordered(),
+            style: Style::new(),
+            shadow: None,
+        }
+    }
+}
+
+impl<'a> Popup<'a> {
+    /// Creates a new popup with the given content.
+    ///
+    /// `content` accepts any type that is convertible to [`Text`].
+    pub fn new<T: Into<Text<'a>>>(content: T) -> Self {
+        Self {
+            content: content.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Sets the content of the popup
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn content<T: Into<Text<'a>>>(mut self, content: T) -> Self {
+        self.content = content.into();
+        self
+    }
+
+    /// Sets the position of the popup relative to the area it is rendered in
+    ///
+    /// Defaults to [`Anchor::Center`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn anchor(mut self, anchor: Anchor) -> Self {
+        self.anchor = anchor;
+        self
+    }
+
+    /// Sets the size of the popup, including its block
+    ///
+    /// By default, the popup is sized to fit its content. In both cases, it is shrunk to fit in the
+    /// area it is rendered in.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn size(mut self, size: Size) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the block surrounding the popup
+    ///
+    /// Defaults to a block with all borders.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = block;
+        self
+    }
+
+    /// Sets the base style of the popup
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the shadow drawn on the right and below the popup
+    ///
+    /// The shadow is one cell wide. Its style is applied to the cells under it, which keep their
+    /// content, e.g. a dark background dims the widgets behind the popup. There is no shadow by
+    /// default.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn shadow<S: Into<Style>>(mut self, style: S) -> Self {
+        self.shadow = Some(style.into());
+        self
+    }
+
+    /// Returns the area of the popup, including its block, when rendered in the given area.
+    pub fn area(&self, area: Rect) -> Rect {
+        self.popup_area(content_size(&self.content), area)
+    }
+
+    /// Returns the area of the popup displaying content of the given size in the bounds.
+    ///
+    /// Room is left for the shadow, so that it is not cut when the popup is moved inside the
+    /// bounds.
+    fn popup_area(&self, content: Size, bounds: Rect) -> Rect {
+        let size = self.size.unwrap_or_else(|| {
+            let (left, right) = self.block.horizontal_space();
+            let (top, bottom) = self.block.vertical_space();
+            Size::new(
+                content.width.saturating_add(left).saturating_add(right),
+                content.height.saturating_add(top).saturating_add(bottom),
+            )
+        });
+        let shadow = u16::from(self.shadow.is_some());
+        let size = Size::new(
+            size.width.saturating_add(shadow),
+            size.height.saturating_add(shadow),
+        );
+        let area = self.anchor.place(size, bounds);
+        Rect {
+            width: area.width.saturating_sub(shadow),
+            height: area.height.saturating_sub(shadow),
+            ..area
+        }
+    }
+
+    /// Renders the shadow, clears the area of the popup displaying content of the given size and
+    /// renders its block, then returns the inner area of the block.
+    pub(crate) fn render_frame(&self, content: Size, bounds: Rect, buf: &mut Buffer) -> Rect {
+        let bounds = bounds.intersection(*buf.area());
+        let area = self.popup_area(content, bounds);
+        if let Some(shadow) = self.shadow.filter(|_| !area.is_empty()) {
+            let right = Rect::new(area.right(), area.y + 1, 1, area.height);
+            let bottom = Rect::new(area.x + 1, area.bottom(), area.width, 1);
+            buf.set_style(right.intersection(bounds), shadow);
+            buf.set_style(bottom.intersection(bounds), shadow);
+        }
+        Clear.render(area, buf);
+        buf.set_style(area, self.style);
+        (&self.block).render(area, buf);
+        self.block.inner(area)
+    }
+}
+
+/// Returns the size of the given text.
+pub(crate) fn content_size(text: &Text<'_>) -> Size {
+    Size::new(
+        u16::try_from(text.width()).unwrap_or(u16::MAX),
+        u16::try_from(text.height()).unwrap_or(u16::MAX),
+    )
+}
+
+impl Styled for Popup<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+impl Widget for Popup<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl Widget for &Popup<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let inner = self.render_frame(content_size(&self.content), area, buf);
+        (&self.content).render(inner, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use ratatui_core::{layout::Position, style::Color};
+
+    use super::*;
+
+    #[test]
+    fn area() {
+        let area = Rect::new(0, 0, 20, 10);
+        let popup = Popup::new("hello");
+        assert_eq!(popup.area(area), Rect::new(6, 3, 7, 3));
+        let popup = popup.size(Size::new(10, 4));
+        assert_eq!(popup.area(area), Rect::new(5, 3, 10, 4));
+        let popup = popup.size(Size::new(30, 4)).block(Block::new());
+        assert_eq!(popup.area(area), Rect::new(0, 3, 20, 4));
+    }
+
+    #[test]
+    fn render() {
+        let mut buf = Buffer::with_lines(["xxxxxxxxxx"; 5]);
+        let popup = Popup::new("hi").anchor(Anchor::Cursor(Position::new(2, 0)));
+        Widget::render(popup, buf.area, &mut buf);
+        let expected = Buffer::with_lines([
+            "xxxxxxxxxx",
+            "xx┌──┐xxxx",
+            "xx│hi│xxxx",
+            "xx└──┘xxxx",
+            "xxxxxxxxxx",
+        ]);
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_shadow() {
+        let mut buf = Buffer::with_lines(["xxxxxxxx"; 4]);
+        let shadow = Style::new().bg(Color::DarkGray);
+        let popup = Popup::new("hi").shadow(shadow);
+        Widget::render(popup, buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(["x┌──┐xxx", "x│hi│xxx", "x└──┘xxx", "xxxxxxxx"]);
+        expected.set_style(Rect::new(5, 1, 1, 3), shadow);
+        expected.set_style(Rect::new(2, 3, 4, 1), shadow);
+        assert_eq!(buf, expected);
+    }
+}
@@ -0,0 +1,138 @@
+use ratatui_core::layout::{Position, Rect, Size};
+
+/// The position of a [`Popup`] or a [`Dialog`] in the area it is rendered in
+///
+/// The popup is placed next to its anchor, and flipped to the other side of the anchor when it
+/// would overflow the area. It is then moved inside the area if it still overflows it, e.g. when
+/// there is room on neither side of the anchor.
+///
+/// [`Popup`]: super::Popup
+/// [`Dialog`]: super::Dialog
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Anchor {
+    /// Centered in the area
+    #[default]
+    Center,
+    /// Below the given area, e.g. a cell of a table or an input field, aligned with its left edge
+    ///
+    /// The popup is displayed above the area if there isn't enough room below it, and aligned with
+    /// its right edge if there isn't enough room on its right.
+    Below(Rect),
+    /// Above the given area, aligned with its left edge
+    ///
+    /// The popup is displayed below the area if there isn't enough room above it, and aligned with
+    /// its right edge if there isn't enough room on its right.
+    Above(Rect),
+    /// Below the cursor at the given position, e.g. for a completion popup or a context menu
+    ///
+    /// The popup is displayed above the cursor if there isn't enough room below it, and on its left
+    /// if there isn't enough room on its right.
+    Cursor(Position),
+    /// On the right of the given area, aligned with its top edge, e.g. for a submenu next to the
+    /// item it belongs to
+    ///
+    /// The popup is displayed on the left of the area if there isn't enough room on its right.
+    Right(Rect),
+}
+
+impl Anchor {
+    /// Returns the area of a popup of the given size placed relative to the anchor in the bounds.
+    pub(crate) fn place(self, size: Size, bounds: Rect) -> Rect {
+        let width = size.width.min(bounds.width);
+        let height = size.height.min(bounds.height);
+        let (x, y) = match self {
+            Self::Center => (
+                bounds.x + (bounds.width - width) / 2,
+                bounds.y + (bounds.height - height) / 2,
+            ),
+            Self::Below(target) => Self::place_around(target, width, height, bounds, true),
+            Self::Above(target) => Self::place_around(target, width, height, bounds, false),
+            Self::Cursor(position) => {
+                let cell = Rect::new(position.x, position.y, 1, 1);
+                let (_, y) = Self::place_around(cell, width, height, bounds, true);
+                let x = if position.x.saturating_add(width) <= bounds.right() {
+                    position.x
+                } else {
+                    position.x.saturating_sub(width)
+                };
+                (x, y)
+            }
+            Self::Right(target) => {
+                let room_right = bounds.right().saturating_sub(target.right()) >= width;
+                let room_left = target.x.saturating_sub(bounds.x) >= width;
+                let x = if room_right || !room_left {
+                    target.right()
+                } else {
+                    target.x - width
+                };
+                (x, target.y)
+            }
+        };
+        Rect::new(x, y, width, height).clamp(bounds)
+    }
+
+    /// Returns the position of a popup placed below or above the target, flipped to the other side
+    /// if it doesn't fit.
+    const fn place_around(
+        target: Rect,
+        width: u16,
+        height: u16,
+        bounds: Rect,
+        below: bool,
+    ) -> (u16, u16) {
+        let room_below = bounds.bottom().saturating_sub(target.bottom()) >= height;
+        let room_above = target.y.saturating_sub(bounds.y) >= height;
+        let below = if below {
+            room_below || !room_above
+        } else {
+            !room_above && room_below
+        };
+        let y = if below {
+            target.bottom()
+        } else {
+            target.y.saturating_sub(height)
+        };
+        let x = if target.x.saturating_add(width) <= bounds.right() {
+            target.x
+        } else {
+            target.right().saturating_sub(width)
+        };
+        (x, y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::center(Anchor::Center, Rect::new(8, 4, 4, 2))]
+    #[case::below(Anchor::Below(Rect::new(2, 2, 6, 1)), Rect::new(2, 3, 4, 2))]
+    #[case::below_flipped(Anchor::Below(Rect::new(2, 8, 6, 1)), Rect::new(2, 6, 4, 2))]
+    #[case::above(Anchor::Above(Rect::new(2, 5, 6, 1)), Rect::new(2, 3, 4, 2))]
+    #[case::above_flipped(Anchor::Above(Rect::new(2, 1, 6, 1)), Rect::new(2, 2, 4, 2))]
+    #[case::right_flipped(Anchor::Below(Rect::new(17, 2, 2, 1)), Rect::new(15, 3, 4, 2))]
+    #[case::cursor(Anchor::Cursor(Position::new(5, 5)), Rect::new(5, 6, 4, 2))]
+    #[case::cursor_left(Anchor::Cursor(Position::new(18, 2)), Rect::new(14, 3, 4, 2))]
+    #[case::cursor_flipped(Anchor::Cursor(Position::new(18, 9)), Rect::new(14, 7, 4, 2))]
+    #[case::right(Anchor::Right(Rect::new(2, 2, 6, 3)), Rect::new(8, 2, 4, 2))]
+    #[case::right_on_left(Anchor::Right(Rect::new(10, 2, 8, 3)), Rect::new(6, 2, 4, 2))]
+    fn place(#[case] anchor: Anchor, #[case] expected: Rect) {
+        let bounds = Rect::new(0, 0, 20, 10);
+        assert_eq!(anchor.place(Size::new(4, 2), bounds), expected);
+    }
+
+    #[test]
+    fn place_clamped() {
+        let bounds = Rect::new(0, 0, 10, 4);
+        // there is room on neither side of the anchor
+        let anchor = Anchor::Below(Rect::new(0, 1, 4, 1));
+        assert_eq!(anchor.place(Size::new(4, 3), bounds), Rect::new(0, 1, 4, 3));
+        // the popup is larger than the bounds
+        let anchor = Anchor::Center;
+        assert_eq!(anchor.place(Size::new(20, 5), bounds), bounds);
+    }
+}
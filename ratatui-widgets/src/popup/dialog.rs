@@ -0,0 +1,372 @@
+use ratatui_core::{
+    buffer::Buffer,
+    layout::{Rect, Size},
+    style::{Modifier, Style, Styled},
+    text::{Line, Text},
+    widgets::{StatefulWidget, Widget},
+};
+
+use super::{content_size, Anchor, Popup};
+use crate::block::Block;
+
+/// A widget to display a modal dialog: a [`Popup`] with a row of buttons, e.g. "OK" and "Cancel"
+///
+/// The content of the dialog is displayed above the buttons, which are centered on the last row of
+/// the popup. The focused button is displayed with the focused button style.
+///
+/// [`Dialog`] is a [`StatefulWidget`], which means you can use it with [`DialogState`] to allow the
+/// user to move the focus between the buttons. Handling the keys which confirm the focused button
+/// is up to the application.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     widgets::{Block, Dialog, DialogState},
+///     Frame,
+/// };
+///
+/// # fn ui(frame: &mut Frame) {
+/// let dialog = Dialog::new("Discard the unsaved changes?")
+///     .block(Block::bordered().title("Quit"))
+///     .buttons(["OK", "Cancel"]);
+///
+/// // This should be stored outside of the function in your application state.
+/// let mut state = DialogState::default().with_focused(1);
+///
+/// frame.render_stateful_widget(dialog, frame.area(), &mut state);
+/// # }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Dialog<'a> {
+    popup: Popup<'a>,
+    buttons: Vec<Line<'a>>,
+    button_style: Style,
+    focused_button_style: Style,
+}
+
+const DEFAULT_FOCUSED_BUTTON_STYLE: Style = Style::new().add_modifier(Modifier::REVERSED);
+
+impl Default for Dialog<'_> {
+    fn default() -> Self {
+        Self {
+            popup: Popup::default(),
+            buttons: Vec::new(),
+            button_style: Style::new(),
+            focused_button_style: DEFAULT_FOCUSED_BUTTON_STYLE,
+        }
+    }
+}
+
+impl<'a> Dialog<'a> {
+    /// Creates a new dialog with the given content.
+    ///
+    /// `content` accepts any type that is convertible to [`Text`].
+    pub fn new<T: Into<Text<'a>>>(content: T) -> Self {
+        Self {
+            popup: Popup::new(content),
+            ..Self::default()
+        }
+    }
+
+    /// Sets the content of the dialog
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn content<T: Into<Text<'a>>>(mut self, content: T) -> Self {
+        self.popup = self.popup.content(content);
+        self
+    }
+
+    /// Sets the labels of the buttons
+    ///
+    /// `buttons` is an iterator of any type that is convertible to [`Line`], such as strings.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn buttons<I>(mut self, buttons: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Line<'a>>,
+    {
+        self.buttons = buttons.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the position of the dialog relative to the area it is rendered in
+    ///
+    /// Defaults to [`Anchor::Center`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn anchor(mut self, anchor: Anchor) -> Self {
+        self.popup = self.popup.anchor(anchor);
+        self
+    }
+
+    /// Sets the size of the dialog, including its block
+    ///
+    /// By default, the dialog is sized to fit its content and its buttons.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn size(mut self, size: Size) -> Self {
+        self.popup = self.popup.size(size);
+        self
+    }
+
+    /// Sets the block surrounding the dialog
+    ///
+    /// Defaults to a block with all borders.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.popup = self.popup.block(block);
+        self
+    }
+
+    /// Sets the base style of the dialog
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.popup = self.popup.style(style);
+        self
+    }
+
+    /// Sets the style of the shadow drawn on the right and below the dialog
+    ///
+    /// See [`Popup::shadow`] for more details.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn shadow<S: Into<Style>>(mut self, style: S) -> Self {
+        self.popup = self.popup.shadow(style);
+        self
+    }
+
+    /// Sets the style of the buttons
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn button_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.button_style = style.into();
+        self
+    }
+
+    /// Sets the style of the focused button
+    ///
+    /// Defaults to reversed colors.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn focused_button_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.focused_button_style = style.into();
+        self
+    }
+
+    /// Returns the area of the dialog, including its block, when rendered in the given area.
+    pub fn area(&self, area: Rect) -> Rect {
+        self.popup.popup_area(self.content_size(), area)
+    }
+
+    /// Returns the width of the row of buttons: each label is padded with a space on each side,
+    /// and the buttons are separated by two spaces.
+    fn buttons_width(&self) -> u16 {
+        let labels: usize = self.buttons.iter().map(|button| button.width() + 2).sum();
+        let gaps = self.buttons.len().saturating_sub(1) * 2;
+        u16::try_from(labels + gaps).unwrap_or(u16::MAX)
+    }
+
+    /// Returns the size of the content and the buttons, separated by an empty row.
+    fn content_size(&self) -> Size {
+        let content = content_size(&self.popup.content);
+        if self.buttons.is_empty() {
+            return content;
+        }
+        let separator = u16::from(content.height > 0);
+        Size::new(
+            content.width.max(self.buttons_width()),
+            content.height.saturating_add(separator).saturating_add(1),
+        )
+    }
+}
+
+impl Styled for Dialog<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.popup.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+/// State of the [`Dialog`] widget
+///
+/// This state is used to track the focused button of a [`Dialog`]. The focus is clamped to the
+/// last button when the dialog is rendered.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::widgets::DialogState;
+///
+/// let mut state = DialogState::default();
+/// state.focus_next();
+/// assert_eq!(state.focused(), 1);
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DialogState {
+    pub(crate) focused: usize,
+}
+
+impl DialogState {
+    /// Sets the index of the focused button
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_focused(mut self, focused: usize) -> Self {
+        self.focused = focused;
+        self
+    }
+
+    /// Index of the focused button
+    pub const fn focused(&self) -> usize {
+        self.focused
+    }
+
+    /// Focuses the button at the given index
+    pub fn focus(&mut self, index: usize) {
+        self.focused = index;
+    }
+
+    /// Focuses the next button
+    ///
+    /// The focus is clamped to the last button when the dialog is rendered.
+    pub fn focus_next(&mut self) {
+        self.focused = self.focused.saturating_add(1);
+    }
+
+    /// Focuses the previous button
+    pub fn focus_previous(&mut self) {
+        self.focused = self.focused.saturating_sub(1);
+    }
+}
+
+impl Widget for Dialog<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl Widget for &Dialog<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut state = DialogState::default();
+        StatefulWidget::render(self, area, buf, &mut state);
+    }
+}
+
+impl StatefulWidget for Dialog<'_> {
+    type State = DialogState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidget::render(&self, area, buf, state);
+    }
+}
+
+impl StatefulWidget for &Dialog<'_> {
+    type State = DialogState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let inner = self.popup.render_frame(self.content_size(), area, buf);
+        if self.buttons.is_empty() {
+            (&self.popup.content).render(inner, buf);
+            return;
+        }
+        state.focused = state.focused.min(self.buttons.len() - 1);
+        let content = Rect {
+            height: inner.height.saturating_sub(2),
+            ..inner
+        };
+        (&self.popup.content).render(content, buf);
+        if inner.is_empty() {
+            return;
+        }
+
+        let y = inner.bottom() - 1;
+        let mut x = inner.x + inner.width.saturating_sub(self.buttons_width()) / 2;
+        for (index, button) in self.buttons.iter().enumerate() {
+            if x >= inner.right() {
+                break;
+            }
+            let style = if index == state.focused {
+                self.focused_button_style
+            } else {
+                self.button_style
+            };
+            let width = u16::try_from(button.width() + 2).unwrap_or(u16::MAX);
+            let button_area = Rect::new(x, y, width, 1).intersection(inner);
+            buf.set_style(button_area, style);
+            let label_x = (x + 1).min(inner.right());
+            buf.set_line(label_x, y, button, inner.right() - label_x);
+            x = x.saturating_add(width).saturating_add(2);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn focus() {
+        let mut state = DialogState::default().with_focused(1);
+        state.focus_previous();
+        state.focus_previous();
+        assert_eq!(state.focused(), 0);
+        state.focus(3);
+        assert_eq!(state.focused(), 3);
+    }
+
+    #[test]
+    fn render() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 18, 6));
+        let dialog = Dialog::new("Quit now?").buttons(["OK", "Cancel"]);
+        let mut state = DialogState::default().with_focused(5);
+        StatefulWidget::render(&dialog, buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines([
+            " ┌──────────────┐ ",
+            " │Quit now?     │ ",
+            " │              │ ",
+            " │ OK    Cancel │ ",
+            " └──────────────┘ ",
+            "                  ",
+        ]);
+        expected.set_style(Rect::new(8, 3, 8, 1), DEFAULT_FOCUSED_BUTTON_STYLE);
+        assert_eq!(buf, expected);
+        // the focus is clamped to the last button
+        assert_eq!(state.focused(), 1);
+    }
+}
@@ -16,12 +16,61 @@ pub trait LineComposer<'a> {
 pub struct WrappedLine<'lend, 'text> {
     /// One line reflowed to the correct width
     pub line: &'lend [StyledGrapheme<'text>],
+    /// The index of each grapheme of `line` in the input line it was taken from, or `None` if it
+    /// was added by the composer, like the hyphen at a soft hyphen
+    pub indices: &'lend [Option<usize>],
     /// The width of the line
     pub width: u16,
     /// Whether the line was aligned left or right
     pub alignment: Alignment,
 }
 
+/// A grapheme of an input line, along with its index in that line.
+#[derive(Debug, Clone)]
+struct IndexedGrapheme<'a> {
+    grapheme: StyledGrapheme<'a>,
+    index: Option<usize>,
+}
+
+impl<'a> IndexedGrapheme<'a> {
+    const fn new(index: usize, grapheme: StyledGrapheme<'a>) -> Self {
+        Self {
+            grapheme,
+            index: Some(index),
+        }
+    }
+
+    /// Creates a grapheme that is added by a composer rather than taken from the input line.
+    const fn added(grapheme: StyledGrapheme<'a>) -> Self {
+        Self {
+            grapheme,
+            index: None,
+        }
+    }
+
+    fn is_whitespace(&self) -> bool {
+        self.grapheme.is_whitespace()
+    }
+
+    fn width(&self) -> u16 {
+        self.grapheme.symbol.width() as u16
+    }
+}
+
+/// Moves the graphemes of `line` into `graphemes` and their indices into `indices`.
+fn split_line<'a>(
+    line: &mut Vec<IndexedGrapheme<'a>>,
+    graphemes: &mut Vec<StyledGrapheme<'a>>,
+    indices: &mut Vec<Option<usize>>,
+) {
+    graphemes.clear();
+    indices.clear();
+    for IndexedGrapheme { grapheme, index } in line.drain(..) {
+        graphemes.push(grapheme);
+        indices.push(index);
+    }
+}
+
 /// A state machine that wraps lines on word boundaries.
 #[derive(Debug, Default, Clone)]
 pub struct WordWrapper<'a, O, I>
@@ -35,16 +84,17 @@ where
     /// The given, unprocessed lines
     input_lines: O,
     max_line_width: u16,
-    wrapped_lines: VecDeque<Vec<StyledGrapheme<'a>>>,
+    wrapped_lines: VecDeque<Vec<IndexedGrapheme<'a>>>,
     current_alignment: Alignment,
     current_line: Vec<StyledGrapheme<'a>>,
+    current_indices: Vec<Option<usize>>,
     /// Removes the leading whitespace from lines
     trim: bool,
 
     // These are cached allocations that hold no state across next_line invocations
-    pending_word: Vec<StyledGrapheme<'a>>,
-    pending_whitespace: VecDeque<StyledGrapheme<'a>>,
-    pending_line_pool: Vec<Vec<StyledGrapheme<'a>>>,
+    pending_word: Vec<IndexedGrapheme<'a>>,
+    pending_whitespace: VecDeque<IndexedGrapheme<'a>>,
+    pending_line_pool: Vec<Vec<IndexedGrapheme<'a>>>,
 }
 
 impl<'a, O, I> WordWrapper<'a, O, I>
@@ -60,6 +110,7 @@ where
             wrapped_lines: VecDeque::new(),
             current_alignment: Alignment::Left,
             current_line: vec![],
+            current_indices: vec![],
             trim,
 
             pending_word: Vec::new(),
@@ -81,9 +132,10 @@ where
         self.pending_whitespace.clear();
         pending_line.clear();
 
-        for grapheme in line_symbols {
+        for (index, grapheme) in line_symbols.into_iter().enumerate() {
+            let grapheme = IndexedGrapheme::new(index, grapheme);
             let is_whitespace = grapheme.is_whitespace();
-            let symbol_width = grapheme.symbol.width() as u16;
+            let symbol_width = grapheme.width();
 
             // ignore symbols wider than line limit
             if symbol_width > self.max_line_width {
@@ -134,7 +186,7 @@ where
 
                 // remove whitespace up to the end of line
                 while let Some(grapheme) = self.pending_whitespace.front() {
-                    let width = grapheme.symbol.width() as u16;
+                    let width = grapheme.width();
 
                     if width > remaining_width {
                         break;
@@ -186,10 +238,10 @@ where
         }
     }
 
-    fn replace_current_line(&mut self, line: Vec<StyledGrapheme<'a>>) {
-        let cache = mem::replace(&mut self.current_line, line);
-        if cache.capacity() > 0 {
-            self.pending_line_pool.push(cache);
+    fn replace_current_line(&mut self, mut line: Vec<IndexedGrapheme<'a>>) {
+        split_line(&mut line, &mut self.current_line, &mut self.current_indices);
+        if line.capacity() > 0 {
+            self.pending_line_pool.push(line);
         }
     }
 }
@@ -208,14 +260,311 @@ where
         loop {
             // emit next cached line if present
             if let Some(line) = self.wrapped_lines.pop_front() {
-                let line_width = line
-                    .iter()
-                    .map(|grapheme| grapheme.symbol.width() as u16)
-                    .sum();
+                let line_width = line.iter().map(IndexedGrapheme::width).sum();
 
                 self.replace_current_line(line);
                 return Some(WrappedLine {
                     line: &self.current_line,
+                    indices: &self.current_indices,
+                    width: line_width,
+                    alignment: self.current_alignment,
+                });
+            }
+
+            // otherwise, process pending wrapped lines from input
+            let (line_symbols, line_alignment) = self.input_lines.next()?;
+            self.current_alignment = line_alignment;
+            self.process_input(line_symbols);
+        }
+    }
+}
+
+/// The soft hyphen marks a position where a word may be hyphenated. It is invisible unless the
+/// line is broken there.
+const SOFT_HYPHEN: &str = "\u{00ad}";
+
+/// The symbol that is rendered at the end of a line that was broken at a soft hyphen.
+const HYPHEN: &str = "-";
+
+/// Describes where a wrapped [`Paragraph`] may break a line.
+///
+/// See [`Paragraph::wrap_mode`].
+///
+/// [`Paragraph`]: crate::paragraph::Paragraph
+/// [`Paragraph::wrap_mode`]: crate::paragraph::Paragraph::wrap_mode
+///
+/// ## Examples
+///
+/// ```
+/// use ratatui::widgets::{Paragraph, Wrap, WrapMode};
+///
+/// let paragraph = Paragraph::new("日本語のテキスト")
+///     .wrap(Wrap { trim: true })
+///     .wrap_mode(WrapMode::UnicodeLineBreak);
+/// ```
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum WrapMode {
+    /// Break lines on whitespace, and within words that are longer than the line
+    #[default]
+    Word,
+    /// Break lines between any two characters
+    Character,
+    /// Break lines according to the [Unicode line breaking algorithm][UAX #14]
+    ///
+    /// This allows breaking between CJK characters, and after punctuation such as the slashes and
+    /// hyphens in URLs.
+    ///
+    /// [UAX #14]: https://www.unicode.org/reports/tr14/
+    UnicodeLineBreak,
+}
+
+/// The options of a [`BreakWrapper`].
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct WrapOptions {
+    /// Whether leading whitespace is trimmed
+    pub trim: bool,
+    /// Where lines may be broken
+    pub mode: WrapMode,
+    /// Whether lines may be broken at soft hyphens
+    pub hyphenate: bool,
+    /// Whether continuation lines repeat the indentation of the line they were wrapped from
+    pub preserve_indent: bool,
+}
+
+impl WrapOptions {
+    /// Whether these options are fully handled by the whitespace-only [`WordWrapper`].
+    pub const fn is_word_wrap(self) -> bool {
+        matches!(self.mode, WrapMode::Word) && !self.hyphenate && !self.preserve_indent
+    }
+}
+
+/// A state machine that wraps lines at the break opportunities of a [`WrapMode`].
+///
+/// In contrast to [`WordWrapper`], which only breaks lines on whitespace, this supports breaking
+/// between any two graphemes, breaking according to the Unicode line breaking algorithm ([UAX
+/// #14]), soft hyphenation, and repeating the indentation of a line on its continuation lines.
+///
+/// [UAX #14]: https://www.unicode.org/reports/tr14/
+#[derive(Debug, Default, Clone)]
+pub struct BreakWrapper<'a, O, I>
+where
+    // Outer iterator providing the individual lines
+    O: Iterator<Item = (I, Alignment)>,
+    // Inner iterator providing the styled symbols of a line Each line consists of an alignment and
+    // a series of symbols
+    I: Iterator<Item = StyledGrapheme<'a>>,
+{
+    /// The given, unprocessed lines
+    input_lines: O,
+    max_line_width: u16,
+    wrap: WrapOptions,
+    wrapped_lines: VecDeque<Vec<IndexedGrapheme<'a>>>,
+    current_alignment: Alignment,
+    current_line: Vec<StyledGrapheme<'a>>,
+    current_indices: Vec<Option<usize>>,
+}
+
+impl<'a, O, I> BreakWrapper<'a, O, I>
+where
+    O: Iterator<Item = (I, Alignment)>,
+    I: Iterator<Item = StyledGrapheme<'a>>,
+{
+    /// Create a new `BreakWrapper` with the given lines, maximum line width and wrap options.
+    pub const fn new(lines: O, max_line_width: u16, wrap: WrapOptions) -> Self {
+        Self {
+            input_lines: lines,
+            max_line_width,
+            wrap,
+            wrapped_lines: VecDeque::new(),
+            current_alignment: Alignment::Left,
+            current_line: vec![],
+            current_indices: vec![],
+        }
+    }
+
+    /// Split an input line (`line_symbols`) into wrapped lines and cache them to be emitted later
+    fn process_input(&mut self, line_symbols: impl IntoIterator<Item = StyledGrapheme<'a>>) {
+        let max_line_width = self.max_line_width;
+        // ignore symbols wider than line limit
+        let graphemes: Vec<IndexedGrapheme<'a>> = line_symbols
+            .into_iter()
+            .enumerate()
+            .map(|(index, grapheme)| IndexedGrapheme::new(index, grapheme))
+            .filter(|grapheme| grapheme.width() <= max_line_width)
+            .collect();
+        if graphemes.is_empty() {
+            self.wrapped_lines.push_back(vec![]);
+            return;
+        }
+        let widths: Vec<u16> = graphemes.iter().map(IndexedGrapheme::width).collect();
+        let breaks = self.break_opportunities(&graphemes);
+
+        let indent_len = if self.wrap.preserve_indent {
+            graphemes.iter().take_while(|g| g.is_whitespace()).count()
+        } else {
+            0
+        };
+        let indent_width: u16 = widths[..indent_len].iter().sum();
+        // an indent that leaves no room for any content is not repeated
+        let indent_len = if indent_width < max_line_width {
+            indent_len
+        } else {
+            0
+        };
+
+        let mut start = 0;
+        let mut first = true;
+        while start < graphemes.len() {
+            let mut line = Vec::new();
+            let mut limit = max_line_width;
+            if !first {
+                let indent = graphemes[..indent_len].iter();
+                line.extend(indent.map(|indent| IndexedGrapheme::added(indent.grapheme.clone())));
+                limit -= widths[..indent_len].iter().sum::<u16>();
+            }
+            if self.wrap.trim && !(first && self.wrap.preserve_indent) {
+                while graphemes
+                    .get(start)
+                    .is_some_and(IndexedGrapheme::is_whitespace)
+                {
+                    start += 1;
+                }
+                if start == graphemes.len() {
+                    if first {
+                        self.wrapped_lines.push_back(line);
+                    }
+                    break;
+                }
+            }
+
+            let end = self.line_end(&graphemes, &widths, &breaks, start, limit);
+            let end = if end == start {
+                // the next symbol only fits without the indentation
+                line.clear();
+                start + 1
+            } else {
+                end
+            };
+
+            line.extend_from_slice(&graphemes[start..end]);
+            if end < graphemes.len() {
+                // whitespace at a line break hangs past the end of the line
+                while line.last().is_some_and(IndexedGrapheme::is_whitespace) {
+                    line.pop();
+                }
+                if self.wrap.hyphenate && graphemes[end - 1].grapheme.symbol == SOFT_HYPHEN {
+                    let style = graphemes[end - 1].grapheme.style;
+                    line.push(IndexedGrapheme::added(StyledGrapheme::new(HYPHEN, style)));
+                }
+            }
+            self.wrapped_lines.push_back(line);
+            start = end;
+            first = false;
+        }
+    }
+
+    /// Finds the index of the grapheme that starts the next line, given the line starts at `start`
+    /// and may be at most `limit` wide.
+    ///
+    /// Returns `start` if not even the first grapheme fits.
+    fn line_end(
+        &self,
+        graphemes: &[IndexedGrapheme<'a>],
+        widths: &[u16],
+        breaks: &[bool],
+        start: usize,
+        limit: u16,
+    ) -> usize {
+        let mut width = 0;
+        let mut end = start;
+        let mut last_break = None;
+        while end < graphemes.len() {
+            if width + widths[end] > limit {
+                break;
+            }
+            width += widths[end];
+            end += 1;
+            let hyphen_width =
+                u16::from(self.wrap.hyphenate && graphemes[end - 1].grapheme.symbol == SOFT_HYPHEN);
+            if end < graphemes.len() && breaks[end] && width + hyphen_width <= limit {
+                last_break = Some(end);
+            }
+        }
+        if end == graphemes.len() || end == start {
+            return end;
+        }
+
+        // whitespace may overflow the line if it is followed by a break opportunity
+        let mut after_whitespace = end;
+        while graphemes
+            .get(after_whitespace)
+            .is_some_and(IndexedGrapheme::is_whitespace)
+        {
+            after_whitespace += 1;
+        }
+        if after_whitespace > end
+            && (after_whitespace == graphemes.len() || breaks[after_whitespace])
+        {
+            return after_whitespace;
+        }
+
+        // otherwise break at the last opportunity, or mid-word if there was none
+        last_break.unwrap_or(end)
+    }
+
+    /// Returns for each grapheme whether a line may be broken before it.
+    fn break_opportunities(&self, graphemes: &[IndexedGrapheme<'a>]) -> Vec<bool> {
+        let mut breaks = vec![false; graphemes.len()];
+        match self.wrap.mode {
+            WrapMode::Word => {
+                for (i, pair) in graphemes.windows(2).enumerate() {
+                    breaks[i + 1] = pair[0].is_whitespace() && !pair[1].is_whitespace();
+                }
+            }
+            WrapMode::Character => breaks.fill(true),
+            WrapMode::UnicodeLineBreak => {
+                let mut offsets = Vec::with_capacity(graphemes.len());
+                let mut text = String::new();
+                for grapheme in graphemes {
+                    offsets.push(text.len());
+                    text.push_str(grapheme.grapheme.symbol);
+                }
+                for (offset, _) in unicode_linebreak::linebreaks(&text) {
+                    if let Ok(index) = offsets.binary_search(&offset) {
+                        breaks[index] = true;
+                    }
+                }
+            }
+        }
+        for (i, pair) in graphemes.windows(2).enumerate() {
+            if pair[0].grapheme.symbol == SOFT_HYPHEN {
+                breaks[i + 1] = self.wrap.hyphenate;
+            }
+        }
+        breaks[0] = false;
+        breaks
+    }
+}
+
+impl<'a, O, I> LineComposer<'a> for BreakWrapper<'a, O, I>
+where
+    O: Iterator<Item = (I, Alignment)>,
+    I: Iterator<Item = StyledGrapheme<'a>>,
+{
+    fn next_line<'lend>(&'lend mut self) -> Option<WrappedLine<'lend, 'a>> {
+        if self.max_line_width == 0 {
+            return None;
+        }
+
+        loop {
+            // emit next cached line if present
+            if let Some(mut line) = self.wrapped_lines.pop_front() {
+                let line_width = line.iter().map(IndexedGrapheme::width).sum();
+
+                split_line(&mut line, &mut self.current_line, &mut self.current_indices);
+                return Some(WrappedLine {
+                    line: &self.current_line,
+                    indices: &self.current_indices,
                     width: line_width,
                     alignment: self.current_alignment,
                 });
@@ -243,6 +592,7 @@ where
     input_lines: O,
     max_line_width: u16,
     current_line: Vec<StyledGrapheme<'a>>,
+    current_indices: Vec<Option<usize>>,
     /// Record the offset to skip render
     horizontal_offset: u16,
 }
@@ -259,6 +609,7 @@ where
             max_line_width,
             horizontal_offset: 0,
             current_line: vec![],
+            current_indices: vec![],
         }
     }
 
@@ -279,6 +630,7 @@ where
         }
 
         self.current_line.truncate(0);
+        self.current_indices.truncate(0);
         let mut current_line_width = 0;
 
         let mut lines_exhausted = true;
@@ -288,7 +640,7 @@ where
             lines_exhausted = false;
             current_alignment = *alignment;
 
-            for StyledGrapheme { symbol, style } in current_line {
+            for (index, StyledGrapheme { symbol, style }) in current_line.enumerate() {
                 // Ignore characters wider that the total max width.
                 if symbol.width() as u16 > self.max_line_width {
                     continue;
@@ -314,6 +666,9 @@ where
                 };
                 current_line_width += symbol.width() as u16;
                 self.current_line.push(StyledGrapheme { symbol, style });
+                // graphemes that are scrolled out of view don't belong to the line
+                self.current_indices
+                    .push((!symbol.is_empty()).then_some(index));
             }
         }
 
@@ -322,6 +677,7 @@ where
         } else {
             Some(WrappedLine {
                 line: &self.current_line,
+                indices: &self.current_indices,
                 width: current_line_width,
                 alignment: current_alignment,
             })
@@ -358,6 +714,7 @@ mod tests {
     #[derive(Clone, Copy)]
     enum Composer {
         WordWrapper { trim: bool },
+        BreakWrapper(WrapOptions),
         LineTruncator,
     }
 
@@ -379,6 +736,9 @@ mod tests {
             Composer::WordWrapper { trim } => {
                 Box::new(WordWrapper::new(styled_lines, text_area_width, trim))
             }
+            Composer::BreakWrapper(wrap) => {
+                Box::new(BreakWrapper::new(styled_lines, text_area_width, wrap))
+            }
             Composer::LineTruncator => Box::new(LineTruncator::new(styled_lines, text_area_width)),
         };
         let mut lines = vec![];
@@ -388,6 +748,7 @@ mod tests {
             line: styled,
             width,
             alignment,
+            ..
         }) = composer.next_line()
         {
             let line = styled
@@ -723,4 +1084,147 @@ mod tests {
         let (word_wrapper, _, _) = run_composer(Composer::WordWrapper { trim: true }, line, width);
         assert_eq!(word_wrapper, ["foo", "bar"]);
     }
+
+    const fn break_wrapper(mode: WrapMode) -> Composer {
+        Composer::BreakWrapper(WrapOptions {
+            trim: true,
+            mode,
+            hyphenate: false,
+            preserve_indent: false,
+        })
+    }
+
+    #[test]
+    fn line_composer_break_wrapper_word_mode() {
+        let width = 20;
+        let text =
+            "abcd efghij klmnopabcd efgh ijklmnopabcdefg hijkl mnopab c d e f g h i j k l m n o";
+        let (word_wrapper, widths, _) = run_composer(break_wrapper(WrapMode::Word), text, width);
+        assert_eq!(
+            word_wrapper,
+            [
+                "abcd efghij",
+                "klmnopabcd efgh",
+                "ijklmnopabcdefg",
+                "hijkl mnopab c d e f",
+                "g h i j k l m n o",
+            ]
+        );
+        assert_eq!(widths, [11, 15, 15, 20, 17]);
+    }
+
+    #[test]
+    fn line_composer_break_wrapper_long_word() {
+        let width = 10;
+        let text = "abcdefghijklmnopqrstuvwxyz";
+        let (wrapped, _, _) = run_composer(break_wrapper(WrapMode::Word), text, width);
+        assert_eq!(wrapped, ["abcdefghij", "klmnopqrst", "uvwxyz"]);
+    }
+
+    #[test]
+    fn line_composer_break_wrapper_character_mode() {
+        let width = 10;
+        let text = "abcd efghij klmnop";
+        let (wrapped, _, _) = run_composer(break_wrapper(WrapMode::Character), text, width);
+        assert_eq!(wrapped, ["abcd efghi", "j klmnop"]);
+
+        let untrimmed = Composer::BreakWrapper(WrapOptions {
+            trim: false,
+            mode: WrapMode::Character,
+            ..WrapOptions::default()
+        });
+        let (wrapped, _, _) = run_composer(untrimmed, "  abcdefghijkl", width);
+        assert_eq!(wrapped, ["  abcdefgh", "ijkl"]);
+    }
+
+    #[test]
+    fn line_composer_break_wrapper_unicode_line_break_cjk() {
+        let width = 10;
+        let text = "コンピュータ上で文字を扱う場合";
+        let (wrapped, widths, _) =
+            run_composer(break_wrapper(WrapMode::UnicodeLineBreak), text, width);
+        assert_eq!(wrapped, ["コンピュー", "タ上で文字", "を扱う場合"]);
+        assert_eq!(widths, [10, 10, 10]);
+    }
+
+    #[test]
+    fn line_composer_break_wrapper_unicode_line_break_url() {
+        let width = 20;
+        let text = "see https://example.com/some/long/path";
+        let (word_mode, _, _) = run_composer(break_wrapper(WrapMode::Word), text, width);
+        let (unicode_mode, _, _) =
+            run_composer(break_wrapper(WrapMode::UnicodeLineBreak), text, width);
+        assert_eq!(word_mode, ["see", "https://example.com/", "some/long/path"]);
+        assert_eq!(
+            unicode_mode,
+            ["see https://", "example.com/some/", "long/path"]
+        );
+    }
+
+    #[test]
+    fn line_composer_break_wrapper_soft_hyphen() {
+        let width = 10;
+        let text = "a hyphen\u{ad}ation example";
+        let (ignored, _, _) = run_composer(break_wrapper(WrapMode::Word), text, width);
+        assert_eq!(ignored, ["a", "hyphen\u{ad}atio", "n example"]);
+
+        let hyphenate = Composer::BreakWrapper(WrapOptions {
+            trim: true,
+            hyphenate: true,
+            ..WrapOptions::default()
+        });
+        let (hyphenated, widths, _) = run_composer(hyphenate, text, width);
+        assert_eq!(hyphenated, ["a hyphen\u{ad}-", "ation", "example"]);
+        assert_eq!(widths, [9, 5, 7]);
+    }
+
+    #[test]
+    fn line_composer_break_wrapper_preserve_indent() {
+        let width = 14;
+        let text = "  - one two three four five\nnext";
+        let preserve_indent = Composer::BreakWrapper(WrapOptions {
+            trim: true,
+            preserve_indent: true,
+            ..WrapOptions::default()
+        });
+        let (wrapped, _, _) = run_composer(preserve_indent, text, width);
+        assert_eq!(wrapped, ["  - one two", "  three four", "  five", "next"]);
+
+        let (trimmed, _, _) = run_composer(break_wrapper(WrapMode::Word), text, width);
+        assert_eq!(trimmed, ["- one two", "three four", "five", "next"]);
+    }
+
+    #[test]
+    fn line_composer_indices() {
+        let line = Line::from("  ab\u{ad}cd ef");
+        let styled = std::iter::once((line.styled_graphemes(Style::default()), Alignment::Left));
+        let wrap = WrapOptions {
+            trim: true,
+            hyphenate: true,
+            preserve_indent: true,
+            ..WrapOptions::default()
+        };
+        let mut composer = BreakWrapper::new(styled, 5, wrap);
+        let mut indices = vec![];
+        while let Some(line) = composer.next_line() {
+            indices.push(line.indices.to_vec());
+        }
+        // the repeated indentation and the hyphen are not taken from the input line
+        assert_eq!(
+            indices,
+            [
+                vec![Some(0), Some(1), Some(2), Some(3), Some(4), None],
+                vec![None, None, Some(5), Some(6)],
+                vec![None, None, Some(8), Some(9)],
+            ]
+        );
+    }
+
+    #[test]
+    fn line_composer_break_wrapper_empty_lines() {
+        let width = 5;
+        let text = "abc\n\n   \ndef";
+        let (wrapped, _, _) = run_composer(break_wrapper(WrapMode::Character), text, width);
+        assert_eq!(wrapped, ["abc", "", "", "def"]);
+    }
 }
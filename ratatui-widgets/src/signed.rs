@@ -0,0 +1,151 @@
+//! Internal module for the bars of signed values, drawn on both sides of a zero axis.
+use ratatui_core::{
+    buffer::Cell,
+    symbols::{bar, half_block},
+};
+
+/// A value stored as its magnitude and its sign, so that any `u64` and any `i64` fit in it.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub(crate) struct SignedValue {
+    /// The magnitude of the value
+    pub(crate) magnitude: u64,
+    /// Whether the value is below zero
+    pub(crate) negative: bool,
+}
+
+impl SignedValue {
+    /// Creates a value that is never negative.
+    pub(crate) const fn positive(magnitude: u64) -> Self {
+        Self {
+            magnitude,
+            negative: false,
+        }
+    }
+
+    /// Creates a value from a signed integer.
+    pub(crate) const fn new(value: i64) -> Self {
+        Self {
+            magnitude: value.unsigned_abs(),
+            negative: value < 0,
+        }
+    }
+
+    /// Returns the value with its sign.
+    pub(crate) fn get(self) -> i128 {
+        if self.negative {
+            -i128::from(self.magnitude)
+        } else {
+            i128::from(self.magnitude)
+        }
+    }
+}
+
+/// The layout of bars along their value axis, split around the zero axis.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Scale {
+    /// The number of cells above (or right of) the zero axis
+    pub(crate) positive_length: u16,
+    /// The number of cells below (or left of) the zero axis
+    pub(crate) negative_length: u16,
+    /// The magnitude drawn at the end of the positive side
+    positive_max: u128,
+    /// The magnitude drawn at the end of the negative side
+    negative_max: u128,
+    /// The number of levels a cell is divided into
+    pub(crate) levels: u16,
+}
+
+impl Scale {
+    /// Splits the given length around the zero axis in proportion to the largest positive and
+    /// negative magnitudes, each side keeping at least one cell if it has a bar.
+    pub(crate) fn new(length: u16, positive_max: u128, negative_max: u128, levels: u16) -> Self {
+        let positive_length = if negative_max == 0 {
+            length
+        } else if positive_max == 0 {
+            0
+        } else {
+            let total = positive_max + negative_max;
+            let cells = (u128::from(length) * positive_max + total / 2) / total;
+            (cells as u16).clamp(1, length.saturating_sub(1).max(1))
+        };
+        Self {
+            positive_length,
+            negative_length: length - positive_length,
+            positive_max,
+            negative_max,
+            levels,
+        }
+    }
+
+    /// Returns the number of levels a magnitude covers from the axis, on its positive or negative
+    /// side.
+    pub(crate) fn levels(&self, magnitude: u128, negative: bool) -> u64 {
+        let (length, max) = if negative {
+            (self.negative_length, self.negative_max)
+        } else {
+            (self.positive_length, self.positive_max)
+        };
+        if max == 0 {
+            return 0;
+        }
+        let levels = u128::from(length) * u128::from(self.levels);
+        (magnitude * levels / max).min(levels) as u64
+    }
+
+    /// Returns the number of levels the value covers from the axis, negative for values below it.
+    pub(crate) fn height(&self, value: i128) -> i128 {
+        let height = i128::from(self.levels(value.unsigned_abs(), value < 0));
+        if value < 0 {
+            -height
+        } else {
+            height
+        }
+    }
+}
+
+/// Sets the symbol of a cell covered from its top by the given number of eighths of a bar hanging
+/// from the zero axis.
+///
+/// There are no symbols for the eighths of a cell filled from the top, so the bar is rounded down
+/// to a half cell.
+pub(crate) fn set_depth_symbol<'a>(
+    cell: &'a mut Cell,
+    set: &bar::Set,
+    eighths: u64,
+) -> &'a mut Cell {
+    match eighths {
+        0..=3 => cell.set_symbol(set.empty),
+        4..=7 => cell.set_char(half_block::UPPER),
+        _ => cell.set_symbol(set.full),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn signed_value() {
+        assert_eq!(SignedValue::new(-3).get(), -3);
+        assert_eq!(SignedValue::new(i64::MIN).magnitude, 1 << 63);
+        assert_eq!(SignedValue::positive(u64::MAX).get(), i128::from(u64::MAX));
+    }
+
+    #[test]
+    fn scale() {
+        let scale = Scale::new(10, 30, 10, 8);
+        assert_eq!((scale.positive_length, scale.negative_length), (8, 2));
+        assert_eq!(scale.height(15), 32);
+        assert_eq!(scale.height(-10), -16);
+        assert_eq!(scale.height(60), 64);
+
+        // each side with a bar keeps a cell
+        let scale = Scale::new(4, 1000, 1, 8);
+        assert_eq!((scale.positive_length, scale.negative_length), (3, 1));
+        let scale = Scale::new(4, 0, 5, 8);
+        assert_eq!((scale.positive_length, scale.negative_length), (0, 4));
+        assert_eq!(scale.height(3), 0);
+    }
+}
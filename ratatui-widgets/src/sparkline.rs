@@ -10,7 +10,10 @@ use ratatui_core::{
 };
 use strum::{Display, EnumString};
 
-use crate::block::{Block, BlockExt};
+use crate::{
+    block::{Block, BlockExt},
+    signed::{set_depth_symbol, Scale, SignedValue},
+};
 
 /// Widget to render a sparkline over one or more lines.
 ///
@@ -38,12 +41,21 @@ use crate::block::{Block, BlockExt};
 /// Absent values and will be rendered with the style set by [`Sparkline::absent_value_style`] and
 /// the symbol set by [`Sparkline::absent_value_symbol`].
 ///
+/// Negative values can be created with [`SparklineBar::signed`]. When the dataset contains
+/// negative values, the area is split around a zero axis: positive bars grow upwards from the axis
+/// and negative bars grow downwards from it. With [`SparklineMode::Bars`], negative bars are drawn
+/// at a resolution of half a row, as there are no symbols for the eighths of a cell filled from
+/// the top.
+///
 /// # Setter methods
 ///
 /// - [`Sparkline::block`] wraps the sparkline in a [`Block`]
 /// - [`Sparkline::data`] defines the dataset, you'll almost always want to use it
 /// - [`Sparkline::max`] sets the maximum value of bars
 /// - [`Sparkline::direction`] sets the render direction
+/// - [`Sparkline::mode`] draws the bars with block symbols or braille patterns
+/// - [`Sparkline::baseline`] draws a horizontal threshold line
+/// - [`Sparkline::min_marker_style`] and [`Sparkline::max_marker_style`] highlight the extremes
 ///
 /// # Examples
 ///
@@ -82,6 +94,16 @@ pub struct Sparkline<'a> {
     bar_set: symbols::bar::Set,
     /// The direction to render the sparkline, either from left to right, or from right to left
     direction: RenderDirection,
+    /// How the bars are drawn
+    mode: SparklineMode,
+    /// The value at which to draw a horizontal threshold line
+    baseline: Option<i64>,
+    /// Style of the threshold line
+    baseline_style: Style,
+    /// Style patched onto the tip of the smallest value
+    min_marker_style: Option<Style>,
+    /// Style patched onto the tip of the largest value
+    max_marker_style: Option<Style>,
 }
 
 /// Defines the direction in which sparkline will be rendered.
@@ -96,6 +118,19 @@ pub enum RenderDirection {
     RightToLeft,
 }
 
+/// Defines how the bars of a sparkline are drawn.
+///
+/// See [`Sparkline::mode`].
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum SparklineMode {
+    /// One value per column, drawn with the symbols of the [`Sparkline::bar_set`] at eight levels
+    /// per row
+    #[default]
+    Bars,
+    /// Two values per column, drawn with braille patterns at four levels per row
+    Braille,
+}
+
 impl<'a> Sparkline<'a> {
     /// Wraps the sparkline with the given `block`.
     #[must_use = "method moves the value of self and returns the modified value"]
@@ -241,6 +276,95 @@ impl<'a> Sparkline<'a> {
         self.direction = direction;
         self
     }
+
+    /// Sets how the bars are drawn.
+    ///
+    /// [`SparklineMode::Bars`] by default. [`SparklineMode::Braille`] packs two values into each
+    /// column at four times the vertical resolution of a row. In this mode, the
+    /// [`Sparkline::bar_set`] is ignored, and when the two values sharing a column have different
+    /// styles, the style of the second one is patched over the first.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::widgets::{Sparkline, SparklineMode};
+    ///
+    /// let sparkline = Sparkline::default()
+    ///     .data(&[1, 4, 2, 8, 5, 7])
+    ///     .mode(SparklineMode::Braille);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn mode(mut self, mode: SparklineMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Draws a horizontal threshold line at the given value.
+    ///
+    /// The line is drawn across the whole width of the sparkline in the row that contains the
+    /// value, but only in the cells that are not already covered by a bar. Use
+    /// [`Sparkline::baseline_style`] to make it stand out from the bars.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{
+    ///     style::{Style, Stylize},
+    ///     widgets::Sparkline,
+    /// };
+    ///
+    /// let sparkline = Sparkline::default()
+    ///     .data(&[1, 4, 2, 8, 5, 7])
+    ///     .baseline(5)
+    ///     .baseline_style(Style::new().red());
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn baseline(mut self, value: i64) -> Self {
+        self.baseline = Some(value);
+        self
+    }
+
+    /// Sets the style of the threshold line drawn by [`Sparkline::baseline`].
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn baseline_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.baseline_style = style.into();
+        self
+    }
+
+    /// Highlights the smallest value of the dataset with the given style.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// The style is patched onto the cell that contains the tip of the bar. Only the first
+    /// occurrence of the value among the rendered bars is highlighted.
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn min_marker_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.min_marker_style = Some(style.into());
+        self
+    }
+
+    /// Highlights the largest value of the dataset with the given style.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// The style is patched onto the cell that contains the tip of the bar. Only the first
+    /// occurrence of the value among the rendered bars is highlighted.
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn max_marker_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.max_marker_style = Some(style.into());
+        self
+    }
 }
 
 /// An bar in a `Sparkline`.
@@ -252,7 +376,7 @@ pub struct SparklineBar {
     /// The value of the bar.
     ///
     /// If `None`, the bar is absent.
-    value: Option<u64>,
+    value: Option<SignedValue>,
     /// The style of the bar.
     ///
     /// If `None`, the bar will use the style of the sparkline.
@@ -260,6 +384,25 @@ pub struct SparklineBar {
 }
 
 impl SparklineBar {
+    /// Creates a bar from a signed value.
+    ///
+    /// Negative values are drawn downwards from a zero axis. See [`Sparkline`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::widgets::{Sparkline, SparklineBar};
+    ///
+    /// let data = [3, -2, 5, -4];
+    /// let sparkline = Sparkline::default().data(data.into_iter().map(SparklineBar::signed));
+    /// ```
+    pub const fn signed(value: i64) -> Self {
+        Self {
+            value: Some(SignedValue::new(value)),
+            style: None,
+        }
+    }
+
     /// Sets the style of the bar.
     ///
     /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
@@ -277,18 +420,26 @@ impl SparklineBar {
         self.style = style.into();
         self
     }
+
+    /// Returns the value of the bar with its sign.
+    fn signed_value(&self) -> Option<i128> {
+        self.value.map(SignedValue::get)
+    }
 }
 
 impl From<Option<u64>> for SparklineBar {
     fn from(value: Option<u64>) -> Self {
-        Self { value, style: None }
+        Self {
+            value: value.map(SignedValue::positive),
+            style: None,
+        }
     }
 }
 
 impl From<u64> for SparklineBar {
     fn from(value: u64) -> Self {
         Self {
-            value: Some(value),
+            value: Some(SignedValue::positive(value)),
             style: None,
         }
     }
@@ -297,7 +448,7 @@ impl From<u64> for SparklineBar {
 impl From<&u64> for SparklineBar {
     fn from(value: &u64) -> Self {
         Self {
-            value: Some(*value),
+            value: Some(SignedValue::positive(*value)),
             style: None,
         }
     }
@@ -306,7 +457,7 @@ impl From<&u64> for SparklineBar {
 impl From<&Option<u64>> for SparklineBar {
     fn from(value: &Option<u64>) -> Self {
         Self {
-            value: *value,
+            value: value.map(SignedValue::positive),
             style: None,
         }
     }
@@ -348,92 +499,228 @@ impl Default for AbsentValueSymbol {
     }
 }
 
+/// Returns the row that contains the given level of a bar, counted from the zero axis.
+///
+/// Positive levels are counted upwards from the axis and negative levels downwards.
+fn level_row(area: Rect, scale: &Scale, height: i128) -> u16 {
+    let levels = i128::from(scale.levels);
+    let offset = if height > 0 {
+        -1 - (height - 1) / levels
+    } else if height < 0 {
+        (-height - 1) / levels
+    } else if scale.positive_length > 0 {
+        -1
+    } else {
+        0
+    };
+    (i128::from(area.top() + scale.positive_length) + offset) as u16
+}
+
 impl Sparkline<'_> {
     fn render_sparkline(&self, spark_area: Rect, buf: &mut Buffer) {
         if spark_area.is_empty() {
             return;
         }
-        // determine the maximum height across all bars
-        let max_height = self
-            .max
-            .unwrap_or_else(|| self.data.iter().filter_map(|s| s.value).max().unwrap_or(1));
+        let per_column = self.values_per_column();
 
         // determine the maximum index to render
-        let max_index = min(spark_area.width as usize, self.data.len());
+        let max_index = min(spark_area.width as usize * per_column, self.data.len());
+        let data = &self.data[..max_index];
+
+        let scale = self.scale(spark_area);
+        match self.mode {
+            SparklineMode::Bars => self.render_bars(data, spark_area, &scale, buf),
+            SparklineMode::Braille => self.render_braille(data, spark_area, &scale, buf),
+        }
+        if let Some(baseline) = self.baseline {
+            self.render_baseline(
+                baseline,
+                data.len().div_ceil(per_column),
+                spark_area,
+                &scale,
+                buf,
+            );
+        }
+        self.render_markers(data, spark_area, &scale, buf);
+    }
+
+    const fn values_per_column(&self) -> usize {
+        match self.mode {
+            SparklineMode::Bars => 1,
+            SparklineMode::Braille => 2,
+        }
+    }
 
-        // render each item in the data
-        for (i, item) in self.data.iter().take(max_index).enumerate() {
-            let x = match self.direction {
-                RenderDirection::LeftToRight => spark_area.left() + i as u16,
-                RenderDirection::RightToLeft => spark_area.right() - i as u16 - 1,
+    /// Splits the area around the zero axis in proportion to the largest positive and negative
+    /// values of the dataset.
+    fn scale(&self, area: Rect) -> Scale {
+        // determine the maximum height and depth across all bars
+        let max = |negative: bool| {
+            self.data
+                .iter()
+                .filter_map(|bar| bar.value)
+                .filter(|value| value.negative == negative)
+                .map(|value| value.magnitude)
+                .max()
+                .unwrap_or(0)
+        };
+        let positive_max = self.max.unwrap_or_else(|| max(false));
+        let levels = match self.mode {
+            SparklineMode::Bars => 8,
+            SparklineMode::Braille => 4,
+        };
+        Scale::new(
+            area.height,
+            u128::from(positive_max),
+            u128::from(max(true)),
+            levels,
+        )
+    }
+
+    /// Returns the x coordinate of the given column, taking the render direction into account.
+    const fn column_x(&self, area: Rect, column: usize) -> u16 {
+        match self.direction {
+            RenderDirection::LeftToRight => area.left() + column as u16,
+            RenderDirection::RightToLeft => area.right() - column as u16 - 1,
+        }
+    }
+
+    fn render_absent_column(&self, x: u16, area: Rect, buf: &mut Buffer) {
+        for y in area.top()..area.bottom() {
+            buf[(x, y)]
+                .set_symbol(&self.absent_value_symbol.0)
+                .set_style(self.style.patch(self.absent_value_style));
+        }
+    }
+
+    fn render_bars(&self, data: &[SparklineBar], area: Rect, scale: &Scale, buf: &mut Buffer) {
+        for (i, item) in data.iter().enumerate() {
+            let x = self.column_x(area, i);
+
+            // absent values are rendered with the absent value symbol and style over the entire
+            // height of the spark area
+            let Some(value) = item.signed_value() else {
+                self.render_absent_column(x, area, buf);
+                continue;
             };
 
-            // determine the height, symbol and style to use for the item
-            //
-            // if the item is not absent:
-            // - the height is the value of the item scaled to the height of the spark area
-            // - the symbol is determined by the scaled height
-            // - the style is the style of the item, if one is set
+            // render the item outwards from the axis
             //
-            // otherwise:
-            // - the height is the total height of the spark area
-            // - the symbol is the absent value symbol
-            // - the style is the absent value style
-            let (mut height, symbol, style) = match item {
-                SparklineBar {
-                    value: Some(value),
-                    style,
-                } => {
-                    let height = if max_height == 0 {
-                        0
+            // the symbol of each row is determined by the _remaining_ height of the bar, and if the
+            // style of the item is set it will be patched over the sparkline style.
+            let style = self.style.patch(item.style.unwrap_or_default());
+            let height = scale.height(value);
+            let axis_y = area.top() + scale.positive_length;
+            for row in 0..scale.positive_length {
+                let remaining = (height - i128::from(row) * 8).clamp(0, 8) as u64;
+                buf[(x, axis_y - row - 1)]
+                    .set_symbol(self.bar_set.symbol_for_eighths(remaining))
+                    .set_style(style);
+            }
+            for row in 0..scale.negative_length {
+                let remaining = (-height - i128::from(row) * 8).clamp(0, 8) as u64;
+                set_depth_symbol(&mut buf[(x, axis_y + row)], &self.bar_set, remaining)
+                    .set_style(style);
+            }
+        }
+    }
+
+    fn render_braille(&self, data: &[SparklineBar], area: Rect, scale: &Scale, buf: &mut Buffer) {
+        for (column, items) in data.chunks(2).enumerate() {
+            let x = self.column_x(area, column);
+            if items.iter().all(|item| item.value.is_none()) {
+                self.render_absent_column(x, area, buf);
+                continue;
+            }
+
+            // each item fills one column of dots, from the axis outwards
+            let mut style = self.style;
+            let mut patterns = vec![symbols::braille::BLANK; area.height as usize];
+            for (i, item) in items.iter().enumerate() {
+                let Some(value) = item.signed_value() else {
+                    continue;
+                };
+                style = style.patch(item.style.unwrap_or_default());
+                let dot_x = match self.direction {
+                    RenderDirection::LeftToRight => i,
+                    RenderDirection::RightToLeft => 1 - i,
+                };
+                let height = scale.height(value);
+                for level in 0..height.unsigned_abs() as u16 {
+                    let (row, dot_y) = if height > 0 {
+                        (scale.positive_length - level / 4 - 1, 3 - level % 4)
                     } else {
-                        *value * u64::from(spark_area.height) * 8 / max_height
+                        (scale.positive_length + level / 4, level % 4)
                     };
-                    (height, None, *style)
+                    patterns[row as usize] |= symbols::braille::DOTS[dot_y as usize][dot_x];
                 }
-                _ => (
-                    u64::from(spark_area.height) * 8,
-                    Some(self.absent_value_symbol.0.as_str()),
-                    Some(self.absent_value_style),
-                ),
-            };
+            }
+            for (y, pattern) in (area.top()..area.bottom()).zip(patterns) {
+                let symbol = char::from_u32(u32::from(pattern)).unwrap_or(' ');
+                buf[(x, y)].set_char(symbol).set_style(style);
+            }
+        }
+    }
 
-            // render the item from top to bottom
-            //
-            // if the symbol is set it will be used for the entire height of the bar, otherwise the
-            // symbol will be determined by the _remaining_ height.
-            //
-            // if the style is set it will be used for the entire height of the bar, otherwise the
-            // sparkline style will be used.
-            for j in (0..spark_area.height).rev() {
-                let symbol = symbol.unwrap_or_else(|| self.symbol_for_height(height));
-                if height > 8 {
-                    height -= 8;
-                } else {
-                    height = 0;
-                }
-                buf[(x, spark_area.top() + j)]
-                    .set_symbol(symbol)
-                    .set_style(self.style.patch(style.unwrap_or_default()));
+    /// Draws the threshold line in the cells of its row that are not covered by a bar.
+    fn render_baseline(
+        &self,
+        baseline: i64,
+        columns: usize,
+        area: Rect,
+        scale: &Scale,
+        buf: &mut Buffer,
+    ) {
+        let height = scale.height(i128::from(baseline));
+        let levels = i128::from(scale.levels);
+        let axis_y = i128::from(area.top() + scale.positive_length);
+        let y = if height >= 0 {
+            axis_y - 1 - height / levels
+        } else {
+            axis_y - height / levels
+        };
+        let y = y.clamp(i128::from(area.top()), i128::from(area.bottom() - 1)) as u16;
+        for column in 0..area.width as usize {
+            let cell = &mut buf[(self.column_x(area, column), y)];
+            let symbol = cell.symbol();
+            let covered =
+                column < columns && symbol != self.bar_set.empty && symbol != BRAILLE_BLANK;
+            if !covered {
+                cell.set_symbol(symbols::line::HORIZONTAL)
+                    .set_style(self.style.patch(self.baseline_style));
             }
         }
     }
 
-    const fn symbol_for_height(&self, height: u64) -> &str {
-        match height {
-            0 => self.bar_set.empty,
-            1 => self.bar_set.one_eighth,
-            2 => self.bar_set.one_quarter,
-            3 => self.bar_set.three_eighths,
-            4 => self.bar_set.half,
-            5 => self.bar_set.five_eighths,
-            6 => self.bar_set.three_quarters,
-            7 => self.bar_set.seven_eighths,
-            _ => self.bar_set.full,
+    /// Patches the marker styles onto the tips of the first smallest and largest values.
+    fn render_markers(&self, data: &[SparklineBar], area: Rect, scale: &Scale, buf: &mut Buffer) {
+        let values = data
+            .iter()
+            .enumerate()
+            .filter_map(|(i, item)| item.signed_value().map(|value| (i, value)));
+        let markers = [
+            (
+                self.min_marker_style,
+                values.clone().min_by_key(|&(_, value)| value),
+            ),
+            (
+                self.max_marker_style,
+                values.rev().max_by_key(|&(_, value)| value),
+            ),
+        ];
+        for (style, extreme) in markers {
+            let (Some(style), Some((i, value))) = (style, extreme) else {
+                continue;
+            };
+            let x = self.column_x(area, i / self.values_per_column());
+            buf[(x, level_row(area, scale, scale.height(value)))].set_style(style);
         }
     }
 }
 
+/// The braille pattern without any dots.
+const BRAILLE_BLANK: &str = "\u{2800}";
+
 #[cfg(test)]
 mod tests {
     use ratatui_core::{
@@ -682,6 +969,160 @@ mod tests {
         assert_eq!(buffer, expected);
     }
 
+    #[test]
+    fn sparkline_mode_to_string() {
+        assert_eq!(SparklineMode::Bars.to_string(), "Bars");
+        assert_eq!(SparklineMode::Braille.to_string(), "Braille");
+    }
+
+    #[test]
+    fn sparkline_mode_from_str() {
+        assert_eq!("Bars".parse::<SparklineMode>(), Ok(SparklineMode::Bars));
+        assert_eq!(
+            "Braille".parse::<SparklineMode>(),
+            Ok(SparklineMode::Braille)
+        );
+        assert_eq!(
+            "".parse::<SparklineMode>(),
+            Err(ParseError::VariantNotFound)
+        );
+    }
+
+    #[test]
+    fn signed_bar() {
+        assert_eq!(SparklineBar::signed(3), SparklineBar::from(3));
+        assert_eq!(SparklineBar::signed(-3).signed_value(), Some(-3));
+        assert_eq!(
+            SparklineBar::signed(i64::MIN).signed_value(),
+            Some(i128::from(i64::MIN))
+        );
+    }
+
+    #[test]
+    fn it_draws_braille() {
+        let widget = Sparkline::default()
+            .data([0, 1, 2, 3, 4, 4])
+            .mode(SparklineMode::Braille);
+        let buffer = render(widget, 5);
+        assert_eq!(buffer, Buffer::with_lines(["⢀⣴⣿xx"]));
+    }
+
+    #[test]
+    fn it_draws_braille_double_height() {
+        let widget = Sparkline::default()
+            .data([0, 2, 4, 6, 8])
+            .mode(SparklineMode::Braille);
+        let area = Rect::new(0, 0, 4, 2);
+        let mut buffer = Buffer::filled(area, Cell::new("x"));
+        widget.render(area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["⠀⢠⡇x", "⢠⣿⡇x"]));
+    }
+
+    #[test]
+    fn it_draws_braille_right_to_left() {
+        let widget = Sparkline::default()
+            .data([0, 1, 2, 3, 4, 4])
+            .mode(SparklineMode::Braille)
+            .direction(RenderDirection::RightToLeft);
+        let buffer = render(widget, 5);
+        assert_eq!(buffer, Buffer::with_lines(["xx⣿⣦⡀"]));
+    }
+
+    #[test]
+    fn it_draws_braille_absent_values() {
+        let widget = Sparkline::default()
+            .data([None, None, Some(4), None])
+            .mode(SparklineMode::Braille)
+            .absent_value_symbol('*');
+        let buffer = render(widget, 3);
+        assert_eq!(buffer, Buffer::with_lines(["*⡇x"]));
+    }
+
+    #[test]
+    fn it_draws_negative_values_around_a_zero_axis() {
+        let widget = Sparkline::default().data([-8, -4, 0, 4, 8].map(SparklineBar::signed));
+        let area = Rect::new(0, 0, 6, 2);
+        let mut buffer = Buffer::filled(area, Cell::new("x"));
+        widget.render(area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["   ▄█x", "█▀   x"]));
+    }
+
+    #[test]
+    fn it_draws_negative_braille_values_around_a_zero_axis() {
+        let widget = Sparkline::default()
+            .data([-4, -2, 2, 4].map(SparklineBar::signed))
+            .mode(SparklineMode::Braille);
+        let area = Rect::new(0, 0, 3, 2);
+        let mut buffer = Buffer::filled(area, Cell::new("x"));
+        widget.render(area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["⠀⣼x", "⡟⠀x"]));
+    }
+
+    #[test]
+    fn it_draws_only_negative_values() {
+        let widget = Sparkline::default().data([-8, -4, 0].map(SparklineBar::signed));
+        let area = Rect::new(0, 0, 4, 2);
+        let mut buffer = Buffer::filled(area, Cell::new("x"));
+        widget.render(area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["██ x", "█  x"]));
+    }
+
+    #[test]
+    fn it_draws_a_baseline() {
+        let widget = Sparkline::default()
+            .data([0, 2, 4, 6, 8])
+            .baseline(4)
+            .baseline_style(Color::Red);
+        let area = Rect::new(0, 0, 6, 2);
+        let mut buffer = Buffer::filled(area, Cell::new("x"));
+        widget.render(area, &mut buffer);
+        let mut expected = Buffer::with_lines(["───▄█─", " ▄███x"]);
+        expected.set_style(Rect::new(0, 0, 3, 1), Color::Red);
+        expected.set_style(Rect::new(5, 0, 1, 1), Color::Red);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn it_draws_a_negative_baseline() {
+        let widget = Sparkline::default()
+            .data([-8, 8].map(SparklineBar::signed))
+            .baseline(-8);
+        let area = Rect::new(0, 0, 3, 2);
+        let mut buffer = Buffer::filled(area, Cell::new("x"));
+        widget.render(area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines([" █x", "█──"]));
+    }
+
+    #[test]
+    fn it_draws_min_and_max_markers() {
+        let widget = Sparkline::default()
+            .data([3, 1, 8, 5, 8, 1])
+            .min_marker_style(Color::Blue)
+            .max_marker_style(Color::Red);
+        let area = Rect::new(0, 0, 6, 2);
+        let mut buffer = Buffer::filled(area, Cell::new("x"));
+        widget.render(area, &mut buffer);
+        let mut expected = Buffer::with_lines(["  █▂█ ", "▆▂███▂"]);
+        expected.set_style(Rect::new(1, 1, 1, 1), Color::Blue);
+        expected.set_style(Rect::new(2, 0, 1, 1), Color::Red);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn it_draws_markers_on_negative_values() {
+        let widget = Sparkline::default()
+            .data([-8, -4, 0, 8].map(SparklineBar::signed))
+            .min_marker_style(Color::Blue)
+            .max_marker_style(Color::Red);
+        let area = Rect::new(0, 0, 4, 2);
+        let mut buffer = Buffer::filled(area, Cell::new("x"));
+        widget.render(area, &mut buffer);
+        let mut expected = Buffer::with_lines(["   █", "█▀  "]);
+        expected.set_style(Rect::new(0, 1, 1, 1), Color::Blue);
+        expected.set_style(Rect::new(3, 0, 1, 1), Color::Red);
+        assert_eq!(buffer, expected);
+    }
+
     #[test]
     fn can_be_stylized() {
         assert_eq!(
@@ -1,6 +1,8 @@
 //! The [`Table`] widget is used to display multiple rows and columns in a grid and allows selecting
 //! one or multiple cells.
 
+use std::{cmp::Ordering, mem, sync::Arc};
+
 use itertools::Itertools;
 use ratatui_core::{
     buffer::Buffer,
@@ -10,13 +12,38 @@ use ratatui_core::{
     widgets::{StatefulWidget, Widget},
 };
 
-pub use self::{cell::Cell, highlight_spacing::HighlightSpacing, row::Row, state::TableState};
-use crate::block::{Block, BlockExt};
+pub use self::{
+    auto_width::AutoWidth,
+    cell::Cell,
+    highlight_spacing::HighlightSpacing,
+    row::Row,
+    sort::{Comparator, RowFilter, SortOrder},
+    source::{TableSource, VirtualTable},
+    state::TableState,
+};
+use self::{
+    cell::layout_cells,
+    sort::{Comparators, Filter, ViewRows},
+    source::Rows,
+    wrap::WrappedRows,
+};
+use unicode_width::UnicodeWidthStr;
+
+pub use crate::marks::Marks;
+use crate::{
+    block::{Block, BlockExt},
+    paragraph::Wrap,
+};
 
+mod auto_width;
 mod cell;
 mod highlight_spacing;
 mod row;
+mod scroll;
+mod sort;
+mod source;
 mod state;
+mod wrap;
 
 /// A widget to display data in formatted columns.
 ///
@@ -272,11 +299,44 @@ pub struct Table<'a> {
     /// Symbol in front of the selected row
     highlight_symbol: Text<'a>,
 
+    /// Style used to render the marked rows
+    marked_row_style: Style,
+
+    /// Symbol in front of the marked rows
+    mark_symbol: Text<'a>,
+
     /// Decides when to allocate spacing for the row selection
     highlight_spacing: HighlightSpacing,
 
     /// Controls how to distribute extra space among the columns
     flex: Flex,
+
+    /// Symbols displayed in the header of the sorted column
+    sort_symbols: (&'a str, &'a str),
+
+    /// Comparators used to sort the columns, compared by text by default
+    comparators: Comparators,
+
+    /// Predicate used to filter the rows
+    filter: Filter,
+
+    /// Whether the columns are sized from their content instead of the widths
+    auto_widths: bool,
+
+    /// Limits of the widths of the columns sized from their content
+    column_limits: Vec<AutoWidth>,
+
+    /// Whether the columns overflowing the area are scrolled horizontally instead of squeezed
+    horizontal_scroll: bool,
+
+    /// Number of leading columns that stay visible when scrolling horizontally
+    frozen_columns: usize,
+
+    /// Symbol between the frozen columns and the scrolled columns
+    frozen_separator: &'a str,
+
+    /// How the content of the cells is wrapped, if it is
+    wrap: Option<Wrap>,
 }
 
 impl<'a> Default for Table<'a> {
@@ -293,8 +353,19 @@ impl<'a> Default for Table<'a> {
             column_highlight_style: Style::new(),
             cell_highlight_style: Style::new(),
             highlight_symbol: Text::default(),
+            marked_row_style: Style::new(),
+            mark_symbol: Text::default(),
             highlight_spacing: HighlightSpacing::default(),
             flex: Flex::Start,
+            sort_symbols: ("▲", "▼"),
+            comparators: Comparators::default(),
+            filter: Filter::default(),
+            auto_widths: false,
+            column_limits: Vec::new(),
+            horizontal_scroll: false,
+            frozen_columns: 0,
+            frozen_separator: "│",
+            wrap: None,
         }
     }
 }
@@ -682,6 +753,57 @@ impl<'a> Table<'a> {
         self
     }
 
+    /// Set the style of the marked rows
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// The rows marked in the [`TableState`] are the ones picked for an operation when several
+    /// rows can be selected, see [`TableState::marks_mut`]. The
+    /// [row highlight style](Table::row_highlight_style) is applied over this style when the marked
+    /// row is also the selected one.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{layout::Constraint, style::{Style, Stylize}, widgets::{Row, Table}};
+    /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let table = Table::new(rows, widths).marked_row_style(Style::new().yellow());
+    /// ```
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn marked_row_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.marked_row_style = style.into();
+        self
+    }
+
+    /// Set the symbol to be displayed in front of the marked rows
+    ///
+    /// The symbol is displayed in the same column as the
+    /// [highlight symbol](Table::highlight_symbol), which wins for the selected row. The column is
+    /// as wide as the widest of the two symbols.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{layout::Constraint, widgets::{Row, Table}};
+    /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let table = Table::new(rows, widths)
+    ///     .highlight_symbol("> ")
+    ///     .mark_symbol("* ");
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn mark_symbol<T: Into<Text<'a>>>(mut self, mark_symbol: T) -> Self {
+        self.mark_symbol = mark_symbol.into();
+        self
+    }
+
     /// Set when to show the highlight spacing
     ///
     /// The highlight spacing is the spacing that is allocated for the selection symbol column (if
@@ -748,6 +870,265 @@ impl<'a> Table<'a> {
         self.flex = flex;
         self
     }
+
+    /// Size the columns from their content instead of the widths
+    ///
+    /// Each column is as wide as its widest cell, including the header and the footer, within the
+    /// limits set with [`Table::column_limits`]. The space left is shared equally between the
+    /// columns that can grow. When the area is too narrow, the widest columns are shrunk first,
+    /// and the cells that don't fit end with an ellipsis (`…`).
+    ///
+    /// To keep this cheap for large tables, only the rows that are displayed are measured, so the
+    /// widths can change when scrolling. The widths set with [`Table::widths`] and the
+    /// [`Flex`] are ignored.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Row, Table};
+    ///
+    /// let rows = [Row::new(["apple", "1"]), Row::new(["banana split", "12"])];
+    /// let table = Table::default().rows(rows).auto_widths();
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn auto_widths(mut self) -> Self {
+        self.auto_widths = true;
+        self
+    }
+
+    /// Set the limits of the widths of the columns sized from their content
+    ///
+    /// The limits apply in order to the columns, the columns without limits can have any width.
+    /// This only has an effect with [`Table::auto_widths`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{AutoWidth, Row, Table};
+    ///
+    /// let rows = [Row::new(["apple", "1", "red"])];
+    /// let table = Table::default()
+    ///     .rows(rows)
+    ///     .auto_widths()
+    ///     .column_limits([AutoWidth::at_least(10), AutoWidth::new(2, 5)]);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn column_limits<I>(mut self, limits: I) -> Self
+    where
+        I: IntoIterator<Item = AutoWidth>,
+    {
+        self.column_limits = limits.into_iter().collect();
+        self
+    }
+
+    /// Scroll the columns horizontally instead of squeezing them in the area
+    ///
+    /// When enabled, the columns keep their natural width: the minimum of a
+    /// [`Constraint::Length`] or [`Constraint::Min`] constraint, or the width of their content
+    /// with [`Table::auto_widths`]. The columns that don't fit in the area are scrolled by whole
+    /// columns from the [`TableState::column_offset`], and the offset follows the selected column
+    /// so that it is always visible. Use [`Table::frozen_columns`] to keep the leading columns in
+    /// place, and [`TableState::column_scrollbar_state`] to display a horizontal [`Scrollbar`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::{
+    ///     layout::Constraint,
+    ///     widgets::{Row, Table},
+    /// };
+    ///
+    /// let rows = [Row::new(["1", "Alice", "alice@example.com", "Paris"])];
+    /// let widths = [2, 10, 20, 10].map(Constraint::Length);
+    /// let table = Table::new(rows, widths).horizontal_scroll(true);
+    /// ```
+    ///
+    /// [`Scrollbar`]: crate::scrollbar::Scrollbar
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn horizontal_scroll(mut self, horizontal_scroll: bool) -> Self {
+        self.horizontal_scroll = horizontal_scroll;
+        self
+    }
+
+    /// Set the number of leading columns that stay visible when scrolling horizontally
+    ///
+    /// The frozen columns are displayed on the left of the table, followed by the
+    /// [`Table::frozen_separator`] and the scrolled columns. This only has an effect with
+    /// [`Table::horizontal_scroll`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::{
+    ///     layout::Constraint,
+    ///     widgets::{Row, Table},
+    /// };
+    ///
+    /// let rows = [Row::new(["1", "Alice", "alice@example.com", "Paris"])];
+    /// let widths = [2, 10, 20, 10].map(Constraint::Length);
+    /// // keep the identifier column visible
+    /// let table = Table::new(rows, widths)
+    ///     .horizontal_scroll(true)
+    ///     .frozen_columns(1);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn frozen_columns(mut self, count: usize) -> Self {
+        self.frozen_columns = count;
+        self
+    }
+
+    /// Set the symbol displayed between the frozen columns and the scrolled columns
+    ///
+    /// The symbol is repeated over the height of the table, with the column spacing on both
+    /// sides. The default is `│`, an empty symbol removes the separator.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{layout::Constraint, widgets::{Row, Table}};
+    /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let table = Table::new(rows, widths)
+    ///     .horizontal_scroll(true)
+    ///     .frozen_columns(1)
+    ///     .frozen_separator("┃");
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn frozen_separator(mut self, separator: &'a str) -> Self {
+        self.frozen_separator = separator;
+        self
+    }
+
+    /// Wrap the content of the cells to the width of their column
+    ///
+    /// The lines of the cells are wrapped like a [`Paragraph`] with the given [`Wrap`] options,
+    /// and the height of each row is computed from its tallest cell. The [`Row::height`] is the
+    /// minimum height of the row. The offset and the selection of the [`TableState`] account for
+    /// the height of the wrapped rows. The header and the footer keep their height, so their wrapped
+    /// cells are cut at the bottom.
+    ///
+    /// The rows of a [`TableSource`] are built to compute their height, instead of calling
+    /// [`TableSource::row_height`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::{
+    ///     layout::Constraint,
+    ///     widgets::{Row, Table, Wrap},
+    /// };
+    ///
+    /// let rows = [Row::new(["apple", "a round fruit with a red or green skin"])];
+    /// let widths = [Constraint::Length(5), Constraint::Length(20)];
+    /// let table = Table::new(rows, widths).wrap(Wrap { trim: true });
+    /// ```
+    ///
+    /// [`Paragraph`]: crate::paragraph::Paragraph
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn wrap(mut self, wrap: Wrap) -> Self {
+        self.wrap = Some(wrap);
+        self
+    }
+
+    /// Set the symbols displayed in the header of the sorted column
+    ///
+    /// The symbol matching the [`SortOrder`] of the [`TableState`] is displayed at the right edge
+    /// of the header cell of the sorted column. The default symbols are `▲` and `▼`.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{layout::Constraint, widgets::{Row, Table}};
+    /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let table = Table::new(rows, widths)
+    ///     .header(Row::new(["Name", "Size"]))
+    ///     .sort_symbols("↑", "↓");
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn sort_symbols(mut self, ascending: &'a str, descending: &'a str) -> Self {
+        self.sort_symbols = (ascending, descending);
+        self
+    }
+
+    /// Set the function used to compare the cells of a column when sorting the rows
+    ///
+    /// By default, the cells are compared by their text. A comparator is useful for columns
+    /// holding non-string data, e.g. numbers, sizes or dates. See [`TableState::sort_by`] to sort
+    /// the rows.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::{
+    ///     layout::Constraint,
+    ///     widgets::{Cell, Row, Table},
+    /// };
+    ///
+    /// fn size(cell: &Cell) -> u64 {
+    ///     cell.text().to_string().parse().unwrap_or_default()
+    /// }
+    ///
+    /// let rows = [Row::new(["a.txt", "120"]), Row::new(["b.txt", "9"])];
+    /// let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let table = Table::new(rows, widths).sort_comparator(1, |a, b| size(a).cmp(&size(b)));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn sort_comparator<F>(mut self, column: usize, comparator: F) -> Self
+    where
+        F: Fn(&Cell<'_>, &Cell<'_>) -> Ordering + Send + Sync + 'static,
+    {
+        self.comparators.set(column, Arc::new(comparator));
+        self
+    }
+
+    /// Set the function used to decide whether a row matches the filter query
+    ///
+    /// By default, the rows with a cell containing the query, ignoring case, are displayed. See
+    /// [`TableState::set_filter`] to filter the rows.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::{
+    ///     layout::Constraint,
+    ///     widgets::{Row, Table},
+    /// };
+    ///
+    /// let rows = [Row::new(["a.txt", "120"]), Row::new(["b.rs", "9"])];
+    /// let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// // match the file extension only
+    /// let table = Table::new(rows, widths).filter_fn(|row, query| {
+    ///     row.cell(0)
+    ///         .is_some_and(|cell| cell.text().to_string().ends_with(query))
+    /// });
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn filter_fn<F>(mut self, filter: F) -> Self
+    where
+        F: Fn(&Row<'_>, &str) -> bool + Send + Sync + 'static,
+    {
+        self.filter = Filter(Arc::new(filter));
+        self
+    }
 }
 
 impl Widget for Table<'_> {
@@ -775,6 +1156,51 @@ impl StatefulWidget for &Table<'_> {
     type State = TableState;
 
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let Some(view) = self.view(state) else {
+            state.view = None;
+            self.render_table(self.rows.as_slice(), self.column_count(), area, buf, state);
+            return;
+        };
+        // The selection is an index in the rows, so it is converted to a position in the view
+        // while rendering. A selected row that is filtered out is deselected.
+        let selected = match state.selected {
+            Some(selected) if selected >= self.rows.len() => view.last().copied(),
+            selected => selected,
+        };
+        state.selected = selected.and_then(|selected| view.iter().position(|&i| i == selected));
+        // The marks are converted in the same way, and the marks of the filtered out rows are
+        // kept for when they are displayed again.
+        let mut marks = mem::take(&mut state.marks);
+        marks.truncate(self.rows.len());
+        state.marks = view
+            .iter()
+            .enumerate()
+            .filter(|(_, &i)| marks.contains(i))
+            .map(|(position, _)| position)
+            .collect();
+        let rows = ViewRows {
+            rows: &self.rows,
+            view: &view,
+        };
+        self.render_table(&rows, self.column_count(), area, buf, state);
+        state.selected = state.selected.map(|position| view[position]);
+        state.marks = marks;
+        state.view = Some(view);
+    }
+}
+
+// private methods for rendering
+impl Table<'_> {
+    /// Renders the table with the given rows, which are either the rows of the table or a
+    /// [`TableSource`].
+    fn render_table<R: Rows + ?Sized>(
+        &self,
+        rows: &R,
+        column_count: usize,
+        area: Rect,
+        buf: &mut Buffer,
+        state: &mut TableState,
+    ) {
         buf.set_style(area, self.style);
         self.block.as_ref().render(area, buf);
         let table_area = self.block.inner_if_some(area);
@@ -782,15 +1208,15 @@ impl StatefulWidget for &Table<'_> {
             return;
         }
 
-        if state.selected.is_some_and(|s| s >= self.rows.len()) {
-            state.select(Some(self.rows.len().saturating_sub(1)));
+        if state.selected.is_some_and(|s| s >= rows.len()) {
+            state.select(Some(rows.len().saturating_sub(1)));
         }
 
-        if self.rows.is_empty() {
+        if rows.len() == 0 {
             state.select(None);
         }
+        state.marks.truncate(rows.len());
 
-        let column_count = self.column_count();
         if state.selected_column.is_some_and(|s| s >= column_count) {
             state.select_column(Some(column_count.saturating_sub(1)));
         }
@@ -799,27 +1225,76 @@ impl StatefulWidget for &Table<'_> {
         }
 
         let selection_width = self.selection_width(state);
-        let columns_widths =
-            self.get_columns_widths(table_area.width, selection_width, column_count);
         let (header_area, rows_area, footer_area) = self.layout(table_area);
+        let bounds = if self.auto_widths && rows.len() > 0 {
+            Self::get_row_bounds(rows, state.selected, state.offset, rows_area.height)
+        } else {
+            (0, 0)
+        };
+        let mut separator = None;
+        let columns_widths = if self.horizontal_scroll {
+            let widths = if self.auto_widths {
+                self.get_natural_auto_widths(rows, bounds, column_count)
+            } else {
+                self.get_natural_widths(table_area.width, selection_width, column_count)
+            };
+            let columns = self.scroll_columns(&widths, table_area.width, selection_width, state);
+            separator = columns.separator;
+            columns.widths
+        } else if self.auto_widths {
+            self.get_auto_columns_widths(
+                rows,
+                bounds,
+                table_area.width,
+                selection_width,
+                column_count,
+            )
+        } else {
+            self.get_columns_widths(table_area.width, selection_width, column_count)
+        };
 
-        self.render_header(header_area, buf, &columns_widths);
-
-        self.render_rows(
-            rows_area,
-            buf,
-            state,
-            selection_width,
-            &self.highlight_symbol,
-            &columns_widths,
-        );
+        self.render_header(header_area, buf, &columns_widths, state.sort);
+
+        if let Some(wrap) = self.wrap {
+            let rows = WrappedRows::new(rows, &columns_widths, wrap);
+            self.render_rows(
+                &rows,
+                rows_area,
+                buf,
+                state,
+                selection_width,
+                &columns_widths,
+            );
+        } else {
+            self.render_rows(
+                rows,
+                rows_area,
+                buf,
+                state,
+                selection_width,
+                &columns_widths,
+            );
+        }
 
         self.render_footer(footer_area, buf, &columns_widths);
+
+        if let Some(x) = separator {
+            self.render_frozen_separator(x, table_area, buf);
+        }
+    }
+
+    /// Renders a cell, wrapping its lines if the cells are wrapped, or ending the lines that don't
+    /// fit with an ellipsis if the columns are sized from their content
+    fn render_cell(&self, cell: &Cell<'_>, area: Rect, buf: &mut Buffer) {
+        if let Some(wrap) = self.wrap {
+            cell.render_wrapped(area, buf, wrap);
+        } else if self.auto_widths {
+            cell.render_truncated(area, buf);
+        } else {
+            cell.render(area, buf);
+        }
     }
-}
 
-// private methods for rendering
-impl Table<'_> {
     /// Splits the table area into a header, rows area and a footer
     fn layout(&self, area: Rect) -> (Rect, Rect, Rect) {
         let header_top_margin = self.header.as_ref().map_or(0, |h| h.top_margin);
@@ -842,11 +1317,33 @@ impl Table<'_> {
         (header_area, rows_area, footer_area)
     }
 
-    fn render_header(&self, area: Rect, buf: &mut Buffer, column_widths: &[(u16, u16)]) {
+    fn render_header(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        column_widths: &[(u16, u16)],
+        sort: Option<(usize, SortOrder)>,
+    ) {
         if let Some(ref header) = self.header {
             buf.set_style(area, header.style);
-            for ((x, width), cell) in column_widths.iter().zip(header.cells.iter()) {
-                cell.render(Rect::new(area.x + x, area.y, *width, area.height), buf);
+            for (cell, _, cell_area) in layout_cells(&header.cells, column_widths, area) {
+                self.render_cell(cell, cell_area, buf);
+            }
+            if let Some((&(x, width), order)) =
+                sort.and_then(|(column, order)| Some((column_widths.get(column)?, order)))
+            {
+                let symbol = match order {
+                    SortOrder::Ascending => self.sort_symbols.0,
+                    SortOrder::Descending => self.sort_symbols.1,
+                };
+                let symbol_width = (symbol.width() as u16).min(width);
+                buf.set_stringn(
+                    area.x + x + width - symbol_width,
+                    area.y,
+                    symbol,
+                    symbol_width as usize,
+                    Style::new(),
+                );
             }
         }
     }
@@ -854,66 +1351,91 @@ impl Table<'_> {
     fn render_footer(&self, area: Rect, buf: &mut Buffer, column_widths: &[(u16, u16)]) {
         if let Some(ref footer) = self.footer {
             buf.set_style(area, footer.style);
-            for ((x, width), cell) in column_widths.iter().zip(footer.cells.iter()) {
-                cell.render(Rect::new(area.x + x, area.y, *width, area.height), buf);
+            for (cell, _, cell_area) in layout_cells(&footer.cells, column_widths, area) {
+                self.render_cell(cell, cell_area, buf);
             }
         }
     }
 
-    fn render_rows(
+    fn render_rows<R: Rows + ?Sized>(
         &self,
+        rows: &R,
         area: Rect,
         buf: &mut Buffer,
         state: &mut TableState,
         selection_width: u16,
-        highlight_symbol: &Text<'_>,
         columns_widths: &[(u16, u16)],
     ) {
-        if self.rows.is_empty() {
+        if rows.len() == 0 {
             return;
         }
 
         let (start_index, end_index) =
-            self.get_row_bounds(state.selected, state.offset, area.height);
+            Self::get_row_bounds(rows, state.selected, state.offset, area.height);
         state.offset = start_index;
 
         let mut y_offset = 0;
 
         let mut selected_row_area = None;
-        for (i, row) in self
-            .rows
-            .iter()
-            .enumerate()
-            .skip(state.offset)
-            .take(end_index - start_index)
-        {
-            let row_area = Rect::new(
-                area.x,
-                area.y + y_offset + row.top_margin,
-                area.width,
-                row.height_with_margin() - row.top_margin,
-            );
-            buf.set_style(row_area, row.style);
-
-            let is_selected = state.selected.is_some_and(|index| index == i);
-            if selection_width > 0 && is_selected {
-                let selection_area = Rect {
-                    width: selection_width,
-                    ..row_area
-                };
-                buf.set_style(selection_area, row.style);
-                highlight_symbol.render(selection_area, buf);
-            };
-            for ((x, width), cell) in columns_widths.iter().zip(row.cells.iter()) {
-                cell.render(
-                    Rect::new(row_area.x + x, row_area.y, *width, row_area.height),
-                    buf,
+        let mut selected_cell_area = None;
+        state.selected_column_span = None;
+        for i in start_index..end_index {
+            rows.with_row(i, |row| {
+                let height = rows.row_height(i, row);
+                let row_area = Rect::new(
+                    area.x,
+                    area.y + y_offset + row.top_margin,
+                    area.width,
+                    height.saturating_add(row.bottom_margin),
                 );
-            }
-            if is_selected {
-                selected_row_area = Some(row_area);
-            }
-            y_offset += row.height_with_margin();
+                buf.set_style(row_area, row.style);
+
+                let is_selected = state.selected.is_some_and(|index| index == i);
+                let is_marked = state.marks.contains(i);
+                if selection_width > 0 && (is_selected || is_marked) {
+                    let selection_area = Rect {
+                        width: selection_width,
+                        ..row_area
+                    };
+                    buf.set_style(selection_area, row.style);
+                    if is_selected {
+                        (&self.highlight_symbol).render(selection_area, buf);
+                    } else {
+                        (&self.mark_symbol).render(selection_area, buf);
+                    }
+                };
+                for (cell, columns, mut cell_area) in
+                    layout_cells(&row.cells, columns_widths, row_area)
+                {
+                    if cell.rows() > 1 {
+                        // extend the cell over the following visible rows it spans
+                        let last = (i + usize::from(cell.rows())).min(end_index);
+                        let height: u16 = (i + 1..last).map(|j| rows.height_with_margin(j)).sum();
+                        cell_area.height = (cell_area.height + height)
+                            .min(area.bottom().saturating_sub(cell_area.y));
+                    }
+                    self.render_cell(cell, cell_area, buf);
+                    if is_selected
+                        && state
+                            .selected_column
+                            .is_some_and(|column| columns.contains(&column))
+                    {
+                        // a spanned cell is selected as a whole, from its first column
+                        state.selected_column = Some(columns.start);
+                        state.selected_column_span = Some((columns.start, columns.end));
+                        selected_cell_area = Some(cell_area);
+                    }
+                }
+                if is_marked {
+                    buf.set_style(row_area, self.marked_row_style);
+                }
+                if is_selected {
+                    selected_row_area = Some(row_area);
+                }
+                y_offset += height
+                    .saturating_add(row.top_margin)
+                    .saturating_add(row.bottom_margin);
+            });
         }
 
         let selected_column_area = state.selected_column.and_then(|s| {
@@ -930,7 +1452,8 @@ impl Table<'_> {
             (Some(row_area), Some(col_area)) => {
                 buf.set_style(row_area, self.row_highlight_style);
                 buf.set_style(col_area, self.column_highlight_style);
-                let cell_area = row_area.intersection(col_area);
+                let cell_area =
+                    selected_cell_area.unwrap_or_else(|| row_area.intersection(col_area));
                 buf.set_style(cell_area, self.cell_highlight_style);
             }
             (Some(row_area), None) => {
@@ -970,21 +1493,25 @@ impl Table<'_> {
         rects.iter().map(|c| (c.x, c.width)).collect()
     }
 
-    fn get_row_bounds(
-        &self,
+    /// Returns the range of rows that are visible, starting from the offset and scrolled to show
+    /// the selected row.
+    ///
+    /// Only the heights of the rows around the offset and the selected row are queried.
+    fn get_row_bounds<R: Rows + ?Sized>(
+        rows: &R,
         selected: Option<usize>,
         offset: usize,
         max_height: u16,
     ) -> (usize, usize) {
-        let offset = offset.min(self.rows.len().saturating_sub(1));
+        let offset = offset.min(rows.len().saturating_sub(1));
         let mut start = offset;
         let mut end = offset;
         let mut height = 0;
-        for item in self.rows.iter().skip(offset) {
-            if height + item.height > max_height {
+        for index in offset..rows.len() {
+            if height + rows.height(index) > max_height {
                 break;
             }
-            height += item.height_with_margin();
+            height += rows.height_with_margin(index);
             end += 1;
         }
 
@@ -993,25 +1520,39 @@ impl Table<'_> {
         };
 
         // clamp the selected row to the last row
-        let selected = selected.min(self.rows.len() - 1);
-
-        // scroll down until the selected row is visible
-        while selected >= end {
-            height = height.saturating_add(self.rows[end].height_with_margin());
-            end += 1;
-            while height > max_height {
-                height = height.saturating_sub(self.rows[start].height_with_margin());
-                start += 1;
+        let selected = selected.min(rows.len() - 1);
+
+        // scroll down until the selected row is visible: the selected row is the last one, and
+        // the rows before it are added while they fit, without going back past the offset
+        if selected >= end {
+            let offset = start;
+            end = selected + 1;
+            start = end;
+            height = 0;
+            while start > offset {
+                let row_height = rows.height_with_margin(start - 1);
+                if height.saturating_add(row_height) > max_height {
+                    break;
+                }
+                height += row_height;
+                start -= 1;
             }
         }
 
-        // scroll up until the selected row is visible
-        while selected < start {
-            start -= 1;
-            height = height.saturating_add(self.rows[start].height_with_margin());
-            while height > max_height {
-                end -= 1;
-                height = height.saturating_sub(self.rows[end].height_with_margin());
+        // scroll up until the selected row is visible: the selected row is the first one, and
+        // the rows after it are added while they fit, without going past the previous end
+        if selected < start {
+            let previous_end = end;
+            start = selected;
+            end = start;
+            height = 0;
+            while end < previous_end {
+                let row_height = rows.height_with_margin(end);
+                if height.saturating_add(row_height) > max_height {
+                    break;
+                }
+                height += row_height;
+                end += 1;
             }
         }
         (start, end)
@@ -1022,7 +1563,7 @@ impl Table<'_> {
             .iter()
             .chain(self.footer.iter())
             .chain(self.header.iter())
-            .map(|r| r.cells.len())
+            .map(Row::column_count)
             .max()
             .unwrap_or_default()
     }
@@ -1030,9 +1571,9 @@ impl Table<'_> {
     /// Returns the width of the selection column if a row is selected, or the `highlight_spacing`
     /// is set to show the column always, otherwise 0.
     fn selection_width(&self, state: &TableState) -> u16 {
-        let has_selection = state.selected.is_some();
+        let has_selection = state.selected.is_some() || !state.marks.is_empty();
         if self.highlight_spacing.should_add(has_selection) {
-            self.highlight_symbol.width() as u16
+            self.highlight_symbol.width().max(self.mark_symbol.width()) as u16
         } else {
             0
         }
@@ -1610,6 +2151,96 @@ mod tests {
             assert_eq!(buf, expected);
         }
 
+        #[test]
+        fn render_with_column_spans() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 14, 3));
+            let table = Table::new([Row::new(["a", "1", "2"])], [Constraint::Length(4); 3])
+                .header(Row::new([
+                    Cell::new("Host"),
+                    Cell::new("Network").column_span(2),
+                ]))
+                .footer(Row::new([Cell::new("total: 3").column_span(3)]));
+            Widget::render(table, Rect::new(0, 0, 14, 3), &mut buf);
+            #[rustfmt::skip]
+            let expected = Buffer::with_lines([
+                "Host Network  ",
+                "a    1    2   ",
+                "total: 3      ",
+            ]);
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_row_spans() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 7, 3));
+            let rows = [
+                Row::new([Cell::new("Fruit\ns").row_span(2), Cell::new("x")]),
+                Row::new([Cell::default(), Cell::new("y")]),
+            ];
+            let table = Table::new(rows, [Constraint::Length(5), Constraint::Length(1)]);
+            Widget::render(table, Rect::new(0, 0, 7, 3), &mut buf);
+            let expected = Buffer::with_lines(["Fruit x", "s     y", "       "]);
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_selected_spanned_cell() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 8, 2));
+            let rows = [
+                Row::new([Cell::new("ab").column_span(2), Cell::new("c")]),
+                Row::new(["d", "e", "f"]),
+            ];
+            let table = Table::new(rows, [Constraint::Length(2); 3])
+                .cell_highlight_style(Style::new().green());
+            let mut state = TableState::new().with_selected_cell((0, 1));
+            StatefulWidget::render(&table, Rect::new(0, 0, 8, 2), &mut buf, &mut state);
+            let expected = Buffer::with_lines::<[Line; 2]>([
+                Line::from(vec!["ab   ".green(), " c ".into()]),
+                Line::from("d  e  f "),
+            ]);
+            assert_eq!(buf, expected);
+            assert_eq!(state.selected_column(), Some(0));
+
+            // moving to the next column skips the span
+            state.select_next_column();
+            assert_eq!(state.selected_column(), Some(2));
+            StatefulWidget::render(&table, Rect::new(0, 0, 8, 2), &mut buf, &mut state);
+
+            // moving back selects the spanned cell
+            state.select_previous_column();
+            StatefulWidget::render(&table, Rect::new(0, 0, 8, 2), &mut buf, &mut state);
+            assert_eq!(state.selected_column(), Some(0));
+        }
+
+        #[test]
+        fn render_with_marked_rows() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 6, 4));
+            let rows = [
+                Row::new(["a", "b"]),
+                Row::new(["c", "d"]),
+                Row::new(["e", "f"]),
+                Row::new(["g", "h"]),
+            ];
+            let table = Table::new(rows, [Constraint::Length(1); 2])
+                .highlight_symbol(">>")
+                .mark_symbol("*")
+                .marked_row_style(Style::new().blue())
+                .row_highlight_style(Style::new().on_yellow());
+            let mut state = TableState::new()
+                .with_selected(Some(2))
+                .with_marked([1, 2, 9]);
+            StatefulWidget::render(&table, buf.area, &mut buf, &mut state);
+            let expected = Buffer::with_lines::<[Line; 4]>([
+                Line::from("  a b "),
+                Line::from("* c d ".blue()),
+                Line::from(">>e f ".blue().on_yellow()),
+                Line::from("  g h "),
+            ]);
+            assert_eq!(buf, expected);
+            // the marks out of bounds are removed
+            assert_eq!(state.marks().iter().collect::<Vec<_>>(), [1, 2]);
+        }
+
         #[test]
         fn render_with_selected_row_and_column() {
             let mut buf = Buffer::empty(Rect::new(0, 0, 20, 4));
@@ -2295,4 +2926,12 @@ mod tests {
         let column_count = table.column_count();
         assert_eq!(column_count, expected);
     }
+
+    #[test]
+    fn column_count_with_spans() {
+        let table = Table::default()
+            .rows([Row::new(["a", "b"])])
+            .header(Row::new([Cell::new("h").column_span(2), Cell::new("i")]));
+        assert_eq!(table.column_count(), 3);
+    }
 }
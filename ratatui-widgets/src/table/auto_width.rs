@@ -0,0 +1,333 @@
+use ratatui_core::{
+    buffer::Buffer,
+    layout::{Alignment, Constraint, Layout, Rect},
+};
+
+use crate::table::{source::Rows, Cell, Row, Table};
+
+/// The limits of the width of a column of a [`Table`] sized from its content
+///
+/// See [`Table::auto_widths`] and [`Table::column_limits`].
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::widgets::AutoWidth;
+///
+/// // a column between 5 and 20 cells wide
+/// let limits = AutoWidth::new(5, 20);
+/// // a column at most 30 cells wide
+/// let limits = AutoWidth::at_most(30);
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct AutoWidth {
+    /// The minimum width of the column
+    pub min: u16,
+    /// The maximum width of the column
+    pub max: u16,
+}
+
+impl AutoWidth {
+    /// Creates limits with the given minimum and maximum widths.
+    pub const fn new(min: u16, max: u16) -> Self {
+        Self { min, max }
+    }
+
+    /// Creates limits with the given minimum width and no maximum.
+    pub const fn at_least(min: u16) -> Self {
+        Self::new(min, u16::MAX)
+    }
+
+    /// Creates limits with the given maximum width and no minimum.
+    pub const fn at_most(max: u16) -> Self {
+        Self::new(0, max)
+    }
+
+    /// Clamps a width to the limits. If the maximum is lower than the minimum, the minimum wins.
+    const fn clamp(self, mut width: u16) -> u16 {
+        if width > self.max {
+            width = self.max;
+        }
+        if width < self.min {
+            self.min
+        } else {
+            width
+        }
+    }
+}
+
+impl Default for AutoWidth {
+    fn default() -> Self {
+        Self::new(0, u16::MAX)
+    }
+}
+
+impl Table<'_> {
+    /// Returns the offset and width of the columns, sized from the content of the header, the
+    /// footer and the rows in the given range.
+    pub(crate) fn get_auto_columns_widths<R: Rows + ?Sized>(
+        &self,
+        rows: &R,
+        bounds: (usize, usize),
+        max_width: u16,
+        selection_width: u16,
+        col_count: usize,
+    ) -> Vec<(u16, u16)> {
+        let content_widths = self.get_content_widths(rows, bounds, col_count);
+        let spacing = self
+            .column_spacing
+            .saturating_mul(u16::try_from(col_count.saturating_sub(1)).unwrap_or(u16::MAX));
+        let available = max_width
+            .saturating_sub(selection_width)
+            .saturating_sub(spacing);
+        let widths = fit_widths(&content_widths, &self.column_limits, available);
+
+        let [_selection_area, columns_area] =
+            Layout::horizontal([Constraint::Length(selection_width), Constraint::Fill(0)])
+                .areas(Rect::new(0, 0, max_width, 1));
+        let mut x = columns_area.x;
+        widths
+            .into_iter()
+            .map(|width| {
+                // columns that don't fit in the area at their minimum width are cut
+                let width = width.min(columns_area.right().saturating_sub(x));
+                let column = (x, width);
+                x = x
+                    .saturating_add(width)
+                    .saturating_add(self.column_spacing)
+                    .min(columns_area.right());
+                column
+            })
+            .collect()
+    }
+
+    /// Returns the widths of the content of the columns within their limits, measured from the
+    /// header, the footer and the rows in the given range.
+    pub(crate) fn get_natural_auto_widths<R: Rows + ?Sized>(
+        &self,
+        rows: &R,
+        bounds: (usize, usize),
+        col_count: usize,
+    ) -> Vec<u16> {
+        self.get_content_widths(rows, bounds, col_count)
+            .into_iter()
+            .enumerate()
+            .map(|(column, width)| {
+                let limit = self.column_limits.get(column).copied();
+                limit.unwrap_or_default().clamp(width)
+            })
+            .collect()
+    }
+
+    /// Returns the width of the widest cell of each column in the header, the footer and the rows
+    /// in the given range.
+    fn get_content_widths<R: Rows + ?Sized>(
+        &self,
+        rows: &R,
+        (start, end): (usize, usize),
+        col_count: usize,
+    ) -> Vec<u16> {
+        let mut content_widths = vec![0; col_count];
+        let mut measure = |row: &Row<'_>| {
+            let mut column = 0;
+            for cell in &row.cells {
+                // cells spanning several columns fit in the columns sized from the other cells
+                if cell.columns() == 1 {
+                    if let Some(width) = content_widths.get_mut(column) {
+                        let cell_width = u16::try_from(cell.text().width()).unwrap_or(u16::MAX);
+                        *width = (*width).max(cell_width);
+                    }
+                }
+                column += usize::from(cell.columns());
+            }
+        };
+        self.header
+            .iter()
+            .chain(self.footer.iter())
+            .for_each(&mut measure);
+        for index in start..end {
+            rows.with_row(index, &mut measure);
+        }
+        content_widths
+    }
+}
+
+/// Fits the content widths of the columns in the available width, within the limits of each
+/// column.
+///
+/// When there is space left, it is shared equally between the columns that can grow. When the
+/// columns don't fit, the widest ones are shrunk first, without going below their minimum.
+fn fit_widths(content_widths: &[u16], limits: &[AutoWidth], available: u16) -> Vec<u16> {
+    let limit = |column: usize| limits.get(column).copied().unwrap_or_default();
+    let mut widths: Vec<u16> = content_widths
+        .iter()
+        .enumerate()
+        .map(|(column, &width)| limit(column).clamp(width))
+        .collect();
+    let total: u32 = widths.iter().map(|&width| u32::from(width)).sum();
+    let available_total = u32::from(available);
+
+    if total > available_total {
+        // find the highest level the columns can be lowered to while fitting in the area
+        let fitted_total = |level: u16| -> u32 {
+            widths
+                .iter()
+                .enumerate()
+                .map(|(column, &width)| u32::from(width.min(level).max(limit(column).min)))
+                .sum()
+        };
+        let (mut low, mut high) = (0, widths.iter().copied().max().unwrap_or_default());
+        while low < high {
+            let mid = low + (high - low).div_ceil(2);
+            if fitted_total(mid) <= available_total {
+                low = mid;
+            } else {
+                high = mid - 1;
+            }
+        }
+        let mut left = available_total.saturating_sub(fitted_total(low));
+        for (column, width) in widths.iter_mut().enumerate() {
+            let lowered = (*width).min(low).max(limit(column).min);
+            // the space left by the rounding of the level goes to the first shrunk columns
+            if lowered < *width && left > 0 {
+                *width = lowered + 1;
+                left -= 1;
+            } else {
+                *width = lowered;
+            }
+        }
+    } else {
+        let mut extra = available_total - total;
+        loop {
+            let growable = widths
+                .iter()
+                .enumerate()
+                .filter(|&(column, &width)| width < limit(column).max)
+                .count();
+            if extra == 0 || growable == 0 {
+                break;
+            }
+            let share = (extra / growable as u32).max(1);
+            for (column, width) in widths.iter_mut().enumerate() {
+                let room = u32::from(limit(column).max.saturating_sub(*width));
+                let added = share.min(room).min(extra);
+                *width += added as u16;
+                extra -= added;
+            }
+        }
+    }
+    widths
+}
+
+impl Cell<'_> {
+    /// Renders the cell, replacing the last visible character of the lines that don't fit in the
+    /// area with an ellipsis, or the first one for right aligned lines.
+    pub(crate) fn render_truncated(&self, area: Rect, buf: &mut Buffer) {
+        self.render(area, buf);
+        if area.is_empty() {
+            return;
+        }
+        let text = self.text();
+        for (line, y) in text.lines.iter().zip(area.rows().map(|row| row.y)) {
+            if line.width() <= usize::from(area.width) {
+                continue;
+            }
+            let x = match line.alignment.or(text.alignment) {
+                Some(Alignment::Right) => area.left(),
+                _ => area.right() - 1,
+            };
+            buf[(x, y)].set_symbol("…");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use ratatui_core::{text::Line, widgets::Widget};
+
+    use super::*;
+
+    #[test]
+    fn clamp() {
+        assert_eq!(AutoWidth::new(2, 5).clamp(1), 2);
+        assert_eq!(AutoWidth::new(2, 5).clamp(7), 5);
+        assert_eq!(AutoWidth::new(5, 2).clamp(3), 5);
+        assert_eq!(AutoWidth::at_most(3).clamp(0), 0);
+    }
+
+    #[test]
+    fn fit_widths_grow() {
+        assert_eq!(fit_widths(&[3, 5], &[], 14), [6, 8]);
+        assert_eq!(fit_widths(&[3, 5], &[], 9), [4, 5]);
+        assert_eq!(fit_widths(&[3, 5], &[AutoWidth::at_most(4)], 14), [4, 10]);
+        assert_eq!(
+            fit_widths(&[3, 5], &[AutoWidth::at_most(4), AutoWidth::at_most(6)], 14),
+            [4, 6]
+        );
+    }
+
+    #[test]
+    fn fit_widths_shrink() {
+        // the widest column is shrunk first
+        assert_eq!(fit_widths(&[3, 10], &[], 9), [3, 6]);
+        assert_eq!(fit_widths(&[8, 10], &[], 9), [5, 4]);
+        assert_eq!(fit_widths(&[8, 10], &[AutoWidth::at_least(7)], 9), [7, 2]);
+        // the minimums win over the available width
+        assert_eq!(
+            fit_widths(&[8, 10], &[AutoWidth::at_least(7); 2], 9),
+            [7, 7]
+        );
+    }
+
+    #[test]
+    fn auto_widths() {
+        let table = Table::new(
+            [Row::new(["a", "bbbb"]), Row::new(["cc", "d"])],
+            [Constraint::Length(1); 2],
+        )
+        .header(Row::new(["h", "h"]))
+        .auto_widths();
+        assert_eq!(
+            table.get_auto_columns_widths(table.rows.as_slice(), (0, 2), 10, 0, 2),
+            [(0, 4), (5, 5)]
+        );
+        // only the given rows are measured
+        assert_eq!(
+            table.get_auto_columns_widths(table.rows.as_slice(), (1, 2), 10, 0, 2),
+            [(0, 5), (6, 4)]
+        );
+        // columns are cut at the edge of the area
+        let table = table.column_limits([AutoWidth::at_least(8), AutoWidth::at_least(8)]);
+        assert_eq!(
+            table.get_auto_columns_widths(table.rows.as_slice(), (0, 2), 10, 0, 2),
+            [(0, 8), (9, 1)]
+        );
+    }
+
+    #[test]
+    fn render_auto_widths() {
+        let table = Table::default()
+            .rows([Row::new(["apple", "1"]), Row::new(["banana split", "12"])])
+            .header(Row::new(["fruit", "n"]))
+            .auto_widths()
+            .column_limits([AutoWidth::default(), AutoWidth::at_most(2)]);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 3));
+        Widget::render(&table, buf.area, &mut buf);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "fruit   n ",
+            "apple   1 ",
+            "banana… 12",
+        ]);
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_truncated_right_aligned() {
+        let cell = Cell::new(Line::from("abcdef").right_aligned());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 1));
+        cell.render_truncated(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["…def"]));
+    }
+}
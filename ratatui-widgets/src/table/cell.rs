@@ -1,3 +1,5 @@
+use std::ops::Range;
+
 use ratatui_core::{
     buffer::Buffer,
     layout::Rect,
@@ -15,6 +17,9 @@ use ratatui_core::{
 ///
 /// You can use [`Text::alignment`] when creating a cell to align its content.
 ///
+/// A cell can span several columns with [`Cell::column_span`], e.g. to group columns in a header or
+/// to merge cells in a footer, and several rows with [`Cell::row_span`].
+///
 /// # Examples
 ///
 /// You can create a `Cell` from anything that can be converted to a [`Text`].
@@ -50,10 +55,23 @@ use ratatui_core::{
 /// [`Row`]: super::Row
 /// [`Table`]: super::Table
 /// [`Stylize`]: ratatui_core::style::Stylize
-#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub struct Cell<'a> {
     content: Text<'a>,
     style: Style,
+    column_span: u16,
+    row_span: u16,
+}
+
+impl Default for Cell<'_> {
+    fn default() -> Self {
+        Self {
+            content: Text::default(),
+            style: Style::default(),
+            column_span: 1,
+            row_span: 1,
+        }
+    }
 }
 
 impl<'a> Cell<'a> {
@@ -84,7 +102,7 @@ impl<'a> Cell<'a> {
     {
         Self {
             content: content.into(),
-            style: Style::default(),
+            ..Self::default()
         }
     }
 
@@ -158,6 +176,71 @@ impl<'a> Cell<'a> {
         self.style = style.into();
         self
     }
+
+    /// Set the number of columns the cell spans
+    ///
+    /// The cell takes the place of `span` cells in the row, and its area covers the columns and the
+    /// spacing between them. A span of `0` is the same as `1`.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Cell, Row};
+    ///
+    /// // a header grouping the columns of a table with 3 columns
+    /// let header = Row::new([Cell::new("Host"), Cell::new("Network").column_span(2)]);
+    /// let subheader = Row::new(["", "RX", "TX"]);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn column_span(mut self, span: u16) -> Self {
+        self.column_span = if span == 0 { 1 } else { span };
+        self
+    }
+
+    /// Set the number of rows the cell spans
+    ///
+    /// The area of the cell extends over the same columns of the following rows, whose cells in
+    /// these columns should be left empty, e.g. with [`Cell::default`]. The cell is only displayed
+    /// while its first row is visible. A span of `0` is the same as `1`.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Cell, Row};
+    ///
+    /// let rows = [
+    ///     Row::new([Cell::new("Fruits").row_span(2), Cell::new("Apple")]),
+    ///     Row::new([Cell::default(), Cell::new("Banana")]),
+    /// ];
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn row_span(mut self, span: u16) -> Self {
+        self.row_span = if span == 0 { 1 } else { span };
+        self
+    }
+
+    /// Returns the content of the cell
+    ///
+    /// This is useful to compare cells when sorting a [`Table`], see [`Comparator`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::Cell;
+    ///
+    /// let cell = Cell::new("42");
+    /// assert_eq!(cell.text().to_string(), "42");
+    /// ```
+    ///
+    /// [`Table`]: super::Table
+    /// [`Comparator`]: super::Comparator
+    pub const fn text(&self) -> &Text<'a> {
+        &self.content
+    }
 }
 
 impl Cell<'_> {
@@ -165,6 +248,41 @@ impl Cell<'_> {
         buf.set_style(area, self.style);
         Widget::render(&self.content, area, buf);
     }
+
+    /// Returns the number of columns the cell spans.
+    pub(crate) const fn columns(&self) -> u16 {
+        self.column_span
+    }
+
+    /// Returns the number of rows the cell spans.
+    pub(crate) const fn rows(&self) -> u16 {
+        self.row_span
+    }
+}
+
+/// Returns the cells of a row with the range of columns they span and their area in the row
+///
+/// The cells that start after the last column are dropped, and the spans are cut at the last
+/// column.
+pub(crate) fn layout_cells<'r, 'a>(
+    cells: &'r [Cell<'a>],
+    columns_widths: &'r [(u16, u16)],
+    area: Rect,
+) -> impl Iterator<Item = (&'r Cell<'a>, Range<usize>, Rect)> + 'r {
+    let mut column = 0;
+    cells.iter().map_while(move |cell| {
+        let start = column;
+        column += usize::from(cell.column_span);
+        let end = column.min(columns_widths.len());
+        if start >= end {
+            return None;
+        }
+        let (x, _) = columns_widths[start];
+        let (last_x, last_width) = columns_widths[end - 1];
+        let width = (last_x + last_width).saturating_sub(x);
+        let cell_area = Rect::new(area.x + x, area.y, width, area.height);
+        Some((cell, start..end, cell_area))
+    })
 }
 
 impl<'a, T> From<T> for Cell<'a>
@@ -174,7 +292,7 @@ where
     fn from(content: T) -> Self {
         Self {
             content: content.into(),
-            style: Style::default(),
+            ..Self::default()
         }
     }
 }
@@ -209,6 +327,34 @@ mod tests {
         assert_eq!(cell.content, Text::from(""));
     }
 
+    #[test]
+    fn spans() {
+        let cell = Cell::new("").column_span(2).row_span(3);
+        assert_eq!((cell.column_span, cell.row_span), (2, 3));
+        let cell = Cell::default().column_span(0).row_span(0);
+        assert_eq!((cell.column_span, cell.row_span), (1, 1));
+    }
+
+    #[test]
+    fn layout_cells_with_spans() {
+        let cells = [
+            Cell::new("a").column_span(2),
+            Cell::new("b"),
+            Cell::new("c"),
+        ];
+        let widths = [(0, 3), (4, 3), (8, 3)];
+        let areas: Vec<_> = layout_cells(&cells, &widths, Rect::new(1, 2, 11, 1))
+            .map(|(cell, columns, area)| (cell.content.to_string(), columns, area))
+            .collect();
+        assert_eq!(
+            areas,
+            [
+                ("a".into(), 0..2, Rect::new(1, 2, 7, 1)),
+                ("b".into(), 2..3, Rect::new(9, 2, 3, 1)),
+            ]
+        );
+    }
+
     #[test]
     fn style() {
         let style = Style::default().red().italic();
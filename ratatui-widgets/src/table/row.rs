@@ -239,10 +239,42 @@ impl<'a> Row<'a> {
         self.style = style.into();
         self
     }
+
+    /// Returns the cell at the given column, if any
+    ///
+    /// A cell spanning several columns (see [`Cell::column_span`]) is returned for each of the
+    /// columns it covers. This is useful to match rows against a filter query, see [`RowFilter`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{Cell, Row};
+    ///
+    /// let row = Row::new(["Cell 1", "Cell 2"]);
+    /// assert_eq!(row.cell(1), Some(&Cell::new("Cell 2")));
+    /// assert_eq!(row.cell(2), None);
+    /// ```
+    ///
+    /// [`RowFilter`]: super::RowFilter
+    pub fn cell(&self, column: usize) -> Option<&Cell<'a>> {
+        let mut end = 0;
+        self.cells.iter().find(|cell| {
+            end += usize::from(cell.columns());
+            column < end
+        })
+    }
 }
 
 // private methods for rendering
 impl Row<'_> {
+    /// Returns the number of columns spanned by the cells of the row.
+    pub(crate) fn column_count(&self) -> usize {
+        self.cells
+            .iter()
+            .map(|cell| usize::from(cell.columns()))
+            .sum()
+    }
+
     /// Returns the total height of the row.
     pub(crate) const fn height_with_margin(&self) -> u16 {
         self.height
@@ -301,6 +333,14 @@ mod tests {
         assert_eq!(row.cells, cells);
     }
 
+    #[test]
+    fn cell_with_column_span() {
+        let row = Row::new([Cell::new("a").column_span(2), Cell::new("b")]);
+        assert_eq!(row.cell(1), Some(&Cell::new("a").column_span(2)));
+        assert_eq!(row.cell(2), Some(&Cell::new("b")));
+        assert_eq!(row.cell(3), None);
+    }
+
     #[test]
     fn height() {
         let row = Row::default().height(2);
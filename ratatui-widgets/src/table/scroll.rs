@@ -0,0 +1,258 @@
+use ratatui_core::{
+    buffer::Buffer,
+    layout::{Constraint, Rect},
+};
+use unicode_width::UnicodeWidthStr;
+
+use crate::{
+    scrollbar::ScrollbarState,
+    table::{Table, TableState},
+};
+
+/// The columns of a horizontally scrolled table
+pub(crate) struct ScrolledColumns {
+    /// The offset and width of each column, zero wide for the columns scrolled out on the left
+    pub(crate) widths: Vec<(u16, u16)>,
+    /// The offset of the separator between the frozen and the scrolled columns, if displayed
+    pub(crate) separator: Option<u16>,
+}
+
+impl Table<'_> {
+    /// Returns the widths the columns would like to have when they are not squeezed in the area.
+    ///
+    /// This is the width given by the layout of the columns, widened to the minimum of the
+    /// [`Constraint::Length`] and [`Constraint::Min`] constraints, and to the share of the area of
+    /// the [`Constraint::Percentage`] and [`Constraint::Ratio`] constraints.
+    pub(crate) fn get_natural_widths(
+        &self,
+        max_width: u16,
+        selection_width: u16,
+        col_count: usize,
+    ) -> Vec<u16> {
+        let area_width = u64::from(max_width.saturating_sub(selection_width));
+        let share = |numerator: u64, denominator: u64| {
+            let width = area_width * numerator / denominator.max(1);
+            u16::try_from(width).unwrap_or(u16::MAX)
+        };
+        self.get_columns_widths(max_width, selection_width, col_count)
+            .into_iter()
+            .enumerate()
+            .map(|(column, (_x, width))| match self.widths.get(column) {
+                Some(Constraint::Length(min) | Constraint::Min(min)) => width.max(*min),
+                Some(Constraint::Percentage(percent)) => width.max(share(u64::from(*percent), 100)),
+                Some(Constraint::Ratio(numerator, denominator)) => {
+                    width.max(share(u64::from(*numerator), u64::from(*denominator)))
+                }
+                _ => width,
+            })
+            .collect()
+    }
+
+    /// Places the columns in the area, the frozen columns first, then the separator and the other
+    /// columns from the column offset of the state.
+    ///
+    /// The column offset is clamped so that the table doesn't scroll past its last column, and
+    /// moved so that the selected column is visible. The scrollbar state of the columns is updated.
+    pub(crate) fn scroll_columns(
+        &self,
+        widths: &[u16],
+        max_width: u16,
+        selection_width: u16,
+        state: &mut TableState,
+    ) -> ScrolledColumns {
+        let count = widths.len();
+        let frozen = self.frozen_columns.min(count);
+        let spacing = self.column_spacing;
+        let mut x = selection_width.min(max_width);
+        let place = |x: &mut u16, width: u16| {
+            // columns that don't fit in the area are cut
+            let width = width.min(max_width - *x);
+            let column = (*x, width);
+            *x = x
+                .saturating_add(width)
+                .saturating_add(spacing)
+                .min(max_width);
+            column
+        };
+
+        let mut columns: Vec<(u16, u16)> = widths[..frozen]
+            .iter()
+            .map(|&width| place(&mut x, width))
+            .collect();
+        let separator_width = u16::try_from(self.frozen_separator.width()).unwrap_or(u16::MAX);
+        let separator = (frozen > 0 && frozen < count && separator_width > 0 && x < max_width)
+            .then(|| {
+                let separator = x;
+                x = x
+                    .saturating_add(separator_width)
+                    .saturating_add(spacing)
+                    .min(max_width);
+                separator
+            });
+
+        let available = u32::from(max_width - x);
+        // whether the columns from `first` to `last` fit in the area
+        let fits = |first: usize, last: usize| {
+            let total: u32 = widths[first..=last]
+                .iter()
+                .map(|&width| u32::from(width) + u32::from(spacing))
+                .sum();
+            total - u32::from(spacing) <= available
+        };
+        // don't scroll further than needed to display the last column
+        let mut max_offset = count.saturating_sub(1).max(frozen);
+        while max_offset > frozen && fits(max_offset - 1, count - 1) {
+            max_offset -= 1;
+        }
+        let mut offset = state.column_offset.clamp(frozen, max_offset);
+        if let Some(selected) = state.selected_column.filter(|&s| s >= frozen && s < count) {
+            offset = offset.min(selected);
+            while offset < selected && !fits(offset, selected) {
+                offset += 1;
+            }
+        }
+        state.column_offset = offset;
+
+        columns.extend((frozen..offset).map(|_| (x, 0)));
+        let mut visible = 0;
+        for &width in &widths[offset..] {
+            let column = place(&mut x, width);
+            if column.1 == width && width > 0 {
+                visible += 1;
+            }
+            columns.push(column);
+        }
+        state.column_scrollbar = if count > frozen {
+            ScrollbarState::new(max_offset - frozen + 1)
+                .position(offset - frozen)
+                .viewport_content_length(visible.max(1))
+        } else {
+            ScrollbarState::default()
+        };
+
+        ScrolledColumns {
+            widths: columns,
+            separator,
+        }
+    }
+
+    /// Renders the separator between the frozen and the scrolled columns over the height of the
+    /// table.
+    pub(crate) fn render_frozen_separator(&self, x: u16, area: Rect, buf: &mut Buffer) {
+        let x = area.x + x;
+        for y in area.top()..area.bottom() {
+            buf.set_stringn(
+                x,
+                y,
+                self.frozen_separator,
+                usize::from(area.right() - x),
+                self.style,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use ratatui_core::widgets::StatefulWidget;
+
+    use super::*;
+    use crate::table::Row;
+
+    fn table() -> Table<'static> {
+        let rows = [
+            Row::new(["id", "alpha", "beta", "gamma", "delta"]),
+            Row::new(["1", "a1", "b1", "c1", "d1"]),
+        ];
+        Table::new(rows, [2, 5, 5, 5, 5].map(Constraint::Length))
+            .horizontal_scroll(true)
+            .frozen_columns(1)
+    }
+
+    fn render(table: &Table, state: &mut TableState) -> Buffer {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 16, 2));
+        StatefulWidget::render(table, buf.area, &mut buf, state);
+        buf
+    }
+
+    #[test]
+    fn natural_widths() {
+        let table = Table::default().widths([
+            Constraint::Length(8),
+            Constraint::Min(6),
+            Constraint::Percentage(50),
+            Constraint::Ratio(1, 5),
+        ]);
+        assert_eq!(table.get_natural_widths(10, 0, 4), [8, 6, 5, 2]);
+    }
+
+    #[test]
+    fn frozen_columns() {
+        let mut state = TableState::new();
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "id │ alpha beta ",
+            "1  │ a1    b1   ",
+        ]);
+        assert_eq!(render(&table(), &mut state), expected);
+        assert_eq!(
+            state.column_scrollbar_state(),
+            ScrollbarState::new(3).viewport_content_length(2)
+        );
+
+        state = state.with_column_offset(3);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "id │ gamma delta",
+            "1  │ c1    d1   ",
+        ]);
+        assert_eq!(render(&table(), &mut state), expected);
+        assert_eq!(
+            state.column_scrollbar_state(),
+            ScrollbarState::new(3)
+                .position(2)
+                .viewport_content_length(2)
+        );
+    }
+
+    #[test]
+    fn offset_is_clamped() {
+        let mut state = TableState::new().with_column_offset(10);
+        render(&table(), &mut state);
+        assert_eq!(state.column_offset(), 3);
+
+        // the frozen columns can't be scrolled
+        let mut state = TableState::new().with_column_offset(0);
+        render(&table(), &mut state);
+        assert_eq!(state.column_offset(), 1);
+    }
+
+    #[test]
+    fn selected_column_is_visible() {
+        let mut state = TableState::new().with_selected_column(Some(4));
+        render(&table(), &mut state);
+        assert_eq!(state.column_offset(), 3);
+
+        state.select_column(Some(2));
+        render(&table(), &mut state);
+        assert_eq!(state.column_offset(), 2);
+
+        // a frozen column is always visible
+        state.select_column(Some(0));
+        render(&table(), &mut state);
+        assert_eq!(state.column_offset(), 2);
+    }
+
+    #[test]
+    fn last_column_is_cut() {
+        let table = table().frozen_separator("");
+        let mut state = TableState::new();
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "id alpha beta  g",
+            "1  a1    b1    c",
+        ]);
+        assert_eq!(render(&table, &mut state), expected);
+    }
+}
@@ -0,0 +1,419 @@
+use std::{
+    cmp::{Ordering, Reverse},
+    fmt,
+    hash::{Hash, Hasher},
+    sync::Arc,
+};
+
+use strum::{Display, EnumString};
+
+use crate::table::{source::Rows, Cell, Row, Table, TableState};
+
+/// The order in which the rows of a [`Table`] are sorted
+///
+/// See [`TableState::sort_by`].
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SortOrder {
+    /// The smallest value first
+    #[default]
+    Ascending,
+    /// The largest value first
+    Descending,
+}
+
+impl SortOrder {
+    /// Returns the opposite order.
+    #[must_use = "method returns the reversed order without modifying self"]
+    pub const fn reversed(self) -> Self {
+        match self {
+            Self::Ascending => Self::Descending,
+            Self::Descending => Self::Ascending,
+        }
+    }
+}
+
+/// A function comparing two cells of a column, used to sort the rows of a [`Table`]
+///
+/// The default comparator compares the text of the cells. Use a custom comparator for columns
+/// holding non-string data, e.g. numbers or dates. See [`Table::sort_comparator`].
+pub type Comparator = Arc<dyn Fn(&Cell<'_>, &Cell<'_>) -> Ordering + Send + Sync>;
+
+/// A function deciding whether a row matches the filter query of a [`Table`]
+///
+/// The default filter keeps the rows with a cell containing the query, ignoring case. See
+/// [`Table::filter_fn`].
+pub type RowFilter = Arc<dyn Fn(&Row<'_>, &str) -> bool + Send + Sync>;
+
+/// The comparators of the columns of a table.
+///
+/// Two tables have equal comparators if they share the same functions, i.e. one table is a clone
+/// of the other.
+#[derive(Default, Clone)]
+pub(crate) struct Comparators(Vec<(usize, Comparator)>);
+
+impl Comparators {
+    pub(crate) fn set(&mut self, column: usize, comparator: Comparator) {
+        self.0.retain(|(c, _)| *c != column);
+        self.0.push((column, comparator));
+    }
+
+    fn get(&self, column: usize) -> Option<&Comparator> {
+        self.0
+            .iter()
+            .find(|(c, _)| *c == column)
+            .map(|(_, comparator)| comparator)
+    }
+}
+
+impl fmt::Debug for Comparators {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.0.iter().map(|(column, _)| column))
+            .finish()
+    }
+}
+
+impl PartialEq for Comparators {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.len() == other.0.len()
+            && self
+                .0
+                .iter()
+                .zip(&other.0)
+                .all(|((a, f), (b, g))| a == b && Arc::ptr_eq(f, g))
+    }
+}
+
+impl Eq for Comparators {}
+
+impl Hash for Comparators {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for (column, comparator) in &self.0 {
+            column.hash(state);
+            Arc::as_ptr(comparator).cast::<()>().hash(state);
+        }
+    }
+}
+
+/// The filter of a table, compared like [`Comparators`].
+#[derive(Clone)]
+pub(crate) struct Filter(pub(crate) RowFilter);
+
+impl Default for Filter {
+    fn default() -> Self {
+        Self(Arc::new(contains_text))
+    }
+}
+
+impl fmt::Debug for Filter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Filter(..)")
+    }
+}
+
+impl PartialEq for Filter {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for Filter {}
+
+impl Hash for Filter {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Arc::as_ptr(&self.0).cast::<()>().hash(state);
+    }
+}
+
+fn contains_text(row: &Row<'_>, query: &str) -> bool {
+    let query = query.to_lowercase();
+    row.cells
+        .iter()
+        .any(|cell| cell.text().to_string().to_lowercase().contains(&query))
+}
+
+impl Table<'_> {
+    /// Returns the indexes of the rows to display, in order, or `None` if the rows are neither
+    /// sorted nor filtered.
+    pub(crate) fn view(&self, state: &TableState) -> Option<Vec<usize>> {
+        let query = state.filter.as_deref().filter(|query| !query.is_empty());
+        if state.sort.is_none() && query.is_none() {
+            return None;
+        }
+        let mut view: Vec<usize> = match query {
+            Some(query) => (0..self.rows.len())
+                .filter(|&i| (self.filter.0)(&self.rows[i], query))
+                .collect(),
+            None => (0..self.rows.len()).collect(),
+        };
+        if let Some((column, order)) = state.sort {
+            let empty = Cell::default();
+            // rows without a cell in the sorted column are sorted as if the cell was empty
+            let cell = |i: usize| self.rows[i].cell(column).unwrap_or(&empty);
+            // the sorts are stable, so rows with equal cells keep their order in both directions
+            match (self.comparators.get(column), order) {
+                (Some(comparator), SortOrder::Ascending) => {
+                    view.sort_by(|&a, &b| comparator(cell(a), cell(b)));
+                }
+                (Some(comparator), SortOrder::Descending) => {
+                    view.sort_by(|&a, &b| comparator(cell(b), cell(a)));
+                }
+                (None, SortOrder::Ascending) => {
+                    view.sort_by_cached_key(|&i| cell(i).text().to_string());
+                }
+                (None, SortOrder::Descending) => {
+                    view.sort_by_cached_key(|&i| Reverse(cell(i).text().to_string()));
+                }
+            }
+        }
+        Some(view)
+    }
+}
+
+/// The rows of a table in the order given by a view
+pub(crate) struct ViewRows<'a, 'b> {
+    pub(crate) rows: &'a [Row<'b>],
+    pub(crate) view: &'a [usize],
+}
+
+impl Rows for ViewRows<'_, '_> {
+    fn len(&self) -> usize {
+        self.view.len()
+    }
+
+    fn height(&self, index: usize) -> u16 {
+        self.rows[self.view[index]].height
+    }
+
+    fn height_with_margin(&self, index: usize) -> u16 {
+        self.rows[self.view[index]].height_with_margin()
+    }
+
+    fn with_row<R>(&self, index: usize, f: impl FnOnce(&Row<'_>) -> R) -> R {
+        f(&self.rows[self.view[index]])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use ratatui_core::{
+        buffer::Buffer,
+        layout::{Constraint, Rect},
+        widgets::StatefulWidget,
+    };
+
+    use super::*;
+
+    fn table() -> Table<'static> {
+        let rows = [
+            Row::new(["b.rs", "120"]),
+            Row::new(["c.txt", "9"]),
+            Row::new(["a.rs", "30"]),
+        ];
+        Table::new(rows, [Constraint::Length(5), Constraint::Length(4)])
+            .header(Row::new(["name", "size"]))
+            .highlight_symbol(">")
+    }
+
+    fn render(table: &Table, state: &mut TableState) -> Buffer {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 11, 4));
+        StatefulWidget::render(table, buf.area, &mut buf, state);
+        buf
+    }
+
+    fn size(cell: &Cell) -> u32 {
+        cell.text().to_string().parse().unwrap()
+    }
+
+    #[test]
+    fn reversed() {
+        assert_eq!(SortOrder::Ascending.reversed(), SortOrder::Descending);
+        assert_eq!(SortOrder::Descending.reversed(), SortOrder::Ascending);
+    }
+
+    #[test]
+    fn sort_by_text() {
+        let mut state = TableState::new().with_sort(0, SortOrder::Ascending);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "name▲ size ",
+            "a.rs  30   ",
+            "b.rs  120  ",
+            "c.txt 9    ",
+        ]);
+        assert_eq!(render(&table(), &mut state), expected);
+        assert_eq!(state.visible_rows(), Some([2, 0, 1].as_slice()));
+
+        state.toggle_sort(0);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "name▼ size ",
+            "c.txt 9    ",
+            "b.rs  120  ",
+            "a.rs  30   ",
+        ]);
+        assert_eq!(render(&table(), &mut state), expected);
+    }
+
+    #[test]
+    fn sort_with_comparator() {
+        let table = table().sort_comparator(1, |a, b| size(a).cmp(&size(b)));
+        let mut state = TableState::new().with_sort(1, SortOrder::Descending);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "name  siz▼ ",
+            "b.rs  120  ",
+            "a.rs  30   ",
+            "c.txt 9    ",
+        ]);
+        assert_eq!(render(&table, &mut state), expected);
+    }
+
+    #[test]
+    fn sort_with_column_span() {
+        let rows = [
+            Row::new(["b.rs", "x", "120"]),
+            Row::new([Cell::new("zz").column_span(2), Cell::new("1")]),
+            Row::new(["a.rs", "y", "30"]),
+        ];
+        let table = Table::new(rows, [Constraint::Length(4); 3])
+            .sort_comparator(2, |a, b| size(a).cmp(&size(b)));
+        // the third cell of the spanning row is in the third column
+        let mut state = TableState::new().with_sort(2, SortOrder::Descending);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 14, 3));
+        StatefulWidget::render(&table, buf.area, &mut buf, &mut state);
+        assert_eq!(state.visible_rows(), Some([0, 2, 1].as_slice()));
+
+        // the second column is covered by the spanning cell
+        state.sort_by(1, SortOrder::Ascending);
+        StatefulWidget::render(&table, buf.area, &mut buf, &mut state);
+        assert_eq!(state.visible_rows(), Some([0, 2, 1].as_slice()));
+    }
+
+    #[test]
+    fn sort_symbols() {
+        let table = table().sort_symbols("+", "-");
+        let mut state = TableState::new().with_sort(1, SortOrder::Ascending);
+        let buf = render(&table, &mut state);
+        assert_eq!(buf[(9, 0)].symbol(), "+");
+    }
+
+    #[test]
+    fn filter() {
+        let mut state = TableState::new().with_filter("RS");
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "name  size ",
+            "b.rs  120  ",
+            "a.rs  30   ",
+            "           ",
+        ]);
+        assert_eq!(render(&table(), &mut state), expected);
+        assert_eq!(state.visible_rows(), Some([0, 2].as_slice()));
+
+        state.set_filter(Some(""));
+        render(&table(), &mut state);
+        assert_eq!(state.visible_rows(), None);
+    }
+
+    #[test]
+    fn filter_fn() {
+        let table = table().filter_fn(|row, query| {
+            row.cell(0)
+                .is_some_and(|cell| cell.text().to_string().starts_with(query))
+        });
+        let mut state = TableState::new().with_filter("a");
+        render(&table, &mut state);
+        assert_eq!(state.visible_rows(), Some([2].as_slice()));
+    }
+
+    #[test]
+    fn capturing_functions() {
+        let extensions = [".txt".to_string()];
+        let priority = ["c.txt", "a.rs", "b.rs"];
+        let rank = move |cell: &Cell| priority.iter().position(|p| cell.text().to_string() == *p);
+        let table = table()
+            .sort_comparator(0, move |a, b| rank(a).cmp(&rank(b)))
+            .filter_fn(move |row, _| {
+                row.cell(0).is_some_and(|cell| {
+                    let name = cell.text().to_string();
+                    !extensions.iter().any(|extension| name.ends_with(extension))
+                })
+            });
+        let mut state = TableState::new()
+            .with_sort(0, SortOrder::Ascending)
+            .with_filter("*");
+        render(&table, &mut state);
+        assert_eq!(state.visible_rows(), Some([2, 0].as_slice()));
+
+        // clones share the functions, so they are equal
+        assert_eq!(table.clone(), table);
+        assert_ne!(table.clone().filter_fn(|_, _| true), table);
+    }
+
+    #[test]
+    fn selection_is_stable() {
+        let mut state = TableState::new()
+            .with_selected(Some(0))
+            .with_sort(0, SortOrder::Ascending);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            " name▲ size",
+            " a.rs  30  ",
+            ">b.rs  120 ",
+            " c.txt 9   ",
+        ]);
+        assert_eq!(render(&table(), &mut state), expected);
+        assert_eq!(state.selected(), Some(0));
+
+        // the selection follows the displayed order
+        state.select_next();
+        assert_eq!(state.selected(), Some(1));
+        state.select_next();
+        assert_eq!(state.selected(), Some(1));
+        state.select_previous();
+        state.select_previous();
+        assert_eq!(state.selected(), Some(2));
+        state.select_last();
+        assert_eq!(state.selected(), Some(1));
+        state.select_first();
+        assert_eq!(state.selected(), Some(2));
+    }
+
+    #[test]
+    fn marks_follow_rows() {
+        let table = table().mark_symbol("*");
+        let mut state = TableState::new()
+            .with_marked([0, 1])
+            .with_sort(0, SortOrder::Ascending)
+            .with_filter("rs");
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            " name▲ size",
+            " a.rs  30  ",
+            "*b.rs  120 ",
+            "           ",
+        ]);
+        assert_eq!(render(&table, &mut state), expected);
+        // the mark of the filtered out row is kept
+        assert_eq!(state.marks().iter().collect::<Vec<_>>(), [0, 1]);
+    }
+
+    #[test]
+    fn filtered_out_selection_is_cleared() {
+        let mut state = TableState::new().with_selected(Some(1)).with_filter("rs");
+        render(&table(), &mut state);
+        assert_eq!(state.selected(), None);
+    }
+
+    #[test]
+    fn select_last_before_render() {
+        let mut state = TableState::new().with_sort(1, SortOrder::Descending);
+        state.select_last();
+        render(&table(), &mut state);
+        // sorted by text, "9" is the first row in descending order and "120" is the last
+        assert_eq!(state.selected(), Some(0));
+    }
+}
@@ -0,0 +1,386 @@
+use ratatui_core::{
+    buffer::Buffer,
+    layout::Rect,
+    widgets::{StatefulWidget, Widget},
+};
+
+use crate::table::{Row, Table, TableState};
+
+/// A lazy source of rows for a [`Table`]
+///
+/// A source only has to know how many rows there are and to produce the rows that are displayed,
+/// which allows rendering tables of millions of rows without building a [`Row`] for each of them
+/// on every frame. When rendering, [`TableSource::row`] is only called for the rows in the visible
+/// window, and [`TableSource::row_height`] for the rows around the offset and the selected row
+/// that are needed to compute that window.
+///
+/// As the rows are not all known, the number of columns of the table is the number of
+/// [`Table::widths`], or the number of cells in the header or the footer if it is larger.
+///
+/// Use [`Table::source`] to render a table from a source.
+///
+/// The sort order and the filter of the [`TableState`] (see [`TableState::sort_by`] and
+/// [`TableState::set_filter`]) are not applied to a source, as this would require building all of
+/// its rows. The source is expected to return its rows already sorted and filtered, e.g. according
+/// to [`TableState::sort`] and [`TableState::filter`]. The header still displays the symbol of the
+/// sorted column.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     layout::{Constraint, Rect},
+///     widgets::{Row, Table, TableSource, TableState},
+///     Frame,
+/// };
+///
+/// struct Squares(usize);
+///
+/// impl TableSource for Squares {
+///     fn len(&self) -> usize {
+///         self.0
+///     }
+///
+///     fn row(&self, index: usize) -> Row<'_> {
+///         Row::new([index.to_string(), (index * index).to_string()])
+///     }
+/// }
+///
+/// # fn ui(frame: &mut Frame) {
+/// # let area = Rect::default();
+/// let mut state = TableState::default();
+/// state.select_last();
+/// let widths = [Constraint::Length(10), Constraint::Fill(1)];
+/// let table = Table::default()
+///     .widths(widths)
+///     .header(Row::new(["n", "n²"]))
+///     .source(Squares(1_000_000));
+/// frame.render_stateful_widget(table, area, &mut state);
+/// # }
+/// ```
+pub trait TableSource {
+    /// Returns the number of rows.
+    fn len(&self) -> usize;
+
+    /// Returns true if the source contains no rows.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the row at the given index, which is always lower than [`TableSource::len`].
+    fn row(&self, index: usize) -> Row<'_>;
+
+    /// Returns the height of the row at the given index, including its top and bottom margins.
+    ///
+    /// The default implementation builds the row to measure it. Override it if the height is
+    /// known without building the row, e.g. when all the rows have the same height.
+    fn row_height(&self, index: usize) -> u16 {
+        self.row(index).height_with_margin()
+    }
+}
+
+impl<S: TableSource + ?Sized> TableSource for &S {
+    fn len(&self) -> usize {
+        S::len(self)
+    }
+
+    fn is_empty(&self) -> bool {
+        S::is_empty(self)
+    }
+
+    fn row(&self, index: usize) -> Row<'_> {
+        S::row(self, index)
+    }
+
+    fn row_height(&self, index: usize) -> u16 {
+        S::row_height(self, index)
+    }
+}
+
+/// A [`Table`] that renders its rows from a [`TableSource`]
+///
+/// This is created with [`Table::source`]. The rows of the table are ignored, everything else
+/// (header, footer, widths, block, styles, highlight symbol...) is used as is.
+///
+/// It is a [`StatefulWidget`] that works with the same [`TableState`] as the [`Table`], so the
+/// selection and the offset can be moved with the usual methods, e.g. [`TableState::select_last`].
+/// The sort order and the filter of the state are not applied, see [`TableSource`].
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct VirtualTable<'a, S> {
+    table: Table<'a>,
+    source: S,
+}
+
+impl<'a> Table<'a> {
+    /// Renders the table from a lazy [`TableSource`] instead of its rows
+    ///
+    /// Only the visible rows are queried from the source, so this is suitable for tables with a
+    /// very large number of rows. The rows of the table are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::{
+    ///     layout::Constraint,
+    ///     widgets::{Row, Table, TableSource},
+    /// };
+    ///
+    /// struct Records(Vec<(String, u32)>);
+    ///
+    /// impl TableSource for Records {
+    ///     fn len(&self) -> usize {
+    ///         self.0.len()
+    ///     }
+    ///
+    ///     fn row(&self, index: usize) -> Row<'_> {
+    ///         let (name, age) = &self.0[index];
+    ///         Row::new([name.clone(), age.to_string()])
+    ///     }
+    /// }
+    ///
+    /// let records = Records(vec![("Alice".into(), 32), ("Bob".into(), 27)]);
+    /// let table = Table::default()
+    ///     .widths([Constraint::Length(10), Constraint::Length(3)])
+    ///     .source(&records);
+    /// ```
+    pub const fn source<S: TableSource>(self, source: S) -> VirtualTable<'a, S> {
+        VirtualTable {
+            table: self,
+            source,
+        }
+    }
+}
+
+impl<S: TableSource> VirtualTable<'_, S> {
+    /// Returns the source of the rows.
+    pub const fn source(&self) -> &S {
+        &self.source
+    }
+
+    fn column_count(&self) -> usize {
+        self.table
+            .header
+            .iter()
+            .chain(self.table.footer.iter())
+            .map(Row::column_count)
+            .chain([self.table.widths.len()])
+            .max()
+            .unwrap_or_default()
+    }
+}
+
+impl<S: TableSource> Widget for VirtualTable<'_, S> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl<S: TableSource> Widget for &VirtualTable<'_, S> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut state = TableState::default();
+        StatefulWidget::render(self, area, buf, &mut state);
+    }
+}
+
+impl<S: TableSource> StatefulWidget for VirtualTable<'_, S> {
+    type State = TableState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidget::render(&self, area, buf, state);
+    }
+}
+
+impl<S: TableSource> StatefulWidget for &VirtualTable<'_, S> {
+    type State = TableState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        self.table.render_table(
+            &SourceRows(&self.source),
+            self.column_count(),
+            area,
+            buf,
+            state,
+        );
+    }
+}
+
+/// The rows rendered by a table, either a slice of [`Row`]s or a [`TableSource`]
+pub(crate) trait Rows {
+    /// Returns the number of rows.
+    fn len(&self) -> usize;
+
+    /// Returns the height of the row at the given index, without its margins.
+    fn height(&self, index: usize) -> u16;
+
+    /// Returns the height of the row at the given index, including its margins.
+    fn height_with_margin(&self, index: usize) -> u16;
+
+    /// Returns the height of the given row, which is the row at the given index, without its
+    /// margins.
+    fn row_height(&self, _index: usize, row: &Row<'_>) -> u16 {
+        row.height
+    }
+
+    /// Calls `f` with the row at the given index.
+    fn with_row<R>(&self, index: usize, f: impl FnOnce(&Row<'_>) -> R) -> R;
+}
+
+impl Rows for [Row<'_>] {
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    fn height(&self, index: usize) -> u16 {
+        self[index].height
+    }
+
+    fn height_with_margin(&self, index: usize) -> u16 {
+        self[index].height_with_margin()
+    }
+
+    fn with_row<R>(&self, index: usize, f: impl FnOnce(&Row<'_>) -> R) -> R {
+        f(&self[index])
+    }
+}
+
+struct SourceRows<'a, S: ?Sized>(&'a S);
+
+impl<S: TableSource + ?Sized> Rows for SourceRows<'_, S> {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// The height of a row of a source always includes its margins, so that the row doesn't have
+    /// to be built to know it.
+    fn height(&self, index: usize) -> u16 {
+        self.0.row_height(index)
+    }
+
+    fn height_with_margin(&self, index: usize) -> u16 {
+        self.0.row_height(index)
+    }
+
+    fn with_row<R>(&self, index: usize, f: impl FnOnce(&Row<'_>) -> R) -> R {
+        f(&self.0.row(index))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use pretty_assertions::assert_eq;
+    use ratatui_core::layout::Constraint;
+
+    use super::*;
+    use crate::table::SortOrder;
+
+    /// A source of single line rows that records which rows were built
+    struct Squares {
+        len: usize,
+        built: RefCell<Vec<usize>>,
+    }
+
+    impl Squares {
+        const fn new(len: usize) -> Self {
+            Self {
+                len,
+                built: RefCell::new(Vec::new()),
+            }
+        }
+    }
+
+    impl TableSource for Squares {
+        fn len(&self) -> usize {
+            self.len
+        }
+
+        fn row(&self, index: usize) -> Row<'_> {
+            self.built.borrow_mut().push(index);
+            Row::new([index.to_string(), (index % 100).pow(2).to_string()])
+        }
+
+        fn row_height(&self, _index: usize) -> u16 {
+            1
+        }
+    }
+
+    fn table(source: &Squares) -> VirtualTable<'_, &Squares> {
+        Table::default()
+            .widths([Constraint::Length(7), Constraint::Length(4)])
+            .header(Row::new(["n", "sq"]))
+            .highlight_symbol(">")
+            .source(source)
+    }
+
+    #[test]
+    fn renders_only_visible_rows() {
+        let source = Squares::new(5_000_000);
+        let mut state = TableState::default().with_offset(1_012);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 3));
+        StatefulWidget::render(table(&source), buf.area, &mut buf, &mut state);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "n       sq  ",
+            "1012    144 ",
+            "1013    169 ",
+        ]);
+        assert_eq!(buf, expected);
+        assert_eq!(*source.built.borrow(), [1_012, 1_013]);
+    }
+
+    #[test]
+    fn select_last() {
+        let source = Squares::new(5_000_000);
+        let mut state = TableState::default();
+        state.select_last();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 13, 3));
+        StatefulWidget::render(table(&source), buf.area, &mut buf, &mut state);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            " n       sq  ",
+            " 4999998 9604",
+            ">4999999 9801",
+        ]);
+        assert_eq!(buf, expected);
+        assert_eq!(state.selected(), Some(4_999_999));
+        assert_eq!(state.offset(), 4_999_998);
+        assert_eq!(*source.built.borrow(), [4_999_998, 4_999_999]);
+    }
+
+    #[test]
+    fn column_count_from_widths() {
+        let source = Squares::new(3);
+        let mut state = TableState::default().with_selected_column(Some(5));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 3));
+        StatefulWidget::render(table(&source), buf.area, &mut buf, &mut state);
+        assert_eq!(state.selected_column(), Some(1));
+    }
+
+    #[test]
+    fn sort_and_filter_are_not_applied() {
+        let source = Squares::new(5_000_000);
+        let mut state = TableState::default().with_filter("9");
+        state.sort_by(1, SortOrder::Descending);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 3));
+        StatefulWidget::render(table(&source), buf.area, &mut buf, &mut state);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "n       sq ▼",
+            "0       0   ",
+            "1       1   ",
+        ]);
+        assert_eq!(buf, expected);
+        assert_eq!(*source.built.borrow(), [0, 1]);
+    }
+
+    #[test]
+    fn empty_source() {
+        let source = Squares::new(0);
+        let mut state = TableState::default().with_selected(Some(1));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 3));
+        StatefulWidget::render(table(&source), buf.area, &mut buf, &mut state);
+        assert_eq!(state.selected(), None);
+        assert!(source.built.borrow().is_empty());
+    }
+}
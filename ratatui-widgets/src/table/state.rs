@@ -1,3 +1,5 @@
+use crate::{marks::Marks, scrollbar::ScrollbarState, table::SortOrder};
+
 /// State of a [`Table`] widget
 ///
 /// This state can be used to scroll through the rows and select one of them. When the table is
@@ -5,15 +7,33 @@
 /// table will be shifted to ensure that the selected row is visible. This will modify the
 /// [`TableState`] object passed to the `Frame::render_stateful_widget` method.
 ///
-/// The state consists of two fields:
+/// The state consists of these fields:
 /// - [`offset`]: the index of the first row to be displayed
 /// - [`selected`]: the index of the selected row, which can be `None` if no row is selected
 /// - [`selected_column`]: the index of the selected column, which can be `None` if no column is
 ///   selected
+/// - [`sort`]: the column the rows are sorted by and the [`SortOrder`], if any
+/// - [`filter`]: the query the rows are filtered with, if any
+/// - [`column_offset`]: the index of the first scrolled column displayed, when the table is
+///   scrolled horizontally
+/// - [`marks`]: the indexes of the marked rows, for tables where several rows can be selected
+///
+/// When the rows are sorted or filtered, the selected row is still the index of the row in the
+/// rows given to the [`Table`], so it refers to the same data whatever the order of the rows. The
+/// offset is the index of the first row displayed, in the displayed order.
+///
+/// The selected row is the one the cursor is on, while the marked rows are the ones picked for an
+/// operation, e.g. with the space key. Like the selected row, the marked rows are indexes in the
+/// rows given to the [`Table`], and they are displayed with [`Table::marked_row_style`] and
+/// [`Table::mark_symbol`].
 ///
 /// [`offset`]: TableState::offset()
 /// [`selected`]: TableState::selected()
 /// [`selected_column`]: TableState::selected_column()
+/// [`sort`]: TableState::sort()
+/// [`filter`]: TableState::filter()
+/// [`column_offset`]: TableState::column_offset()
+/// [`marks`]: TableState::marks()
 ///
 /// See the `table` example and the `recipe` and `traceroute` tabs in the demo2 example in the
 /// [Examples] directory for a more in depth example of the various configuration options and for
@@ -50,14 +70,52 @@
 /// Note that if [`Table::widths`] is not called before rendering, the rendered columns will have
 /// equal width.
 ///
+/// A cell spanning several columns is selected as a whole: when the selected column is in the
+/// middle of a span, it is moved to the first column of the span when the table is rendered.
+///
 /// [`Table`]: super::Table
 /// [`Table::widths`]: crate::table::Table::widths
+/// [`Table::marked_row_style`]: crate::table::Table::marked_row_style
+/// [`Table::mark_symbol`]: crate::table::Table::mark_symbol
 #[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TableState {
     pub(crate) offset: usize,
     pub(crate) selected: Option<usize>,
     pub(crate) selected_column: Option<usize>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub(crate) sort: Option<(usize, SortOrder)>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub(crate) filter: Option<String>,
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "is_zero"))]
+    pub(crate) column_offset: usize,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Marks::is_empty")
+    )]
+    pub(crate) marks: Marks,
+    /// The indexes of the displayed rows, in order, as of the last render if they were sorted or
+    /// filtered
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) view: Option<Vec<usize>>,
+    /// The columns spanned by the selected cell as of the last render
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) selected_column_span: Option<(usize, usize)>,
+    /// The position of the scrolled columns as of the last render
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) column_scrollbar: ScrollbarState,
+}
+
+#[cfg(feature = "serde")]
+#[allow(clippy::trivially_copy_pass_by_ref)] // the signature is required by serde
+const fn is_zero(value: &usize) -> bool {
+    *value == 0
 }
 
 impl TableState {
@@ -75,6 +133,13 @@ impl TableState {
             offset: 0,
             selected: None,
             selected_column: None,
+            sort: None,
+            filter: None,
+            column_offset: 0,
+            marks: Marks::new(),
+            view: None,
+            selected_column_span: None,
+            column_scrollbar: ScrollbarState::new(0),
         }
     }
 
@@ -95,6 +160,44 @@ impl TableState {
         self
     }
 
+    /// Sets the indexes of the marked rows
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let state = TableState::new().with_marked([1, 3]);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_marked<I: IntoIterator<Item = usize>>(mut self, marked: I) -> Self {
+        self.marks = marked.into_iter().collect();
+        self
+    }
+
+    /// Sets the index of the first scrolled column to be displayed
+    ///
+    /// This only has an effect on tables scrolled horizontally, see [`Table::horizontal_scroll`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let state = TableState::new().with_column_offset(2);
+    /// ```
+    ///
+    /// [`Table::horizontal_scroll`]: crate::table::Table::horizontal_scroll
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_column_offset(mut self, column_offset: usize) -> Self {
+        self.column_offset = column_offset;
+        self
+    }
+
     /// Sets the index of the selected row
     ///
     /// This is a fluent setter method which must be chained or used as it consumes self
@@ -160,6 +263,40 @@ impl TableState {
         self
     }
 
+    /// Sets the column the rows are sorted by and the sort order
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{SortOrder, TableState};
+    ///
+    /// let state = TableState::new().with_sort(1, SortOrder::Descending);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_sort(mut self, column: usize, order: SortOrder) -> Self {
+        self.sort = Some((column, order));
+        self
+    }
+
+    /// Sets the query the rows are filtered with
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let state = TableState::new().with_filter("foo");
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_filter<T: Into<String>>(mut self, query: T) -> Self {
+        self.set_filter(Some(query));
+        self
+    }
+
     /// Index of the first row to be displayed
     ///
     /// # Examples
@@ -188,6 +325,68 @@ impl TableState {
         &mut self.offset
     }
 
+    /// Index of the first scrolled column to be displayed
+    ///
+    /// The columns are scrolled by whole columns. The offset is never lower than the number of
+    /// frozen columns once the table has been rendered.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let state = TableState::new();
+    /// assert_eq!(state.column_offset(), 0);
+    /// ```
+    pub const fn column_offset(&self) -> usize {
+        self.column_offset
+    }
+
+    /// Mutable reference to the index of the first scrolled column to be displayed
+    ///
+    /// When a column is selected, the offset is moved when rendering to keep it visible, so
+    /// scrolling the selected column with [`TableState::scroll_right_by`] is usually enough.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let mut state = TableState::default();
+    /// *state.column_offset_mut() += 1;
+    /// ```
+    pub fn column_offset_mut(&mut self) -> &mut usize {
+        &mut self.column_offset
+    }
+
+    /// Returns the state of a horizontal [`Scrollbar`] reflecting the scrolled columns
+    ///
+    /// The content length is the number of column offsets the table can be scrolled to, and the
+    /// viewport the number of scrolled columns fully displayed, as of the last render. This is the
+    /// default [`ScrollbarState`] until a table scrolled horizontally is rendered.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::{
+    ///     layout::Rect,
+    ///     widgets::{Scrollbar, ScrollbarOrientation, Table, TableState},
+    ///     Frame,
+    /// };
+    ///
+    /// # fn ui(frame: &mut Frame, table: Table, state: &mut TableState) {
+    /// # let area = Rect::default();
+    /// frame.render_stateful_widget(table, area, state);
+    /// let scrollbar = Scrollbar::new(ScrollbarOrientation::HorizontalBottom);
+    /// frame.render_stateful_widget(scrollbar, area, &mut state.column_scrollbar_state());
+    /// # }
+    /// ```
+    ///
+    /// [`Scrollbar`]: crate::scrollbar::Scrollbar
+    pub const fn column_scrollbar_state(&self) -> ScrollbarState {
+        self.column_scrollbar
+    }
+
     /// Index of the selected row
     ///
     /// Returns `None` if no row is selected
@@ -322,11 +521,217 @@ impl TableState {
         }
     }
 
+    /// Returns the column the rows are sorted by and the sort order, if any
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{SortOrder, TableState};
+    ///
+    /// let state = TableState::new().with_sort(1, SortOrder::Ascending);
+    /// assert_eq!(state.sort(), Some((1, SortOrder::Ascending)));
+    /// ```
+    pub const fn sort(&self) -> Option<(usize, SortOrder)> {
+        self.sort
+    }
+
+    /// Sorts the rows by the given column and order
+    ///
+    /// The header cell of the sorted column displays a symbol matching the order, see
+    /// [`Table::sort_symbols`]. The cells are compared by text, unless a comparator is set for the
+    /// column with [`Table::sort_comparator`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{SortOrder, TableState};
+    ///
+    /// let mut state = TableState::default();
+    /// state.sort_by(2, SortOrder::Descending);
+    /// ```
+    ///
+    /// [`Table::sort_symbols`]: super::Table::sort_symbols
+    /// [`Table::sort_comparator`]: super::Table::sort_comparator
+    pub fn sort_by(&mut self, column: usize, order: SortOrder) {
+        self.sort = Some((column, order));
+    }
+
+    /// Sorts the rows by the given column, as when clicking on a column header
+    ///
+    /// If the rows are already sorted by this column, the order is reversed, otherwise the rows
+    /// are sorted in ascending order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{SortOrder, TableState};
+    ///
+    /// let mut state = TableState::default();
+    /// state.toggle_sort(1);
+    /// assert_eq!(state.sort(), Some((1, SortOrder::Ascending)));
+    /// state.toggle_sort(1);
+    /// assert_eq!(state.sort(), Some((1, SortOrder::Descending)));
+    /// state.toggle_sort(0);
+    /// assert_eq!(state.sort(), Some((0, SortOrder::Ascending)));
+    /// ```
+    pub fn toggle_sort(&mut self, column: usize) {
+        let order = match self.sort {
+            Some((sorted, order)) if sorted == column => order.reversed(),
+            _ => SortOrder::Ascending,
+        };
+        self.sort = Some((column, order));
+    }
+
+    /// Displays the rows in their original order
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::{SortOrder, TableState};
+    ///
+    /// let mut state = TableState::new().with_sort(1, SortOrder::Ascending);
+    /// state.clear_sort();
+    /// assert_eq!(state.sort(), None);
+    /// ```
+    pub fn clear_sort(&mut self) {
+        self.sort = None;
+    }
+
+    /// Returns the query the rows are filtered with, if any
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let state = TableState::new().with_filter("foo");
+    /// assert_eq!(state.filter(), Some("foo"));
+    /// ```
+    pub fn filter(&self) -> Option<&str> {
+        self.filter.as_deref()
+    }
+
+    /// Sets the query the rows are filtered with
+    ///
+    /// Only the rows matching the query are displayed. By default, a row matches if one of its
+    /// cells contains the query, ignoring case, see [`Table::filter_fn`] to change this. An empty
+    /// query or `None` displays all the rows. If the selected row is filtered out, it is
+    /// deselected when the table is rendered.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let mut state = TableState::default();
+    /// state.set_filter(Some("foo"));
+    /// state.set_filter(None::<String>);
+    /// ```
+    ///
+    /// [`Table::filter_fn`]: super::Table::filter_fn
+    pub fn set_filter<T: Into<String>>(&mut self, query: Option<T>) {
+        self.filter = query.map(Into::into);
+    }
+
+    /// Returns the indexes of the displayed rows, in the displayed order, as of the last render
+    ///
+    /// This is `None` if the rows are neither sorted nor filtered, in which case all the rows are
+    /// displayed in order. This can be used to find the row under the mouse cursor, as the row at
+    /// position `i` from the top of the rows area is `visible_rows[offset + i]`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let state = TableState::default();
+    /// assert_eq!(state.visible_rows(), None);
+    /// ```
+    pub fn visible_rows(&self) -> Option<&[usize]> {
+        self.view.as_deref()
+    }
+
+    /// The marked rows
+    ///
+    /// The indexes that are out of bounds are removed when the table is rendered. The marks of the
+    /// rows that are filtered out are kept.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let state = TableState::new().with_marked([3, 1]);
+    /// assert_eq!(state.marks().iter().collect::<Vec<_>>(), [1, 3]);
+    /// ```
+    pub const fn marks(&self) -> &Marks {
+        &self.marks
+    }
+
+    /// Mutable reference to the marked rows
+    ///
+    /// When the rows are filtered, [`Marks::mark_all`] and [`Marks::invert`] also mark the rows
+    /// that are filtered out. To mark only the displayed rows, extend the marks with the
+    /// [`TableState::visible_rows`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let mut state = TableState::new().with_selected(Some(2));
+    /// if let Some(index) = state.selected() {
+    ///     state.marks_mut().toggle(index);
+    /// }
+    /// match state.visible_rows().map(<[usize]>::to_vec) {
+    ///     Some(rows) => state.marks_mut().extend(rows),
+    ///     None => state.marks_mut().mark_all(3),
+    /// }
+    /// ```
+    pub fn marks_mut(&mut self) -> &mut Marks {
+        &mut self.marks
+    }
+
+    /// Marks the rows from the anchor to the given index, both included, and selects the row at
+    /// the given index
+    ///
+    /// This is [`Marks::mark_range_to`], starting from the selected row if there is no anchor.
+    /// When the rows are sorted or filtered, the range follows the displayed order as of the last
+    /// render.
+    ///
+    /// The index must be lower than the number of rows.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::widgets::TableState;
+    ///
+    /// let mut state = TableState::new().with_selected(Some(2));
+    /// state.mark_range_to(5);
+    /// assert_eq!(state.marks().len(), 4);
+    /// state.mark_range_to(3);
+    /// assert_eq!(state.marks().len(), 2);
+    /// ```
+    pub fn mark_range_to(&mut self, index: usize) {
+        let from = self.selected.unwrap_or(index);
+        self.marks.mark_range(from, index, self.view.as_deref());
+        self.selected = Some(index);
+    }
+
+    /// Returns the position of the selected row in the displayed rows
+    fn selected_position(&self, view: &[usize]) -> Option<usize> {
+        self.selected
+            .and_then(|selected| view.iter().position(|&i| i == selected))
+    }
+
     /// Selects the next row or the first one if no row is selected
     ///
     /// Note: until the table is rendered, the number of rows is not known, so the index is set to
     /// `0` and will be corrected when the table is rendered
     ///
+    /// When the rows are sorted or filtered, this follows the order of the displayed rows as of the
+    /// last render.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -336,6 +741,14 @@ impl TableState {
     /// state.select_next();
     /// ```
     pub fn select_next(&mut self) {
+        if let Some(view) = &self.view {
+            let next = self
+                .selected_position(view)
+                .map_or(0, |i| (i + 1).min(view.len().saturating_sub(1)));
+            let next = view.get(next).copied();
+            self.select(next);
+            return;
+        }
         let next = self.selected.map_or(0, |i| i.saturating_add(1));
         self.select(Some(next));
     }
@@ -345,6 +758,8 @@ impl TableState {
     /// Note: until the table is rendered, the number of columns is not known, so the index is set
     /// to `0` and will be corrected when the table is rendered
     ///
+    /// If the selected cell spans several columns, the column after the span is selected.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -353,7 +768,10 @@ impl TableState {
     /// state.select_next_column();
     /// ```
     pub fn select_next_column(&mut self) {
-        let next = self.selected_column.map_or(0, |i| i.saturating_add(1));
+        let next = match (self.selected_column, self.selected_column_span) {
+            (Some(column), Some((start, end))) if (start..end).contains(&column) => end,
+            (column, _) => column.map_or(0, |i| i.saturating_add(1)),
+        };
         self.select_column(Some(next));
     }
 
@@ -362,6 +780,9 @@ impl TableState {
     /// Note: until the table is rendered, the number of rows is not known, so the index is set to
     /// `usize::MAX` and will be corrected when the table is rendered
     ///
+    /// When the rows are sorted or filtered, this follows the order of the displayed rows as of the
+    /// last render.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -371,12 +792,23 @@ impl TableState {
     /// state.select_previous();
     /// ```
     pub fn select_previous(&mut self) {
+        if let Some(view) = &self.view {
+            let previous = self
+                .selected_position(view)
+                .map_or_else(|| view.len().saturating_sub(1), |i| i.saturating_sub(1));
+            let previous = view.get(previous).copied();
+            self.select(previous);
+            return;
+        }
         let previous = self.selected.map_or(usize::MAX, |i| i.saturating_sub(1));
         self.select(Some(previous));
     }
 
     /// Selects the previous column or the last one if no column is selected
     ///
+    /// If the previous column is spanned by a cell, that cell is selected when the table is
+    /// rendered.
+    ///
     /// Note: until the table is rendered, the number of columns is not known, so the index is set
     /// to `usize::MAX` and will be corrected when the table is rendered
     ///
@@ -399,6 +831,9 @@ impl TableState {
     /// Note: until the table is rendered, the number of rows is not known, so the index is set to
     /// `0` and will be corrected when the table is rendered
     ///
+    /// When the rows are sorted or filtered, this follows the order of the displayed rows as of the
+    /// last render.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -408,7 +843,11 @@ impl TableState {
     /// state.select_first();
     /// ```
     pub fn select_first(&mut self) {
-        self.select(Some(0));
+        let first = self
+            .view
+            .as_ref()
+            .map_or(Some(0), |view| view.first().copied());
+        self.select(first);
     }
 
     /// Selects the first column
@@ -432,6 +871,9 @@ impl TableState {
     /// Note: until the table is rendered, the number of rows is not known, so the index is set to
     /// `usize::MAX` and will be corrected when the table is rendered
     ///
+    /// When the rows are sorted or filtered, this follows the order of the displayed rows as of the
+    /// last render.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -441,7 +883,11 @@ impl TableState {
     /// state.select_last();
     /// ```
     pub fn select_last(&mut self) {
-        self.select(Some(usize::MAX));
+        let last = self
+            .view
+            .as_ref()
+            .map_or(Some(usize::MAX), |view| view.last().copied());
+        self.select(last);
     }
 
     /// Selects the last column
@@ -539,6 +985,28 @@ impl TableState {
 mod tests {
     use super::*;
 
+    fn marked(state: &TableState) -> Vec<usize> {
+        state.marks().iter().collect()
+    }
+
+    #[test]
+    fn mark_range_to() {
+        let mut state = TableState::new().with_selected(Some(3));
+        state.mark_range_to(5);
+        assert_eq!(marked(&state), [3, 4, 5]);
+        assert_eq!(state.selected(), Some(5));
+        state.mark_range_to(1);
+        assert_eq!(marked(&state), [1, 2, 3]);
+    }
+
+    #[test]
+    fn mark_range_to_in_view() {
+        let mut state = TableState::new().with_selected(Some(4));
+        state.view = Some(vec![4, 0, 3, 1]);
+        state.mark_range_to(3);
+        assert_eq!(marked(&state), [0, 3, 4]);
+    }
+
     #[test]
     fn new() {
         let state = TableState::new();
@@ -572,6 +1040,31 @@ mod tests {
         assert_eq!(state.selected_column, None);
     }
 
+    #[test]
+    fn with_sort() {
+        let state = TableState::new().with_sort(1, SortOrder::Descending);
+        assert_eq!(state.sort, Some((1, SortOrder::Descending)));
+    }
+
+    #[test]
+    fn with_filter() {
+        let state = TableState::new().with_filter("foo");
+        assert_eq!(state.filter, Some("foo".into()));
+    }
+
+    #[test]
+    fn toggle_sort() {
+        let mut state = TableState::new();
+        state.toggle_sort(0);
+        assert_eq!(state.sort(), Some((0, SortOrder::Ascending)));
+        state.toggle_sort(0);
+        assert_eq!(state.sort(), Some((0, SortOrder::Descending)));
+        state.toggle_sort(1);
+        assert_eq!(state.sort(), Some((1, SortOrder::Ascending)));
+        state.clear_sort();
+        assert_eq!(state.sort(), None);
+    }
+
     #[test]
     fn offset() {
         let state = TableState::new();
@@ -0,0 +1,193 @@
+use std::{cell::RefCell, collections::HashMap};
+
+use ratatui_core::{
+    buffer::Buffer,
+    layout::{Alignment, Rect},
+    style::{Style, Styled},
+    widgets::Widget,
+};
+
+use crate::{
+    paragraph::{Paragraph, Wrap},
+    reflow::{LineComposer, WordWrapper},
+    table::{cell::layout_cells, source::Rows, Cell, Row},
+};
+
+impl Cell<'_> {
+    /// Returns the content of the cell as a paragraph wrapped with the given options.
+    fn wrapped(&self, wrap: Wrap) -> Paragraph<'_> {
+        let text = self.text();
+        Paragraph::new(text.clone())
+            .alignment(text.alignment.unwrap_or(Alignment::Left))
+            .wrap(wrap)
+    }
+
+    /// Returns the number of lines of the content of the cell once wrapped to the given width.
+    pub(crate) fn wrapped_height(&self, width: u16, wrap: Wrap) -> u16 {
+        if width == 0 {
+            return 0;
+        }
+        let styled = self.text().iter().map(|line| {
+            let graphemes = line
+                .spans
+                .iter()
+                .flat_map(|span| span.styled_graphemes(Style::default()));
+            (graphemes, Alignment::Left)
+        });
+        let mut composer = WordWrapper::new(styled, width, wrap.trim);
+        let mut height = 0_u16;
+        while composer.next_line().is_some() {
+            height = height.saturating_add(1);
+        }
+        height
+    }
+
+    /// Renders the cell, wrapping its content to the width of the area.
+    pub(crate) fn render_wrapped(&self, area: Rect, buf: &mut Buffer, wrap: Wrap) {
+        buf.set_style(area, Styled::style(self));
+        self.wrapped(wrap).render(area, buf);
+    }
+}
+
+impl Row<'_> {
+    /// Returns the height of the row with its cells wrapped to the width of their columns.
+    ///
+    /// This is the number of lines of the tallest cell, or the height of the row if it is larger.
+    /// Cells spanning several rows don't make their first row taller.
+    fn wrapped_height(&self, columns_widths: &[(u16, u16)], wrap: Wrap) -> u16 {
+        let area = Rect::new(0, 0, u16::MAX, 1);
+        layout_cells(&self.cells, columns_widths, area)
+            .filter(|(cell, _, _)| cell.rows() == 1)
+            .map(|(cell, _, cell_area)| cell.wrapped_height(cell_area.width, wrap))
+            .fold(self.height, u16::max)
+    }
+}
+
+/// The rows of a table with their height computed from their wrapped cells
+///
+/// The height of each row is computed once, the first time it is needed, as it wraps the content
+/// of all the cells of the row.
+pub(crate) struct WrappedRows<'a, R: ?Sized> {
+    rows: &'a R,
+    columns_widths: &'a [(u16, u16)],
+    wrap: Wrap,
+    heights: RefCell<HashMap<usize, u16>>,
+}
+
+impl<'a, R: Rows + ?Sized> WrappedRows<'a, R> {
+    pub(crate) fn new(rows: &'a R, columns_widths: &'a [(u16, u16)], wrap: Wrap) -> Self {
+        Self {
+            rows,
+            columns_widths,
+            wrap,
+            heights: RefCell::default(),
+        }
+    }
+
+    /// Returns the wrapped height of the given row, which is the row at the given index
+    fn wrapped_height(&self, index: usize, row: &Row<'_>) -> u16 {
+        *self
+            .heights
+            .borrow_mut()
+            .entry(index)
+            .or_insert_with(|| row.wrapped_height(self.columns_widths, self.wrap))
+    }
+}
+
+impl<R: Rows + ?Sized> Rows for WrappedRows<'_, R> {
+    fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    fn height(&self, index: usize) -> u16 {
+        if let Some(&height) = self.heights.borrow().get(&index) {
+            return height;
+        }
+        self.rows
+            .with_row(index, |row| self.wrapped_height(index, row))
+    }
+
+    fn height_with_margin(&self, index: usize) -> u16 {
+        self.rows.with_row(index, |row| {
+            self.wrapped_height(index, row)
+                .saturating_add(row.top_margin)
+                .saturating_add(row.bottom_margin)
+        })
+    }
+
+    fn row_height(&self, index: usize, row: &Row<'_>) -> u16 {
+        self.wrapped_height(index, row)
+    }
+
+    fn with_row<T>(&self, index: usize, f: impl FnOnce(&Row<'_>) -> T) -> T {
+        self.rows.with_row(index, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use ratatui_core::{layout::Constraint, widgets::StatefulWidget};
+
+    use super::*;
+    use crate::table::{Table, TableState};
+
+    #[test]
+    fn wrapped_height() {
+        let cell = Cell::new("lorem ipsum dolor");
+        assert_eq!(cell.wrapped_height(5, Wrap::default()), 3);
+        assert_eq!(cell.wrapped_height(11, Wrap::default()), 2);
+
+        let row = Row::new([Cell::new("a b c"), Cell::new("d")]);
+        assert_eq!(row.wrapped_height(&[(0, 1), (2, 1)], Wrap::default()), 3);
+        // the height of the row is the minimum
+        let row = row.height(4);
+        assert_eq!(row.wrapped_height(&[(0, 1), (2, 1)], Wrap::default()), 4);
+    }
+
+    #[test]
+    fn heights_are_cached() {
+        let rows = [Row::new(["a b"]).bottom_margin(1), Row::new(["c"])];
+        let wrapped = WrappedRows::new(&rows[..], &[(0, 1)], Wrap::default());
+        assert_eq!(wrapped.height(0), 2);
+        assert_eq!(wrapped.height_with_margin(0), 3);
+        assert_eq!(wrapped.with_row(0, |row| wrapped.row_height(0, row)), 2);
+        assert_eq!(*wrapped.heights.borrow(), HashMap::from([(0, 2)]));
+    }
+
+    #[test]
+    fn render_wrapped() {
+        let rows = [
+            Row::new(["apple", "a red fruit"]),
+            Row::new(["kiwi", "green"]),
+        ];
+        let table = Table::new(rows, [Constraint::Length(5), Constraint::Length(6)])
+            .wrap(Wrap { trim: true });
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 4));
+        Widget::render(&table, buf.area, &mut buf);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "apple a red ",
+            "      fruit ",
+            "kiwi  green ",
+            "            ",
+        ]);
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn scroll_wrapped_rows() {
+        let rows = [
+            Row::new(["one two"]),
+            Row::new(["three four"]),
+            Row::new(["five"]),
+        ];
+        let table = Table::new(rows, [Constraint::Length(5)]).wrap(Wrap::default());
+        let mut state = TableState::new().with_selected(Some(2));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 3));
+        StatefulWidget::render(&table, buf.area, &mut buf, &mut state);
+        // the second row is two lines high, so the first row is scrolled out
+        assert_eq!(buf, Buffer::with_lines(["three", "four ", "five "]));
+        assert_eq!(state.offset(), 1);
+    }
+}
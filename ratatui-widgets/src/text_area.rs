@@ -0,0 +1,443 @@
+//! The [`TextArea`] widget is used to edit multi-line text.
+use std::borrow::Cow;
+
+use ratatui_core::{
+    buffer::Buffer,
+    layout::{Position, Rect},
+    style::{Modifier, Style, Styled},
+    text::{Line, Masked},
+    widgets::{StatefulWidget, Widget},
+};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+pub use self::state::{CursorMove, TextAreaState};
+use crate::{
+    block::{Block, BlockExt},
+    paragraph::TextPosition,
+};
+
+mod state;
+
+/// A widget to edit multi-line text
+///
+/// The text, cursor, selection, scroll offset and undo history are stored in a [`TextAreaState`],
+/// which is edited by the application in response to key events. `TextArea` only configures how
+/// the state is displayed.
+///
+/// When rendered, the text is scrolled to keep the cursor visible, and the screen position of the
+/// cursor is stored in the state. Pass [`TextAreaState::cursor_position`] to
+/// `Frame::set_cursor_position` to show the terminal cursor.
+///
+/// # Fluent setters
+///
+/// - [`TextArea::block`] wraps the text area in a [`Block`].
+/// - [`TextArea::style`] sets the base style of the widget.
+/// - [`TextArea::placeholder`] sets the text displayed when the text area is empty.
+/// - [`TextArea::placeholder_style`] sets the style of the placeholder.
+/// - [`TextArea::selection_style`] sets the style of the selected text.
+/// - [`TextArea::mask_char`] hides the text, e.g. for passwords.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     layout::Rect,
+///     widgets::{Block, TextArea, TextAreaState},
+///     Frame,
+/// };
+///
+/// # fn ui(frame: &mut Frame) {
+/// # let area = Rect::default();
+/// // This should be stored outside of the function in your application state.
+/// let mut state = TextAreaState::default();
+///
+/// let text_area = TextArea::new()
+///     .block(Block::bordered().title("Message"))
+///     .placeholder("Type a message");
+/// frame.render_stateful_widget(text_area, area, &mut state);
+/// if let Some(position) = state.cursor_position() {
+///     frame.set_cursor_position(position);
+/// }
+/// # }
+/// ```
+///
+/// For password input, use [`TextArea::mask_char`]. Each character is displayed as the mask
+/// character, as with [`Masked`].
+///
+/// ```rust
+/// use ratatui::widgets::TextArea;
+///
+/// let password = TextArea::new().mask_char('•');
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct TextArea<'a> {
+    /// An optional block to wrap the widget in
+    block: Option<Block<'a>>,
+    /// Style used as a base style for the widget
+    style: Style,
+    /// Text displayed when the text area is empty
+    placeholder: Line<'a>,
+    /// Style applied to the placeholder
+    placeholder_style: Style,
+    /// Style applied to the selected text
+    selection_style: Style,
+    /// Character that replaces each character of the text
+    mask_char: Option<char>,
+}
+
+const DEFAULT_PLACEHOLDER_STYLE: Style = Style::new().add_modifier(Modifier::DIM);
+const DEFAULT_SELECTION_STYLE: Style = Style::new().add_modifier(Modifier::REVERSED);
+
+impl Default for TextArea<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> TextArea<'a> {
+    /// Creates a new text area with the default styles and no placeholder.
+    pub const fn new() -> Self {
+        Self {
+            block: None,
+            style: Style::new(),
+            placeholder: Line {
+                style: Style::new(),
+                alignment: None,
+                spans: Vec::new(),
+            },
+            placeholder_style: DEFAULT_PLACEHOLDER_STYLE,
+            selection_style: DEFAULT_SELECTION_STYLE,
+            mask_char: None,
+        }
+    }
+
+    /// Wraps the text area with the given [`Block`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the base style of the widget
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the text displayed when the text area is empty
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn placeholder<T: Into<Line<'a>>>(mut self, placeholder: T) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Sets the style of the placeholder
+    ///
+    /// The style is patched onto the base style, and the styles of the placeholder [`Line`] are
+    /// patched onto it. Defaults to dimmed text.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn placeholder_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.placeholder_style = style.into();
+        self
+    }
+
+    /// Sets the style of the selected text
+    ///
+    /// Defaults to reversed text.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn selection_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.selection_style = style.into();
+        self
+    }
+
+    /// Masks the text by displaying each character as `mask_char`
+    ///
+    /// This is useful for password fields. The text in the state is not changed, and the cursor
+    /// moves over the masked characters.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn mask_char(mut self, mask_char: char) -> Self {
+        self.mask_char = Some(mask_char);
+        self
+    }
+
+    /// Returns the symbol that is displayed for a grapheme.
+    fn display_symbol<'s>(&self, grapheme: &'s str) -> Cow<'s, str> {
+        self.mask_char.map_or(Cow::Borrowed(grapheme), |mask_char| {
+            Masked::new(grapheme, mask_char).value()
+        })
+    }
+
+    /// Returns the display width of a line up to the given column.
+    fn display_column(&self, line: &str, column: usize) -> usize {
+        line.graphemes(true)
+            .take(column)
+            .map(|grapheme| self.display_symbol(grapheme).width())
+            .sum()
+    }
+
+    /// Adjusts the scroll offset of the state so that the cursor is inside the area.
+    fn scroll_to_cursor(&self, area: Rect, state: &mut TextAreaState) -> Position {
+        let cursor = state.cursor;
+        let x = self.display_column(&state.lines[cursor.line], cursor.column);
+        let x = u16::try_from(x).unwrap_or(u16::MAX);
+        let y = u16::try_from(cursor.line).unwrap_or(u16::MAX);
+        let offset = &mut state.offset;
+        offset.x = offset.x.clamp(x.saturating_sub(area.width - 1), x);
+        offset.y = offset.y.clamp(y.saturating_sub(area.height - 1), y);
+        Position::new(area.x + x - offset.x, area.y + y - offset.y)
+    }
+
+    fn render_line(&self, area: Rect, buf: &mut Buffer, state: &TextAreaState, line_index: usize) {
+        let selection = state.selection();
+        let is_selected = |column| {
+            selection
+                .is_some_and(|selection| selection.contains(TextPosition::new(line_index, column)))
+        };
+        let line = &state.lines[line_index];
+        let offset = state.offset.x;
+        let mut x = 0;
+        let mut column = 0;
+        for grapheme in line.graphemes(true) {
+            let symbol = self.display_symbol(grapheme);
+            let width = symbol.width() as u16;
+            if x >= offset && x + width - offset <= area.width && width > 0 {
+                let style = if is_selected(column) {
+                    self.selection_style
+                } else {
+                    Style::new()
+                };
+                buf.set_stringn(area.x + x - offset, area.y, symbol, width as usize, style);
+            }
+            x = x.saturating_add(width);
+            column += 1;
+        }
+        // show that the line break is selected
+        if is_selected(column) && x >= offset && x - offset < area.width {
+            buf[(area.x + x - offset, area.y)].set_style(self.selection_style);
+        }
+    }
+}
+
+impl StatefulWidget for TextArea<'_> {
+    type State = TextAreaState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidget::render(&self, area, buf, state);
+    }
+}
+
+impl StatefulWidget for &TextArea<'_> {
+    type State = TextAreaState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        buf.set_style(area, self.style);
+        self.block.as_ref().render(area, buf);
+        let area = self.block.inner_if_some(area);
+        state.cursor_position = None;
+        if area.is_empty() {
+            return;
+        }
+
+        if state.is_empty() {
+            state.offset = Position::ORIGIN;
+            buf.set_style(
+                area.intersection(Rect { height: 1, ..area }),
+                self.placeholder_style,
+            );
+            (&self.placeholder).render(area, buf);
+            state.cursor_position = Some(area.as_position());
+            return;
+        }
+
+        let cursor_position = self.scroll_to_cursor(area, state);
+        let rows = area.rows();
+        for (row, line_index) in rows.zip(state.offset.y as usize..state.lines.len()) {
+            self.render_line(row, buf, state, line_index);
+        }
+        state.cursor_position = Some(cursor_position);
+    }
+}
+
+impl Styled for TextArea<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use ratatui_core::style::Stylize;
+
+    use super::*;
+
+    #[test]
+    fn new() {
+        let text_area = TextArea::new();
+        assert_eq!(text_area, TextArea::default());
+        assert_eq!(text_area.placeholder_style, DEFAULT_PLACEHOLDER_STYLE);
+        assert_eq!(text_area.selection_style, DEFAULT_SELECTION_STYLE);
+        assert_eq!(text_area.mask_char, None);
+    }
+
+    #[test]
+    fn render() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 3));
+        let mut state = TextAreaState::new("Hello\n日本語");
+        state.set_cursor((1, 2));
+        TextArea::new().render(buf.area, &mut buf, &mut state);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["Hello   ", "日本語  ", "        "])
+        );
+        assert_eq!(state.cursor_position(), Some(Position::new(4, 1)));
+    }
+
+    #[test]
+    fn render_with_block() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 3));
+        let mut state = TextAreaState::new("Hi");
+        state.move_cursor(CursorMove::LineEnd);
+        TextArea::new()
+            .block(Block::bordered())
+            .render(buf.area, &mut buf, &mut state);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["┌──────┐", "│Hi    │", "└──────┘"])
+        );
+        assert_eq!(state.cursor_position(), Some(Position::new(3, 1)));
+    }
+
+    #[test]
+    fn render_empty_area() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 2, 2));
+        let mut state = TextAreaState::new("Hi");
+        TextArea::new()
+            .block(Block::bordered())
+            .render(buf.area, &mut buf, &mut state);
+        assert_eq!(state.cursor_position(), None);
+    }
+
+    #[test]
+    fn render_placeholder() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 2));
+        let mut state = TextAreaState::default();
+        TextArea::new()
+            .placeholder(Line::from(vec!["Type".into(), "...".italic()]))
+            .render(buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines(["Type... ", "        "]);
+        expected.set_style(Rect::new(0, 0, 8, 1), Style::new().dim());
+        expected.set_style(Rect::new(4, 0, 3, 1), Style::new().italic());
+        assert_eq!(buf, expected);
+        assert_eq!(state.cursor_position(), Some(Position::new(0, 0)));
+    }
+
+    #[test]
+    fn render_masked() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+        let mut state = TextAreaState::new("pä👩‍👧");
+        state.move_cursor(CursorMove::LineEnd);
+        TextArea::new()
+            .mask_char('*')
+            .render(buf.area, &mut buf, &mut state);
+        // the emoji is a single grapheme made of 3 characters
+        assert_eq!(buf, Buffer::with_lines(["***** "]));
+        assert_eq!(state.cursor_position(), Some(Position::new(5, 0)));
+    }
+
+    #[test]
+    fn render_scrolls_to_cursor() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 2));
+        let mut state = TextAreaState::new("one\ntwo\nthree\nfour");
+        state.set_cursor((2, 5));
+        TextArea::new().render(buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["o   ", "ree "]));
+        assert_eq!(state.offset(), Position::new(2, 1));
+        assert_eq!(state.cursor_position(), Some(Position::new(3, 1)));
+
+        // the offset is kept while the cursor is visible
+        state.set_cursor((1, 2));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 2));
+        TextArea::new().render(buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["o   ", "ree "]));
+        assert_eq!(state.cursor_position(), Some(Position::new(0, 0)));
+
+        state.set_cursor((0, 0));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 2));
+        TextArea::new().render(buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["one ", "two "]));
+        assert_eq!(state.offset(), Position::ORIGIN);
+    }
+
+    #[test]
+    fn render_partially_visible_wide_grapheme() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 1));
+        let mut state = TextAreaState::new("a日本");
+        *state.offset_mut() = Position::new(2, 0);
+        state.set_cursor((0, 3));
+        TextArea::new().render(buf.area, &mut buf, &mut state);
+        assert_eq!(state.offset(), Position::new(3, 0));
+        assert_eq!(buf, Buffer::with_lines(["本 "]));
+    }
+
+    #[test]
+    fn render_selection() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 2));
+        let mut state = TextAreaState::new("abc\nde").with_cursor((0, 1));
+        state.extend_selection(CursorMove::Down);
+        TextArea::new()
+            .selection_style(Style::new().red())
+            .render(buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines(["abc  ", "de   "]);
+        expected.set_style(Rect::new(1, 0, 3, 1), Style::new().red());
+        expected.set_style(Rect::new(0, 1, 1, 1), Style::new().red());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_style() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 1));
+        let mut state = TextAreaState::new("a");
+        TextArea::new()
+            .blue()
+            .render(buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines(["a  "]);
+        expected.set_style(buf.area, Style::new().blue());
+        assert_eq!(buf, expected);
+    }
+}
@@ -0,0 +1,783 @@
+use std::collections::VecDeque;
+
+use ratatui_core::layout::Position;
+use strum::{Display, EnumString};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+use crate::paragraph::{Selection, TextPosition};
+
+/// The default number of edits that can be undone
+const DEFAULT_HISTORY_LIMIT: usize = 100;
+
+/// State of the [`TextArea`] widget
+///
+/// The state holds the text being edited, along with the cursor, the selection, the scroll offset
+/// and the undo history. All positions are expressed as [`TextPosition`]s, where the column is the
+/// index of a grapheme in its line, so the cursor never ends up in the middle of a multi-byte or
+/// multi-codepoint character.
+///
+/// When the text area is rendered, the scroll offset is adjusted to keep the cursor visible, and
+/// the position of the cursor on the screen is stored in the state. Pass it to
+/// `Frame::set_cursor_position` to show the terminal cursor there.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::widgets::{CursorMove, TextAreaState, TextPosition};
+///
+/// let mut state = TextAreaState::new("Hello\nworld");
+/// state.move_cursor(CursorMove::Bottom);
+/// state.insert_str("!");
+/// assert_eq!(state.text(), "Hello\nworld!");
+/// assert_eq!(state.cursor(), TextPosition::new(1, 6));
+///
+/// state.undo();
+/// assert_eq!(state.text(), "Hello\nworld");
+/// ```
+///
+/// [`TextArea`]: super::TextArea
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TextAreaState {
+    pub(crate) lines: Vec<String>,
+    pub(crate) cursor: TextPosition,
+    pub(crate) anchor: Option<TextPosition>,
+    pub(crate) offset: Position,
+    pub(crate) cursor_position: Option<Position>,
+    /// The display column that vertical movement tries to keep
+    preferred_column: Option<usize>,
+    undo: VecDeque<Snapshot>,
+    redo: Vec<Snapshot>,
+    history_limit: usize,
+}
+
+/// The content and cursor of a [`TextAreaState`] before an edit
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Snapshot {
+    lines: Vec<String>,
+    cursor: TextPosition,
+}
+
+/// A movement of the cursor in a [`TextAreaState`]
+///
+/// See [`TextAreaState::move_cursor`] and [`TextAreaState::extend_selection`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Display, EnumString)]
+pub enum CursorMove {
+    /// One grapheme to the left, or to the end of the previous line
+    Left,
+    /// One grapheme to the right, or to the start of the next line
+    Right,
+    /// One line up, keeping the display column where possible
+    Up,
+    /// One line down, keeping the display column where possible
+    Down,
+    /// To the start of the current or previous word
+    WordLeft,
+    /// To the start of the next word
+    WordRight,
+    /// To the start of the line
+    LineStart,
+    /// To the end of the line
+    LineEnd,
+    /// To the start of the text
+    Top,
+    /// To the end of the text
+    Bottom,
+}
+
+impl Default for TextAreaState {
+    fn default() -> Self {
+        Self::new("")
+    }
+}
+
+impl TextAreaState {
+    /// Creates a new state with the given text and the cursor at the start of the text.
+    ///
+    /// The text is split into lines at `\n`, and a trailing `\r` is removed from each line.
+    pub fn new<T: AsRef<str>>(text: T) -> Self {
+        Self {
+            lines: split_lines(text.as_ref()),
+            cursor: TextPosition::default(),
+            anchor: None,
+            offset: Position::ORIGIN,
+            cursor_position: None,
+            preferred_column: None,
+            undo: VecDeque::new(),
+            redo: Vec::new(),
+            history_limit: DEFAULT_HISTORY_LIMIT,
+        }
+    }
+
+    /// Sets the position of the cursor
+    ///
+    /// The position is clamped to the text. This is a fluent setter method which must be chained
+    /// or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_cursor<P: Into<TextPosition>>(mut self, position: P) -> Self {
+        self.set_cursor(position);
+        self
+    }
+
+    /// Sets the maximum number of edits that can be undone
+    ///
+    /// Defaults to 100. This is a fluent setter method which must be chained or used as it
+    /// consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_history_limit(mut self, limit: usize) -> Self {
+        self.history_limit = limit;
+        // keep the most recent snapshots, which are at the back of both stacks
+        let excess = self.undo.len().saturating_sub(limit);
+        self.undo.drain(..excess);
+        let excess = self.redo.len().saturating_sub(limit);
+        self.redo.drain(..excess);
+        self
+    }
+
+    /// Returns the text, with the lines joined by `\n`.
+    pub fn text(&self) -> String {
+        self.lines.join("\n")
+    }
+
+    /// Returns the lines of the text.
+    ///
+    /// There is always at least one line, which is empty for an empty text.
+    pub fn lines(&self) -> &[String] {
+        &self.lines
+    }
+
+    /// Returns true if the text is empty.
+    pub fn is_empty(&self) -> bool {
+        self.lines.len() == 1 && self.lines[0].is_empty()
+    }
+
+    /// Replaces the text and moves the cursor to the start of it.
+    ///
+    /// This is recorded in the undo history.
+    pub fn set_text<T: AsRef<str>>(&mut self, text: T) {
+        self.push_history();
+        self.lines = split_lines(text.as_ref());
+        self.cursor = TextPosition::default();
+        self.anchor = None;
+        self.preferred_column = None;
+    }
+
+    /// Returns the position of the cursor in the text.
+    pub const fn cursor(&self) -> TextPosition {
+        self.cursor
+    }
+
+    /// Moves the cursor to the given position and clears the selection.
+    ///
+    /// The position is clamped to the text.
+    pub fn set_cursor<P: Into<TextPosition>>(&mut self, position: P) {
+        self.cursor = self.clamp(position.into());
+        self.anchor = None;
+        self.preferred_column = None;
+    }
+
+    /// Returns the scroll offset of the text area.
+    ///
+    /// `x` is the number of columns and `y` the number of lines that are scrolled out of view. The
+    /// offset is updated when rendering to keep the cursor visible.
+    pub const fn offset(&self) -> Position {
+        self.offset
+    }
+
+    /// Returns a mutable reference to the scroll offset of the text area.
+    pub fn offset_mut(&mut self) -> &mut Position {
+        &mut self.offset
+    }
+
+    /// Returns the position of the cursor on the screen, as of the last render.
+    ///
+    /// This is `None` before the text area is rendered, or if it was rendered into an empty area.
+    /// Pass it to `Frame::set_cursor_position` to show the terminal cursor there.
+    pub const fn cursor_position(&self) -> Option<Position> {
+        self.cursor_position
+    }
+
+    /// Returns the selection, if any text is selected.
+    ///
+    /// The cursor of the selection is always the cursor of the text area.
+    pub fn selection(&self) -> Option<Selection> {
+        self.anchor
+            .map(|anchor| Selection::new(anchor, self.cursor))
+            .filter(|selection| !selection.is_empty())
+    }
+
+    /// Returns the selected text, with lines joined by `\n`.
+    #[allow(clippy::string_slice)] // the byte indices are grapheme boundaries
+    pub fn selected_text(&self) -> Option<String> {
+        let (start, end) = self.selection()?.range();
+        let text = if start.line == end.line {
+            let line = &self.lines[start.line];
+            line[byte_index(line, start.column)..byte_index(line, end.column)].to_string()
+        } else {
+            let first = &self.lines[start.line];
+            let last = &self.lines[end.line];
+            let mut text = first[byte_index(first, start.column)..].to_string();
+            for line in &self.lines[start.line + 1..end.line] {
+                text.push('\n');
+                text.push_str(line);
+            }
+            text.push('\n');
+            text.push_str(&last[..byte_index(last, end.column)]);
+            text
+        };
+        Some(text)
+    }
+
+    /// Selects the whole text and moves the cursor to its end.
+    pub fn select_all(&mut self) {
+        self.anchor = Some(TextPosition::default());
+        self.cursor = self.end();
+        self.preferred_column = None;
+    }
+
+    /// Clears the selection without moving the cursor.
+    pub fn clear_selection(&mut self) {
+        self.anchor = None;
+    }
+
+    /// Moves the cursor and clears the selection.
+    ///
+    /// If text is selected, moving left or right places the cursor at the start or end of the
+    /// selection.
+    pub fn move_cursor(&mut self, movement: CursorMove) {
+        if let Some(selection) = self.selection() {
+            let (start, end) = selection.range();
+            match movement {
+                CursorMove::Left => {
+                    self.set_cursor(start);
+                    return;
+                }
+                CursorMove::Right => {
+                    self.set_cursor(end);
+                    return;
+                }
+                _ => {}
+            }
+        }
+        self.anchor = None;
+        self.apply_move(movement);
+    }
+
+    /// Moves the cursor and extends the selection to the new position.
+    ///
+    /// If nothing is selected, the selection starts at the current position of the cursor.
+    pub fn extend_selection(&mut self, movement: CursorMove) {
+        self.anchor.get_or_insert(self.cursor);
+        self.apply_move(movement);
+    }
+
+    /// Inserts a character at the cursor, replacing the selection.
+    pub fn insert_char(&mut self, c: char) {
+        self.insert_str(c.encode_utf8(&mut [0; 4]));
+    }
+
+    /// Inserts a line break at the cursor, replacing the selection.
+    pub fn insert_newline(&mut self) {
+        self.insert_str("\n");
+    }
+
+    /// Inserts text at the cursor, replacing the selection, and moves the cursor after it.
+    ///
+    /// The text may contain line breaks. This is recorded in the undo history as a single edit.
+    pub fn insert_str(&mut self, text: &str) {
+        self.push_history();
+        self.remove_selection();
+        let TextPosition { line, column } = self.cursor;
+        let current = &mut self.lines[line];
+        let after = current.split_off(byte_index(current, column));
+        let mut inserted = split_lines(text);
+        let count = inserted.len();
+        let last_len = inserted[count - 1].graphemes(true).count();
+        let column = if count == 1 {
+            column + last_len
+        } else {
+            last_len
+        };
+        let first = inserted.remove(0);
+        self.lines[line].push_str(&first);
+        inserted
+            .last_mut()
+            .unwrap_or(&mut self.lines[line])
+            .push_str(&after);
+        let next = line + 1;
+        self.lines.splice(next..next, inserted);
+        self.cursor = TextPosition::new(line + count - 1, column);
+        self.preferred_column = None;
+    }
+
+    /// Deletes the selection, or the grapheme before the cursor.
+    ///
+    /// At the start of a line, the line is joined with the previous one. Returns false if there
+    /// was nothing to delete.
+    pub fn delete_backward(&mut self) -> bool {
+        if self.selection().is_none() && self.cursor == TextPosition::default() {
+            return false;
+        }
+        self.push_history();
+        if !self.remove_selection() {
+            let start = self.position_before(self.cursor);
+            self.remove_range(start, self.cursor);
+        }
+        true
+    }
+
+    /// Deletes the selection, or the grapheme after the cursor.
+    ///
+    /// At the end of a line, the next line is joined with it. Returns false if there was nothing
+    /// to delete.
+    pub fn delete_forward(&mut self) -> bool {
+        if self.selection().is_none() && self.cursor == self.end() {
+            return false;
+        }
+        self.push_history();
+        if !self.remove_selection() {
+            let end = self.position_after(self.cursor);
+            self.remove_range(self.cursor, end);
+        }
+        true
+    }
+
+    /// Deletes the selected text. Returns false if nothing was selected.
+    pub fn delete_selection(&mut self) -> bool {
+        if self.selection().is_none() {
+            return false;
+        }
+        self.push_history();
+        self.remove_selection()
+    }
+
+    /// Undoes the last edit. Returns false if there was nothing to undo.
+    pub fn undo(&mut self) -> bool {
+        let Some(snapshot) = self.undo.pop_back() else {
+            return false;
+        };
+        let current = self.restore(snapshot);
+        self.redo.push(current);
+        true
+    }
+
+    /// Redoes the last undone edit. Returns false if there was nothing to redo.
+    pub fn redo(&mut self) -> bool {
+        let Some(snapshot) = self.redo.pop() else {
+            return false;
+        };
+        let current = self.restore(snapshot);
+        self.undo.push_back(current);
+        true
+    }
+
+    /// Returns the display width of the line up to the given column.
+    fn display_column(&self, position: TextPosition) -> usize {
+        self.lines[position.line]
+            .graphemes(true)
+            .take(position.column)
+            .map(UnicodeWidthStr::width)
+            .sum()
+    }
+
+    fn apply_move(&mut self, movement: CursorMove) {
+        let cursor = self.cursor;
+        let preferred_column = self.preferred_column.take();
+        self.cursor = match movement {
+            CursorMove::Left => self.position_before(cursor),
+            CursorMove::Right => self.position_after(cursor),
+            CursorMove::Up if cursor.line == 0 => TextPosition::default(),
+            CursorMove::Down if cursor.line + 1 == self.lines.len() => self.end(),
+            CursorMove::Up | CursorMove::Down => {
+                let column = preferred_column.unwrap_or_else(|| self.display_column(cursor));
+                self.preferred_column = Some(column);
+                let line = if movement == CursorMove::Up {
+                    cursor.line - 1
+                } else {
+                    cursor.line + 1
+                };
+                TextPosition::new(line, self.column_at(line, column))
+            }
+            CursorMove::WordLeft => self.word_start_before(cursor),
+            CursorMove::WordRight => self.word_start_after(cursor),
+            CursorMove::LineStart => TextPosition::new(cursor.line, 0),
+            CursorMove::LineEnd => TextPosition::new(cursor.line, self.line_len(cursor.line)),
+            CursorMove::Top => TextPosition::default(),
+            CursorMove::Bottom => self.end(),
+        };
+    }
+
+    /// Returns the column of the grapheme at the given display column of a line.
+    fn column_at(&self, line: usize, display_column: usize) -> usize {
+        let mut x = 0;
+        for (column, grapheme) in self.lines[line].graphemes(true).enumerate() {
+            x += grapheme.width();
+            if x > display_column {
+                return column;
+            }
+        }
+        self.line_len(line)
+    }
+
+    fn word_start_before(&self, position: TextPosition) -> TextPosition {
+        if position.column == 0 {
+            return self.position_before(position);
+        }
+        let graphemes: Vec<&str> = self.lines[position.line].graphemes(true).collect();
+        let mut column = position.column;
+        while column > 0 && is_whitespace(graphemes[column - 1]) {
+            column -= 1;
+        }
+        while column > 0 && !is_whitespace(graphemes[column - 1]) {
+            column -= 1;
+        }
+        TextPosition::new(position.line, column)
+    }
+
+    fn word_start_after(&self, position: TextPosition) -> TextPosition {
+        let graphemes: Vec<&str> = self.lines[position.line].graphemes(true).collect();
+        if position.column >= graphemes.len() {
+            return self.position_after(position);
+        }
+        let mut column = position.column;
+        while column < graphemes.len() && !is_whitespace(graphemes[column]) {
+            column += 1;
+        }
+        while column < graphemes.len() && is_whitespace(graphemes[column]) {
+            column += 1;
+        }
+        TextPosition::new(position.line, column)
+    }
+
+    fn position_before(&self, position: TextPosition) -> TextPosition {
+        match (position.column, position.line) {
+            (0, 0) => position,
+            (0, line) => TextPosition::new(line - 1, self.line_len(line - 1)),
+            (column, line) => TextPosition::new(line, column - 1),
+        }
+    }
+
+    fn position_after(&self, position: TextPosition) -> TextPosition {
+        if position.column < self.line_len(position.line) {
+            TextPosition::new(position.line, position.column + 1)
+        } else if position.line + 1 < self.lines.len() {
+            TextPosition::new(position.line + 1, 0)
+        } else {
+            position
+        }
+    }
+
+    fn line_len(&self, line: usize) -> usize {
+        self.lines[line].graphemes(true).count()
+    }
+
+    fn end(&self) -> TextPosition {
+        let line = self.lines.len() - 1;
+        TextPosition::new(line, self.line_len(line))
+    }
+
+    fn clamp(&self, position: TextPosition) -> TextPosition {
+        let line = position.line.min(self.lines.len() - 1);
+        TextPosition::new(line, position.column.min(self.line_len(line)))
+    }
+
+    /// Removes the selected text and places the cursor at its start.
+    ///
+    /// Returns false if nothing was selected.
+    fn remove_selection(&mut self) -> bool {
+        let selection = self.selection();
+        self.anchor = None;
+        selection.is_some_and(|selection| {
+            let (start, end) = selection.range();
+            self.remove_range(start, end);
+            true
+        })
+    }
+
+    /// Removes the text between two positions and places the cursor at the start.
+    #[allow(clippy::string_slice)] // the byte indices are grapheme boundaries
+    fn remove_range(&mut self, start: TextPosition, end: TextPosition) {
+        let last = &self.lines[end.line];
+        let tail = last[byte_index(last, end.column)..].to_string();
+        self.lines.drain(start.line + 1..=end.line);
+        let first = &mut self.lines[start.line];
+        first.truncate(byte_index(first, start.column));
+        first.push_str(&tail);
+        self.cursor = start;
+        self.preferred_column = None;
+    }
+
+    /// Records the current text in the undo history, and clears the redo history.
+    fn push_history(&mut self) {
+        self.redo.clear();
+        if self.history_limit == 0 {
+            return;
+        }
+        if self.undo.len() == self.history_limit {
+            self.undo.pop_front();
+        }
+        self.undo.push_back(Snapshot {
+            lines: self.lines.clone(),
+            cursor: self.cursor,
+        });
+    }
+
+    /// Restores a snapshot and returns the replaced content.
+    fn restore(&mut self, snapshot: Snapshot) -> Snapshot {
+        let current = Snapshot {
+            lines: std::mem::replace(&mut self.lines, snapshot.lines),
+            cursor: self.cursor,
+        };
+        self.cursor = snapshot.cursor;
+        self.anchor = None;
+        self.preferred_column = None;
+        current
+    }
+}
+
+impl<T: AsRef<str>> From<T> for TextAreaState {
+    fn from(text: T) -> Self {
+        Self::new(text)
+    }
+}
+
+fn split_lines(text: &str) -> Vec<String> {
+    text.split('\n')
+        .map(|line| line.strip_suffix('\r').unwrap_or(line).to_string())
+        .collect()
+}
+
+/// Returns the byte index of the grapheme at the given column, or the length of the line.
+fn byte_index(line: &str, column: usize) -> usize {
+    line.grapheme_indices(true)
+        .nth(column)
+        .map_or(line.len(), |(index, _)| index)
+}
+
+fn is_whitespace(grapheme: &str) -> bool {
+    grapheme.chars().all(char::is_whitespace)
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn new() {
+        let state = TextAreaState::new("Hello\r\nworld\n");
+        assert_eq!(state.lines(), ["Hello", "world", ""]);
+        assert_eq!(state.text(), "Hello\nworld\n");
+        assert_eq!(state.cursor(), TextPosition::new(0, 0));
+        assert!(!state.is_empty());
+        assert!(TextAreaState::default().is_empty());
+        assert_eq!(TextAreaState::default().lines(), [""]);
+    }
+
+    #[test]
+    fn set_cursor_clamps() {
+        let mut state = TextAreaState::new("ab\nc");
+        state.set_cursor((0, 5));
+        assert_eq!(state.cursor(), TextPosition::new(0, 2));
+        state.set_cursor((5, 5));
+        assert_eq!(state.cursor(), TextPosition::new(1, 1));
+    }
+
+    #[test]
+    fn insert() {
+        let mut state = TextAreaState::new("");
+        state.insert_char('a');
+        state.insert_char('é');
+        state.insert_str("👩‍👩‍👧 c");
+        assert_eq!(state.text(), "aé👩‍👩‍👧 c");
+        assert_eq!(state.cursor(), TextPosition::new(0, 5));
+        state.set_cursor((0, 1));
+        state.insert_newline();
+        assert_eq!(state.lines(), ["a", "é👩‍👩‍👧 c"]);
+        assert_eq!(state.cursor(), TextPosition::new(1, 0));
+    }
+
+    #[test]
+    fn insert_multiple_lines() {
+        let mut state = TextAreaState::new("ab").with_cursor((0, 1));
+        state.insert_str("1\n2\n3");
+        assert_eq!(state.lines(), ["a1", "2", "3b"]);
+        assert_eq!(state.cursor(), TextPosition::new(2, 1));
+    }
+
+    #[test]
+    fn delete_backward() {
+        let mut state = TextAreaState::new("ab\ncd").with_cursor((1, 0));
+        assert!(state.delete_backward());
+        assert_eq!(state.lines(), ["abcd"]);
+        assert_eq!(state.cursor(), TextPosition::new(0, 2));
+        assert!(state.delete_backward());
+        assert_eq!(state.lines(), ["acd"]);
+        state.set_cursor((0, 0));
+        assert!(!state.delete_backward());
+    }
+
+    #[test]
+    fn delete_forward() {
+        let mut state = TextAreaState::new("a😀\ncd").with_cursor((0, 1));
+        assert!(state.delete_forward());
+        assert_eq!(state.lines(), ["a", "cd"]);
+        assert!(state.delete_forward());
+        assert_eq!(state.lines(), ["acd"]);
+        state.move_cursor(CursorMove::Bottom);
+        assert!(!state.delete_forward());
+    }
+
+    #[test]
+    fn move_cursor() {
+        let mut state = TextAreaState::new("ab\n\ncd");
+        state.move_cursor(CursorMove::Right);
+        state.move_cursor(CursorMove::Right);
+        state.move_cursor(CursorMove::Right);
+        assert_eq!(state.cursor(), TextPosition::new(1, 0));
+        state.move_cursor(CursorMove::Left);
+        assert_eq!(state.cursor(), TextPosition::new(0, 2));
+        state.move_cursor(CursorMove::LineStart);
+        assert_eq!(state.cursor(), TextPosition::new(0, 0));
+        state.move_cursor(CursorMove::LineEnd);
+        assert_eq!(state.cursor(), TextPosition::new(0, 2));
+        state.move_cursor(CursorMove::Bottom);
+        assert_eq!(state.cursor(), TextPosition::new(2, 2));
+        state.move_cursor(CursorMove::Right);
+        assert_eq!(state.cursor(), TextPosition::new(2, 2));
+        state.move_cursor(CursorMove::Top);
+        assert_eq!(state.cursor(), TextPosition::new(0, 0));
+        state.move_cursor(CursorMove::Left);
+        assert_eq!(state.cursor(), TextPosition::new(0, 0));
+    }
+
+    #[test]
+    fn move_cursor_vertically_keeps_display_column() {
+        let mut state = TextAreaState::new("abcdef\n日本\nabcdef").with_cursor((0, 3));
+        state.move_cursor(CursorMove::Down);
+        // the display column 3 is in the middle of the second wide character
+        assert_eq!(state.cursor(), TextPosition::new(1, 1));
+        state.move_cursor(CursorMove::Down);
+        assert_eq!(state.cursor(), TextPosition::new(2, 3));
+        state.move_cursor(CursorMove::Down);
+        assert_eq!(state.cursor(), TextPosition::new(2, 6));
+        state.move_cursor(CursorMove::Up);
+        assert_eq!(state.cursor(), TextPosition::new(1, 2));
+        state.move_cursor(CursorMove::Up);
+        state.move_cursor(CursorMove::Up);
+        assert_eq!(state.cursor(), TextPosition::new(0, 0));
+    }
+
+    #[test]
+    fn move_cursor_by_word() {
+        let mut state = TextAreaState::new("foo  bar\nbaz");
+        state.move_cursor(CursorMove::WordRight);
+        assert_eq!(state.cursor(), TextPosition::new(0, 5));
+        state.move_cursor(CursorMove::WordRight);
+        assert_eq!(state.cursor(), TextPosition::new(0, 8));
+        state.move_cursor(CursorMove::WordRight);
+        assert_eq!(state.cursor(), TextPosition::new(1, 0));
+        state.move_cursor(CursorMove::WordLeft);
+        assert_eq!(state.cursor(), TextPosition::new(0, 8));
+        state.move_cursor(CursorMove::WordLeft);
+        assert_eq!(state.cursor(), TextPosition::new(0, 5));
+        state.move_cursor(CursorMove::WordLeft);
+        assert_eq!(state.cursor(), TextPosition::new(0, 0));
+    }
+
+    #[test]
+    fn selection() {
+        let mut state = TextAreaState::new("Hello\nworld").with_cursor((0, 3));
+        assert_eq!(state.selection(), None);
+        state.extend_selection(CursorMove::Down);
+        assert_eq!(state.selection(), Some(Selection::new((0, 3), (1, 3))));
+        assert_eq!(state.selected_text().as_deref(), Some("lo\nwor"));
+        state.extend_selection(CursorMove::Up);
+        assert_eq!(state.selection(), None);
+        state.extend_selection(CursorMove::LineStart);
+        assert_eq!(state.selected_text().as_deref(), Some("Hel"));
+
+        // moving collapses the selection to its start or end
+        state.move_cursor(CursorMove::Right);
+        assert_eq!(state.cursor(), TextPosition::new(0, 3));
+        assert_eq!(state.selection(), None);
+
+        state.select_all();
+        assert_eq!(state.selected_text().as_deref(), Some("Hello\nworld"));
+        state.clear_selection();
+        assert_eq!(state.selection(), None);
+    }
+
+    #[test]
+    fn edit_selection() {
+        let mut state = TextAreaState::new("Hello\nworld").with_cursor((0, 1));
+        state.extend_selection(CursorMove::Down);
+        state.insert_char('i');
+        assert_eq!(state.lines(), ["Hiorld"]);
+        assert_eq!(state.cursor(), TextPosition::new(0, 2));
+
+        state.extend_selection(CursorMove::LineEnd);
+        assert!(state.delete_backward());
+        assert_eq!(state.lines(), ["Hi"]);
+        assert!(!state.delete_selection());
+    }
+
+    #[test]
+    fn undo_redo() {
+        let mut state = TextAreaState::new("a");
+        state.move_cursor(CursorMove::LineEnd);
+        state.insert_char('b');
+        state.insert_str("c\nd");
+        assert_eq!(state.text(), "abc\nd");
+
+        assert!(state.undo());
+        assert_eq!(state.text(), "ab");
+        assert_eq!(state.cursor(), TextPosition::new(0, 2));
+        assert!(state.undo());
+        assert_eq!(state.text(), "a");
+        assert!(!state.undo());
+
+        assert!(state.redo());
+        assert!(state.redo());
+        assert_eq!(state.text(), "abc\nd");
+        assert_eq!(state.cursor(), TextPosition::new(1, 1));
+        assert!(!state.redo());
+
+        // a new edit clears the redo history
+        state.undo();
+        state.insert_char('x');
+        assert!(!state.redo());
+    }
+
+    #[test]
+    fn history_limit() {
+        let mut state = TextAreaState::default().with_history_limit(2);
+        state.insert_char('a');
+        state.insert_char('b');
+        state.insert_char('c');
+        assert!(state.undo());
+        assert!(state.undo());
+        assert!(!state.undo());
+        assert_eq!(state.text(), "a");
+
+        // lowering the limit keeps the most recent edits
+        let mut state = TextAreaState::default();
+        for c in "abcd".chars() {
+            state.insert_char(c);
+        }
+        assert!(state.undo());
+        assert!(state.undo());
+        state = state.with_history_limit(1);
+        assert_eq!(state.text(), "ab");
+        assert!(state.undo());
+        assert!(!state.undo());
+        assert_eq!(state.text(), "a");
+        assert!(state.redo());
+        assert!(state.redo());
+        assert!(!state.redo());
+        assert_eq!(state.text(), "abc");
+    }
+}
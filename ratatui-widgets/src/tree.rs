@@ -0,0 +1,660 @@
+//! The [`Tree`] widget is used to display hierarchical data with expandable nodes.
+use std::{collections::HashSet, hash::Hash};
+
+use ratatui_core::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Modifier, Style, Styled},
+    symbols::line,
+    widgets::{StatefulWidget, Widget},
+};
+use unicode_width::UnicodeWidthStr;
+
+pub use self::{item::TreeItem, state::TreeState};
+use crate::{
+    block::{Block, BlockExt},
+    table::HighlightSpacing,
+};
+
+mod item;
+mod state;
+
+/// A widget to display hierarchical data, such as a file tree, with expandable nodes
+///
+/// A tree is a collection of [`TreeItem`]s, which can have children. The children of a node are
+/// only displayed when the node is expanded in the [`TreeState`], and are indented below it with
+/// guides drawn using the [`symbols::line`] characters.
+///
+/// [`Tree`] is a [`StatefulWidget`], which means you can use it with [`TreeState`] to allow the
+/// user to expand and collapse nodes, and to select one of them.
+///
+/// # Fluent setters
+///
+/// - [`Tree::block`] wraps the tree in a [`Block`].
+/// - [`Tree::style`] sets the base style of the widget.
+/// - [`Tree::highlight_style`] sets the style of the selected node.
+/// - [`Tree::highlight_symbol`] sets the symbol displayed in front of the selected node.
+/// - [`Tree::highlight_spacing`] sets when to reserve space for the highlight symbol.
+/// - [`Tree::node_symbols`] sets the symbols of expanded, collapsed and leaf nodes.
+/// - [`Tree::indent_guides`] sets the line symbols of the indent guides.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     layout::Rect,
+///     style::{Style, Stylize},
+///     widgets::{Block, Tree, TreeItem, TreeState},
+///     Frame,
+/// };
+///
+/// # fn ui(frame: &mut Frame) {
+/// # let area = Rect::default();
+/// let items = [
+///     TreeItem::new("src", "src").children([
+///         TreeItem::new("lib.rs", "lib.rs"),
+///         TreeItem::new("main.rs", "main.rs"),
+///     ]),
+///     TreeItem::new("Cargo.toml", "Cargo.toml"),
+/// ];
+/// let tree = Tree::new(items)
+///     .block(Block::bordered().title("Files"))
+///     .highlight_style(Style::new().reversed())
+///     .highlight_symbol(">>");
+///
+/// // This should be stored outside of the function in your application state.
+/// let mut state = TreeState::default().with_expanded([vec!["src"]]);
+///
+/// frame.render_stateful_widget(tree, area, &mut state);
+/// # }
+/// ```
+///
+/// [`symbols::line`]: ratatui_core::symbols::line
+/// [`StatefulWidget`]: ratatui_core::widgets::StatefulWidget
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Tree<'a, T> {
+    /// The root items of the tree
+    items: Vec<TreeItem<'a, T>>,
+    /// An optional block to wrap the widget in
+    block: Option<Block<'a>>,
+    /// Style used as a base style for the widget
+    style: Style,
+    /// Style used to render the selected node
+    highlight_style: Style,
+    /// Symbol in front of the selected node
+    highlight_symbol: Option<&'a str>,
+    /// Decides when to allocate spacing for the highlight symbol
+    highlight_spacing: HighlightSpacing,
+    /// Symbols in front of expanded, collapsed and leaf nodes
+    node_symbols: NodeSymbols<'a>,
+    /// Line symbols of the indent guides, or `None` to indent with spaces
+    indent_guides: Option<line::Set>,
+}
+
+/// The symbols displayed in front of the nodes of a [`Tree`]
+///
+/// The symbols should have the same width, so that the content of the nodes is aligned.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct NodeSymbols<'a> {
+    /// Symbol of a node whose children are displayed
+    pub expanded: &'a str,
+    /// Symbol of a node whose children are hidden
+    pub collapsed: &'a str,
+    /// Symbol of a node without children
+    pub leaf: &'a str,
+}
+
+impl Default for NodeSymbols<'_> {
+    fn default() -> Self {
+        DEFAULT_NODE_SYMBOLS
+    }
+}
+
+const DEFAULT_HIGHLIGHT_STYLE: Style = Style::new().add_modifier(Modifier::REVERSED);
+const DEFAULT_NODE_SYMBOLS: NodeSymbols<'static> = NodeSymbols {
+    expanded: "▼ ",
+    collapsed: "▶ ",
+    leaf: "  ",
+};
+
+impl<T> Default for Tree<'_, T> {
+    fn default() -> Self {
+        Self {
+            items: Vec::new(),
+            block: None,
+            style: Style::new(),
+            highlight_style: DEFAULT_HIGHLIGHT_STYLE,
+            highlight_symbol: None,
+            highlight_spacing: HighlightSpacing::default(),
+            node_symbols: DEFAULT_NODE_SYMBOLS,
+            indent_guides: Some(line::NORMAL),
+        }
+    }
+}
+
+impl<'a, T> Tree<'a, T> {
+    /// Creates a new tree from the given root items
+    pub fn new<I>(items: I) -> Self
+    where
+        I: IntoIterator<Item = TreeItem<'a, T>>,
+    {
+        Self {
+            items: items.into_iter().collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Set the root items
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn items<I>(mut self, items: I) -> Self
+    where
+        I: IntoIterator<Item = TreeItem<'a, T>>,
+    {
+        self.items = items.into_iter().collect();
+        self
+    }
+
+    /// Wraps the tree with a custom [`Block`] widget.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the base style of the widget
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Set the style of the selected node
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This style will be applied to the whole row of the selected node, including the indent
+    /// guides and the highlight symbol. Defaults to reversed.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn highlight_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.highlight_style = style.into();
+        self
+    }
+
+    /// Set the symbol to be displayed in front of the selected node
+    ///
+    /// By default there are no highlight symbols.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn highlight_symbol(mut self, highlight_symbol: &'a str) -> Self {
+        self.highlight_symbol = Some(highlight_symbol);
+        self
+    }
+
+    /// Set when to show the highlight spacing
+    ///
+    /// The highlight spacing is the spacing that is allocated for the selection symbol (if
+    /// enabled) and is used to shift the nodes when a node is selected. See [`HighlightSpacing`]
+    /// for the options.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn highlight_spacing(mut self, value: HighlightSpacing) -> Self {
+        self.highlight_spacing = value;
+        self
+    }
+
+    /// Set the symbols displayed in front of expanded, collapsed and leaf nodes
+    ///
+    /// Defaults to `"▼ "`, `"▶ "` and `"  "`.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn node_symbols(mut self, symbols: NodeSymbols<'a>) -> Self {
+        self.node_symbols = symbols;
+        self
+    }
+
+    /// Set the line symbols of the indent guides
+    ///
+    /// Each level of nesting is indented by two columns. The guides connect the children of a node
+    /// with a vertical line, using the `vertical`, `vertical_right`, `bottom_left` and
+    /// `horizontal` symbols of the [`line::Set`]. Pass `None` to indent with spaces only. Defaults
+    /// to [`line::NORMAL`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn indent_guides<S: Into<Option<line::Set>>>(mut self, guides: S) -> Self {
+        self.indent_guides = guides.into();
+        self
+    }
+
+    /// Returns the indentation of a line of a node: the guides of its ancestors, then the
+    /// connector to its parent on the first line.
+    fn indent(&self, node: &Node<'_, '_, T>, first_line: bool) -> String {
+        if node.depth == 0 {
+            return String::new();
+        }
+        let Some(set) = self.indent_guides else {
+            return "  ".repeat(node.depth);
+        };
+        let mut indent = String::new();
+        for &has_more_siblings in &node.guides {
+            indent.push_str(if has_more_siblings { set.vertical } else { " " });
+            indent.push(' ');
+        }
+        match (first_line, node.is_last) {
+            (true, true) => [set.bottom_left, set.horizontal],
+            (true, false) => [set.vertical_right, set.horizontal],
+            (false, true) => [" ", " "],
+            (false, false) => [set.vertical, " "],
+        }
+        .iter()
+        .for_each(|symbol| indent.push_str(symbol));
+        indent
+    }
+}
+
+impl<T> Styled for Tree<'_, T> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+impl<T> Widget for Tree<'_, T>
+where
+    T: Clone + Eq + Hash,
+{
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl<T> Widget for &Tree<'_, T>
+where
+    T: Clone + Eq + Hash,
+{
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut state = TreeState::default();
+        StatefulWidget::render(self, area, buf, &mut state);
+    }
+}
+
+impl<T> StatefulWidget for Tree<'_, T>
+where
+    T: Clone + Eq + Hash,
+{
+    type State = TreeState<T>;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidget::render(&self, area, buf, state);
+    }
+}
+
+impl<T> StatefulWidget for &Tree<'_, T>
+where
+    T: Clone + Eq + Hash,
+{
+    type State = TreeState<T>;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        buf.set_style(area, self.style);
+        self.block.as_ref().render(area, buf);
+        let area = self.block.inner_if_some(area);
+        if area.is_empty() {
+            return;
+        }
+
+        let nodes = flatten(&self.items, &state.expanded);
+        if nodes.is_empty() {
+            state.select(None);
+            return;
+        }
+
+        let selected = state
+            .selected
+            .as_ref()
+            .and_then(|selected| nodes.iter().position(|node| &node.path == selected));
+        state.offset = scroll_offset(&nodes, state.offset, selected, area.height as usize);
+
+        let highlight_symbol = self.highlight_symbol.unwrap_or("");
+        let blank_symbol = " ".repeat(highlight_symbol.width());
+        let selection_spacing = self.highlight_spacing.should_add(selected.is_some());
+
+        let mut y = area.top();
+        for (index, node) in nodes.iter().enumerate().skip(state.offset) {
+            if y >= area.bottom() {
+                break;
+            }
+            let height = (node.item.height() as u16).min(area.bottom() - y);
+            let row_area = Rect { y, height, ..area };
+            let item_style = self.style.patch(node.item.style);
+            buf.set_style(row_area, item_style);
+
+            let is_selected = selected == Some(index);
+            let symbol = if node.item.children.is_empty() {
+                self.node_symbols.leaf
+            } else if state.expanded.contains(&node.path) {
+                self.node_symbols.expanded
+            } else {
+                self.node_symbols.collapsed
+            };
+            let blank_node_symbol = " ".repeat(symbol.width());
+            let mut content_x = row_area.x;
+            for row in 0..height {
+                let first_line = row == 0;
+                let mut prefix = String::new();
+                if selection_spacing {
+                    prefix.push_str(if is_selected && first_line {
+                        highlight_symbol
+                    } else {
+                        &blank_symbol
+                    });
+                }
+                prefix.push_str(&self.indent(node, first_line));
+                prefix.push_str(if first_line {
+                    symbol
+                } else {
+                    &blank_node_symbol
+                });
+                let (x, _) = buf.set_stringn(
+                    row_area.x,
+                    y + row,
+                    prefix,
+                    row_area.width as usize,
+                    item_style,
+                );
+                content_x = x;
+            }
+            let content_area = Rect {
+                x: content_x,
+                width: row_area.right().saturating_sub(content_x),
+                ..row_area
+            };
+            Widget::render(&node.item.content, content_area, buf);
+
+            if is_selected {
+                buf.set_style(row_area, self.highlight_style);
+            }
+            y += height;
+        }
+    }
+}
+
+/// A visible node of a tree
+struct Node<'i, 'a, T> {
+    /// The identifiers of the node and its ancestors
+    path: Vec<T>,
+    item: &'i TreeItem<'a, T>,
+    /// The nesting level of the node, 0 for the root items
+    depth: usize,
+    /// Whether the ancestors of the node below the root level have siblings after them
+    guides: Vec<bool>,
+    /// Whether the node is the last of its siblings
+    is_last: bool,
+}
+
+/// Returns the visible nodes of a tree, i.e. the root items and the descendants of expanded nodes.
+fn flatten<'i, 'a, T>(
+    items: &'i [TreeItem<'a, T>],
+    expanded: &HashSet<Vec<T>>,
+) -> Vec<Node<'i, 'a, T>>
+where
+    T: Clone + Eq + Hash,
+{
+    fn flatten_into<'i, 'a, T>(
+        items: &'i [TreeItem<'a, T>],
+        expanded: &HashSet<Vec<T>>,
+        parent: &[T],
+        guides: &[bool],
+        nodes: &mut Vec<Node<'i, 'a, T>>,
+    ) where
+        T: Clone + Eq + Hash,
+    {
+        for (index, item) in items.iter().enumerate() {
+            let mut path = parent.to_vec();
+            path.push(item.identifier.clone());
+            let is_last = index + 1 == items.len();
+            let is_expanded = !item.children.is_empty() && expanded.contains(&path);
+            nodes.push(Node {
+                path: path.clone(),
+                item,
+                depth: parent.len(),
+                guides: guides.to_vec(),
+                is_last,
+            });
+            if is_expanded {
+                let mut child_guides = guides.to_vec();
+                if !parent.is_empty() {
+                    child_guides.push(!is_last);
+                }
+                flatten_into(&item.children, expanded, &path, &child_guides, nodes);
+            }
+        }
+    }
+
+    let mut nodes = Vec::new();
+    flatten_into(items, expanded, &[], &[], &mut nodes);
+    nodes
+}
+
+/// Returns the index of the first visible node, so that the selected node is visible.
+fn scroll_offset<T>(
+    nodes: &[Node<'_, '_, T>],
+    offset: usize,
+    selected: Option<usize>,
+    max_height: usize,
+) -> usize {
+    let mut offset = offset.min(nodes.len().saturating_sub(1));
+    let Some(selected) = selected else {
+        return offset;
+    };
+    if selected < offset {
+        return selected;
+    }
+    let height = |offset: usize| -> usize {
+        nodes[offset..=selected]
+            .iter()
+            .map(|node| node.item.height())
+            .sum()
+    };
+    while offset < selected && height(offset) > max_height {
+        offset += 1;
+    }
+    offset
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use ratatui_core::{style::Stylize, text::Text};
+    use rstest::rstest;
+
+    use super::*;
+
+    fn items() -> Vec<TreeItem<'static, &'static str>> {
+        vec![
+            TreeItem::new("src", "src").children([
+                TreeItem::new("widgets", "widgets").children([
+                    TreeItem::new("list.rs", "list.rs"),
+                    TreeItem::new("tree.rs", "tree.rs"),
+                ]),
+                TreeItem::new("lib.rs", "lib.rs"),
+            ]),
+            TreeItem::new("Cargo.toml", "Cargo.toml"),
+        ]
+    }
+
+    fn all_expanded() -> TreeState<&'static str> {
+        TreeState::default().with_expanded([vec!["src"], vec!["src", "widgets"]])
+    }
+
+    #[test]
+    fn new() {
+        let tree = Tree::new(items());
+        assert_eq!(tree.items, items());
+        assert_eq!(tree.highlight_style, DEFAULT_HIGHLIGHT_STYLE);
+        assert_eq!(tree.node_symbols, NodeSymbols::default());
+        assert_eq!(tree.indent_guides, Some(line::NORMAL));
+        assert_eq!(Tree::<usize>::default().items, []);
+    }
+
+    #[test]
+    fn render_collapsed() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 16, 3));
+        Widget::render(Tree::new(items()), buf.area, &mut buf);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["▶ src           ", "  Cargo.toml    ", "                ",])
+        );
+    }
+
+    #[test]
+    fn render_expanded() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 16, 6));
+        let mut state = all_expanded();
+        StatefulWidget::render(Tree::new(items()), buf.area, &mut buf, &mut state);
+        assert_eq!(
+            buf,
+            Buffer::with_lines([
+                "▼ src           ",
+                "├─▼ widgets     ",
+                "│ ├─  list.rs   ",
+                "│ └─  tree.rs   ",
+                "└─  lib.rs      ",
+                "  Cargo.toml    ",
+            ])
+        );
+    }
+
+    #[test]
+    fn render_deep_last_child() {
+        let items = [TreeItem::new(1, "a")
+            .children([TreeItem::new(2, "b").children([TreeItem::new(3, "c")])])];
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 3));
+        let mut state = TreeState::default().with_expanded([vec![1], vec![1, 2]]);
+        StatefulWidget::render(Tree::new(items), buf.area, &mut buf, &mut state);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["▼ a       ", "└─▼ b     ", "  └─  c   "])
+        );
+    }
+
+    #[rstest]
+    #[case::rounded(Some(line::ROUNDED), ["▼ src       ", "├─▼ widgets ", "│ ╰─  list.r"])]
+    #[case::none(None, ["▼ src       ", "  ▼ widgets ", "      list.r"])]
+    fn render_indent_guides(#[case] guides: Option<line::Set>, #[case] expected: [&str; 3]) {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 3));
+        let mut state = all_expanded();
+        let items = [TreeItem::new("src", "src").children([
+            TreeItem::new("widgets", "widgets").children([TreeItem::new("list.rs", "list.rs")]),
+            TreeItem::new("lib.rs", "lib.rs"),
+        ])];
+        let tree = Tree::new(items).indent_guides(guides);
+        StatefulWidget::render(tree, buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(expected));
+    }
+
+    #[test]
+    fn render_highlight() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 14, 3));
+        let mut state = TreeState::default()
+            .with_expanded([vec!["src"]])
+            .with_selected(Some(vec!["src", "widgets"]));
+        let tree = Tree::new(items())
+            .highlight_symbol(">")
+            .highlight_style(Style::new().red());
+        StatefulWidget::render(tree, buf.area, &mut buf, &mut state);
+        let mut expected =
+            Buffer::with_lines([" ▼ src        ", ">├─▶ widgets  ", " └─  lib.rs   "]);
+        expected.set_style(Rect::new(0, 1, 14, 1), Style::new().red());
+        assert_eq!(buf, expected);
+    }
+
+    #[rstest]
+    #[case::always(HighlightSpacing::Always, " ▶ src  ")]
+    #[case::when_selected(HighlightSpacing::WhenSelected, "▶ src   ")]
+    #[case::never(HighlightSpacing::Never, "▶ src   ")]
+    fn render_highlight_spacing(#[case] spacing: HighlightSpacing, #[case] expected: &str) {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 1));
+        let tree = Tree::new(items())
+            .highlight_symbol(">")
+            .highlight_spacing(spacing);
+        Widget::render(tree, buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines([expected]));
+    }
+
+    #[test]
+    fn render_scrolls_to_selected() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 14, 2));
+        let mut state = all_expanded().with_selected(Some(vec!["src", "lib.rs"]));
+        let tree = Tree::new(items()).highlight_style(Style::new());
+        StatefulWidget::render(&tree, buf.area, &mut buf, &mut state);
+        assert_eq!(state.offset(), 3);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["│ └─  tree.rs ", "└─  lib.rs    "])
+        );
+
+        state.select_first(&tree.items);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 14, 2));
+        StatefulWidget::render(&tree, buf.area, &mut buf, &mut state);
+        assert_eq!(state.offset(), 0);
+    }
+
+    #[test]
+    fn render_multiline_item() {
+        let items = [TreeItem::new(1, "a").children([
+            TreeItem::new(2, Text::from("b\nc")),
+            TreeItem::new(3, Text::from("d\ne")),
+        ])];
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 5));
+        let mut state = TreeState::default().with_expanded([vec![1]]);
+        StatefulWidget::render(Tree::new(items), buf.area, &mut buf, &mut state);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["▼ a     ", "├─  b   ", "│   c   ", "└─  d   ", "    e   ",])
+        );
+    }
+
+    #[test]
+    fn render_item_style() {
+        let items = [TreeItem::new(1, "a").blue()];
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 1));
+        Widget::render(Tree::new(items), buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(["  a "]);
+        expected.set_style(buf.area, Style::new().blue());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_empty() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 1));
+        let mut state = TreeState::default().with_selected(Some(vec![1]));
+        StatefulWidget::render(Tree::new([]), buf.area, &mut buf, &mut state);
+        assert_eq!(state.selected(), None);
+        assert_eq!(buf, Buffer::with_lines(["    "]));
+    }
+}
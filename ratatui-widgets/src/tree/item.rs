@@ -0,0 +1,148 @@
+use ratatui_core::{
+    style::{Style, Styled},
+    text::Text,
+};
+
+/// A node in a [`Tree`]
+///
+/// Each item has an identifier, content and optional children. An item is addressed in a
+/// [`TreeState`] by the path of identifiers from the root of the tree to the item, so the
+/// identifiers only need to be unique among siblings.
+///
+/// The item's height is defined by the number of lines it contains, like a [`ListItem`]. You can
+/// set the style of an item with [`TreeItem::style`] or using the [`Stylize`] trait. This [`Style`]
+/// will be combined with the [`Style`] of the inner [`Text`].
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui::{style::Stylize, widgets::TreeItem};
+///
+/// let item = TreeItem::new("src", "src").bold().children([
+///     TreeItem::new("lib.rs", "lib.rs"),
+///     TreeItem::new("main.rs", "main.rs"),
+/// ]);
+/// ```
+///
+/// [`Tree`]: super::Tree
+/// [`TreeState`]: super::TreeState
+/// [`ListItem`]: crate::list::ListItem
+/// [`Stylize`]: ratatui_core::style::Stylize
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct TreeItem<'a, T> {
+    pub(crate) identifier: T,
+    pub(crate) content: Text<'a>,
+    pub(crate) style: Style,
+    pub(crate) children: Vec<Self>,
+}
+
+impl<'a, T> TreeItem<'a, T> {
+    /// Creates a new item without children.
+    ///
+    /// `content` accepts any type that is convertible to [`Text`].
+    pub fn new<C>(identifier: T, content: C) -> Self
+    where
+        C: Into<Text<'a>>,
+    {
+        Self {
+            identifier,
+            content: content.into(),
+            style: Style::default(),
+            children: Vec::new(),
+        }
+    }
+
+    /// Sets the children of the item
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn children<I>(mut self, children: I) -> Self
+    where
+        I: IntoIterator<Item = Self>,
+    {
+        self.children = children.into_iter().collect();
+        self
+    }
+
+    /// Sets the item style
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This [`Style`] can be overridden by the [`Style`] of the [`Text`] content.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// [`Color`]: ratatui_core::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the identifier of the item.
+    pub const fn identifier(&self) -> &T {
+        &self.identifier
+    }
+
+    /// Returns the item height, i.e. the number of lines of its content.
+    pub fn height(&self) -> usize {
+        self.content.height()
+    }
+
+    /// Returns the max width of all the lines of the item's content.
+    pub fn width(&self) -> usize {
+        self.content.width()
+    }
+}
+
+impl<T> Styled for TreeItem<'_, T> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use ratatui_core::{
+        style::{Color, Stylize},
+        text::Line,
+    };
+
+    use super::*;
+
+    #[test]
+    fn new() {
+        let item = TreeItem::new(1, "Item");
+        assert_eq!(item.identifier(), &1);
+        assert_eq!(item.content, Text::from("Item"));
+        assert_eq!(item.style, Style::default());
+        assert!(item.children.is_empty());
+    }
+
+    #[test]
+    fn children() {
+        let item = TreeItem::new(1, "Parent").children([TreeItem::new(2, "Child")]);
+        assert_eq!(item.children, [TreeItem::new(2, "Child")]);
+    }
+
+    #[test]
+    fn style() {
+        let item = TreeItem::new(1, "Item").red();
+        assert_eq!(item.style, Style::new().fg(Color::Red));
+    }
+
+    #[test]
+    fn height_and_width() {
+        let item = TreeItem::new(1, vec![Line::from("a"), Line::from("abc")]);
+        assert_eq!(item.height(), 2);
+        assert_eq!(item.width(), 3);
+    }
+}
@@ -0,0 +1,398 @@
+use std::{collections::HashSet, hash::Hash};
+
+use super::{flatten, TreeItem};
+
+/// State of the [`Tree`] widget
+///
+/// The state tracks which nodes are expanded, which node is selected, and the index of the first
+/// visible row. Nodes are identified by the path of identifiers from the root of the tree to the
+/// node, e.g. `vec!["src", "widgets"]` for the `widgets` child of the root item `src`.
+///
+/// Navigating between visible nodes requires the items of the tree, as the visible nodes depend on
+/// which nodes are expanded. When the tree is rendered as a stateful widget, the offset is adjusted
+/// to keep the selected node visible.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::widgets::{TreeItem, TreeState};
+///
+/// let items = [
+///     TreeItem::new("src", "src").children([TreeItem::new("lib.rs", "lib.rs")]),
+///     TreeItem::new("Cargo.toml", "Cargo.toml"),
+/// ];
+///
+/// // This should be stored outside of the function in your application state.
+/// let mut state = TreeState::default();
+/// state.select_next(&items);
+/// assert_eq!(state.selected(), Some(&["src"][..]));
+///
+/// state.select_first_child(&items);
+/// assert_eq!(state.selected(), Some(&["src", "lib.rs"][..]));
+/// assert!(state.is_expanded(&["src"]));
+///
+/// state.select_parent();
+/// state.collapse_selected();
+/// state.select_next(&items);
+/// assert_eq!(state.selected(), Some(&["Cargo.toml"][..]));
+/// ```
+///
+/// [`Tree`]: super::Tree
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "T: serde::Serialize",
+        deserialize = "T: serde::Deserialize<'de> + Eq + Hash"
+    ))
+)]
+pub struct TreeState<T> {
+    pub(crate) offset: usize,
+    pub(crate) expanded: HashSet<Vec<T>>,
+    pub(crate) selected: Option<Vec<T>>,
+}
+
+impl<T> Default for TreeState<T> {
+    fn default() -> Self {
+        Self {
+            offset: 0,
+            expanded: HashSet::new(),
+            selected: None,
+        }
+    }
+}
+
+impl<T: Eq + Hash> PartialEq for TreeState<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.offset == other.offset
+            && self.expanded == other.expanded
+            && self.selected == other.selected
+    }
+}
+
+impl<T: Eq + Hash> Eq for TreeState<T> {}
+
+impl<T> TreeState<T>
+where
+    T: Clone + Eq + Hash,
+{
+    /// Sets the index of the first visible row
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Sets the path of the selected node
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_selected(mut self, selected: Option<Vec<T>>) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    /// Sets the paths of the expanded nodes
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_expanded<I>(mut self, expanded: I) -> Self
+    where
+        I: IntoIterator<Item = Vec<T>>,
+    {
+        self.expanded = expanded.into_iter().collect();
+        self
+    }
+
+    /// Index of the first visible row, counting only the nodes that are visible
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Mutable reference to the index of the first visible row
+    pub fn offset_mut(&mut self) -> &mut usize {
+        &mut self.offset
+    }
+
+    /// Path of the selected node
+    ///
+    /// Returns `None` if no node is selected
+    pub fn selected(&self) -> Option<&[T]> {
+        self.selected.as_deref()
+    }
+
+    /// Selects the node with the given path, or deselects if `None`
+    pub fn select(&mut self, selected: Option<Vec<T>>) {
+        self.selected = selected;
+        if self.selected.is_none() {
+            self.offset = 0;
+        }
+    }
+
+    /// Paths of the expanded nodes
+    pub const fn expanded(&self) -> &HashSet<Vec<T>> {
+        &self.expanded
+    }
+
+    /// Returns true if the node with the given path is expanded
+    pub fn is_expanded(&self, path: &[T]) -> bool {
+        self.expanded.contains(path)
+    }
+
+    /// Expands the node with the given path. Returns false if it was already expanded.
+    pub fn expand(&mut self, path: Vec<T>) -> bool {
+        self.expanded.insert(path)
+    }
+
+    /// Collapses the node with the given path. Returns false if it was not expanded.
+    pub fn collapse(&mut self, path: &[T]) -> bool {
+        self.expanded.remove(path)
+    }
+
+    /// Expands the node with the given path if it is collapsed, or collapses it otherwise
+    pub fn toggle(&mut self, path: Vec<T>) {
+        if !self.collapse(&path) {
+            self.expand(path);
+        }
+    }
+
+    /// Expands the selected node. Returns false if nothing changed.
+    pub fn expand_selected(&mut self) -> bool {
+        self.selected
+            .clone()
+            .is_some_and(|selected| self.expand(selected))
+    }
+
+    /// Collapses the selected node. Returns false if nothing changed.
+    pub fn collapse_selected(&mut self) -> bool {
+        self.selected
+            .clone()
+            .is_some_and(|selected| self.collapse(&selected))
+    }
+
+    /// Toggles the selected node between expanded and collapsed
+    pub fn toggle_selected(&mut self) {
+        if let Some(selected) = self.selected.clone() {
+            self.toggle(selected);
+        }
+    }
+
+    /// Collapses all nodes
+    pub fn collapse_all(&mut self) {
+        self.expanded.clear();
+    }
+
+    /// Selects the next visible node, or the first one if no node is selected
+    ///
+    /// Selects the first node if the selected node is not visible. Returns false if nothing
+    /// changed.
+    pub fn select_next(&mut self, items: &[TreeItem<'_, T>]) -> bool {
+        self.select_visible(items, |index, len| {
+            index.map_or(0, |index| (index + 1).min(len - 1))
+        })
+    }
+
+    /// Selects the previous visible node, or the last one if no node is selected
+    ///
+    /// Selects the last node if the selected node is not visible. Returns false if nothing
+    /// changed.
+    pub fn select_previous(&mut self, items: &[TreeItem<'_, T>]) -> bool {
+        self.select_visible(items, |index, len| {
+            index.map_or(len - 1, |index| index.saturating_sub(1))
+        })
+    }
+
+    /// Selects the first node. Returns false if nothing changed.
+    pub fn select_first(&mut self, items: &[TreeItem<'_, T>]) -> bool {
+        self.select_visible(items, |_, _| 0)
+    }
+
+    /// Selects the last visible node. Returns false if nothing changed.
+    pub fn select_last(&mut self, items: &[TreeItem<'_, T>]) -> bool {
+        self.select_visible(items, |_, len| len - 1)
+    }
+
+    /// Selects the parent of the selected node. Returns false if nothing changed.
+    pub fn select_parent(&mut self) -> bool {
+        match &mut self.selected {
+            Some(selected) if selected.len() > 1 => {
+                selected.pop();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Expands the selected node and selects its first child
+    ///
+    /// Returns false if nothing is selected or the selected node has no children.
+    pub fn select_first_child(&mut self, items: &[TreeItem<'_, T>]) -> bool {
+        let Some(selected) = self.selected.clone() else {
+            return false;
+        };
+        let Some(child) = find(items, &selected).and_then(|item| item.children.first()) else {
+            return false;
+        };
+        let mut path = selected.clone();
+        path.push(child.identifier.clone());
+        self.expand(selected);
+        self.selected = Some(path);
+        true
+    }
+
+    /// Selects a visible node based on the index of the selected node among the visible nodes,
+    /// and the number of visible nodes.
+    fn select_visible<F>(&mut self, items: &[TreeItem<'_, T>], index: F) -> bool
+    where
+        F: FnOnce(Option<usize>, usize) -> usize,
+    {
+        let visible = flatten(items, &self.expanded);
+        if visible.is_empty() {
+            return false;
+        }
+        let selected = self
+            .selected
+            .as_ref()
+            .and_then(|selected| visible.iter().position(|node| &node.path == selected));
+        let new = index(selected, visible.len());
+        if selected == Some(new) {
+            return false;
+        }
+        self.selected = Some(visible[new].path.clone());
+        true
+    }
+}
+
+/// Returns the item with the given path.
+fn find<'i, 'a, T: PartialEq>(
+    items: &'i [TreeItem<'a, T>],
+    path: &[T],
+) -> Option<&'i TreeItem<'a, T>> {
+    let (first, rest) = path.split_first()?;
+    let item = items.iter().find(|item| &item.identifier == first)?;
+    if rest.is_empty() {
+        Some(item)
+    } else {
+        find(&item.children, rest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn items() -> Vec<TreeItem<'static, &'static str>> {
+        vec![
+            TreeItem::new("a", "a").children([
+                TreeItem::new("b", "b"),
+                TreeItem::new("c", "c").children([TreeItem::new("d", "d")]),
+            ]),
+            TreeItem::new("e", "e"),
+        ]
+    }
+
+    #[test]
+    fn default() {
+        let state = TreeState::<usize>::default();
+        assert_eq!(state.offset(), 0);
+        assert_eq!(state.selected(), None);
+        assert!(state.expanded().is_empty());
+    }
+
+    #[test]
+    fn with() {
+        let state = TreeState::default()
+            .with_offset(1)
+            .with_selected(Some(vec![1, 2]))
+            .with_expanded([vec![1]]);
+        assert_eq!(state.offset(), 1);
+        assert_eq!(state.selected(), Some(&[1, 2][..]));
+        assert!(state.is_expanded(&[1]));
+        assert!(!state.is_expanded(&[1, 2]));
+    }
+
+    #[test]
+    fn expand_collapse() {
+        let mut state = TreeState::default();
+        assert!(state.expand(vec!["a"]));
+        assert!(!state.expand(vec!["a"]));
+        assert!(state.collapse(&["a"]));
+        assert!(!state.collapse(&["a"]));
+        state.toggle(vec!["a"]);
+        assert!(state.is_expanded(&["a"]));
+        state.toggle(vec!["a"]);
+        assert!(!state.is_expanded(&["a"]));
+
+        assert!(!state.expand_selected());
+        state.select(Some(vec!["e"]));
+        assert!(state.expand_selected());
+        assert!(state.collapse_selected());
+        state.toggle_selected();
+        assert!(state.is_expanded(&["e"]));
+        state.collapse_all();
+        assert!(state.expanded().is_empty());
+    }
+
+    #[test]
+    fn select_next_and_previous() {
+        let items = items();
+        let mut state = TreeState::default().with_expanded([vec!["a"]]);
+        assert!(state.select_next(&items));
+        assert_eq!(state.selected(), Some(&["a"][..]));
+        state.select_next(&items);
+        assert_eq!(state.selected(), Some(&["a", "b"][..]));
+        state.select_next(&items);
+        state.select_next(&items);
+        assert_eq!(state.selected(), Some(&["e"][..]));
+        assert!(!state.select_next(&items));
+        state.select_previous(&items);
+        assert_eq!(state.selected(), Some(&["a", "c"][..]));
+
+        state.select(None);
+        state.select_previous(&items);
+        assert_eq!(state.selected(), Some(&["e"][..]));
+        state.select_first(&items);
+        assert_eq!(state.selected(), Some(&["a"][..]));
+        state.select_last(&items);
+        assert_eq!(state.selected(), Some(&["e"][..]));
+    }
+
+    #[test]
+    fn select_hidden_node() {
+        let items = items();
+        let mut state = TreeState::default().with_selected(Some(vec!["a", "c", "d"]));
+        state.select_next(&items);
+        assert_eq!(state.selected(), Some(&["a"][..]));
+    }
+
+    #[test]
+    fn select_parent_and_child() {
+        let items = items();
+        let mut state = TreeState::default().with_selected(Some(vec!["a"]));
+        assert!(state.select_first_child(&items));
+        assert_eq!(state.selected(), Some(&["a", "b"][..]));
+        assert!(state.is_expanded(&["a"]));
+        assert!(!state.select_first_child(&items));
+
+        state.select_next(&items);
+        assert!(state.select_first_child(&items));
+        assert_eq!(state.selected(), Some(&["a", "c", "d"][..]));
+        assert!(state.select_parent());
+        assert!(state.select_parent());
+        assert_eq!(state.selected(), Some(&["a"][..]));
+        assert!(!state.select_parent());
+    }
+
+    #[test]
+    fn select_empty() {
+        let mut state = TreeState::<usize>::default();
+        assert!(!state.select_next(&[]));
+        assert!(!state.select_last(&[]));
+        assert_eq!(state.selected(), None);
+    }
+}
@@ -13,27 +13,28 @@
 //! [examples]: https://github.com/ratatui/ratatui/blob/main/examples
 //! [examples readme]: https://github.com/ratatui/ratatui/blob/main/examples/README.md
 
-// A simple example demonstrating how to handle user input. This is a bit out of the scope of
-// the library as it does not provide any input handling out of the box. However, it may helps
-// some to get started.
+// A simple example demonstrating how to handle user input with the `Input` widget. Input handling
+// is a bit out of the scope of the library as it does not provide any input handling out of the
+// box, but `InputState` provides the editing operations that key events map to.
 //
 // This is a very simple example:
 //   * An input box always focused. Every character you type is registered here.
 //   * An entered character is inserted at the cursor position.
-//   * Pressing Backspace erases the left character before the cursor position
-//   * Pressing Enter pushes the current input in the history of previous messages. **Note: ** as
-//   this is a relatively simple example unicode characters are unsupported and their use will
-// result in undefined behaviour.
+//   * Pressing Backspace erases the character before the cursor position
+//   * Previous messages that start with the input are suggested as a completion. Pressing Tab
+//   accepts the completion.
+//   * Pressing Enter pushes the current input in the history of previous messages. Empty messages
+//   are highlighted as invalid and are not recorded.
 //
 // See also https://github.com/rhysd/tui-textarea and https://github.com/sayanarijit/tui-input/
 
 use color_eyre::Result;
 use ratatui::{
     crossterm::event::{self, Event, KeyCode, KeyEventKind},
-    layout::{Constraint, Layout, Position},
+    layout::{Constraint, Layout},
     style::{Color, Modifier, Style, Stylize},
     text::{Line, Span, Text},
-    widgets::{Block, List, ListItem, Paragraph},
+    widgets::{Block, CursorMove, Input, InputState, List, ListItem, Paragraph},
     DefaultTerminal, Frame,
 };
 
@@ -47,10 +48,8 @@ fn main() -> Result<()> {
 
 /// App holds the state of the application
 struct App {
-    /// Current value of the input box
-    input: String,
-    /// Position of cursor in the editor area.
-    character_index: usize,
+    /// Current value, cursor and completion of the input box
+    input: InputState,
     /// Current input mode
     input_mode: InputMode,
     /// History of recorded messages
@@ -63,77 +62,44 @@ enum InputMode {
 }
 
 impl App {
-    const fn new() -> Self {
+    fn new() -> Self {
         Self {
-            input: String::new(),
+            input: InputState::default(),
             input_mode: InputMode::Normal,
             messages: Vec::new(),
-            character_index: 0,
         }
     }
 
-    fn move_cursor_left(&mut self) {
-        let cursor_moved_left = self.character_index.saturating_sub(1);
-        self.character_index = self.clamp_cursor(cursor_moved_left);
-    }
-
-    fn move_cursor_right(&mut self) {
-        let cursor_moved_right = self.character_index.saturating_add(1);
-        self.character_index = self.clamp_cursor(cursor_moved_right);
-    }
-
-    fn enter_char(&mut self, new_char: char) {
-        let index = self.byte_index();
-        self.input.insert(index, new_char);
-        self.move_cursor_right();
-    }
-
-    /// Returns the byte index based on the character position.
-    ///
-    /// Since each character in a string can be contain multiple bytes, it's necessary to calculate
-    /// the byte index based on the index of the character.
-    fn byte_index(&self) -> usize {
-        self.input
-            .char_indices()
-            .map(|(i, _)| i)
-            .nth(self.character_index)
-            .unwrap_or(self.input.len())
-    }
-
-    fn delete_char(&mut self) {
-        let is_not_cursor_leftmost = self.character_index != 0;
-        if is_not_cursor_leftmost {
-            // Method "remove" is not used on the saved text for deleting the selected char.
-            // Reason: Using remove on String works on bytes instead of the chars.
-            // Using remove would require special care because of char boundaries.
-
-            let current_index = self.character_index;
-            let from_left_to_current_index = current_index - 1;
-
-            // Getting all characters before the selected character.
-            let before_char_to_delete = self.input.chars().take(from_left_to_current_index);
-            // Getting all characters after selected character.
-            let after_char_to_delete = self.input.chars().skip(current_index);
-
-            // Put all characters together except the selected one.
-            // By leaving the selected one out, it is forgotten and therefore deleted.
-            self.input = before_char_to_delete.chain(after_char_to_delete).collect();
-            self.move_cursor_left();
-        }
-    }
-
-    fn clamp_cursor(&self, new_cursor_pos: usize) -> usize {
-        new_cursor_pos.clamp(0, self.input.chars().count())
+    fn input() -> Input<'static> {
+        Input::new()
+            .placeholder("Type a message")
+            .validator(|value| {
+                if value.trim().is_empty() {
+                    Err("the message is empty".to_string())
+                } else {
+                    Ok(())
+                }
+            })
+            .block(Block::bordered().title("Input"))
     }
 
-    fn reset_cursor(&mut self) {
-        self.character_index = 0;
+    /// Suggests the most recent message that starts with the input as a completion.
+    fn update_completion(&mut self) {
+        let value = self.input.value();
+        let completion = self
+            .messages
+            .iter()
+            .rev()
+            .filter(|message| !value.is_empty() && message.len() > value.len())
+            .find_map(|message| message.strip_prefix(value))
+            .map(str::to_string);
+        self.input.set_completion(completion);
     }
 
     fn submit_message(&mut self) {
-        self.messages.push(self.input.clone());
-        self.input.clear();
-        self.reset_cursor();
+        if Self::input().validate(&self.input).is_ok() {
+            self.messages.push(self.input.take_value());
+        }
     }
 
     fn run(mut self, mut terminal: DefaultTerminal) -> Result<()> {
@@ -151,22 +117,29 @@ impl App {
                         }
                         _ => {}
                     },
-                    InputMode::Editing if key.kind == KeyEventKind::Press => match key.code {
-                        KeyCode::Enter => self.submit_message(),
-                        KeyCode::Char(to_insert) => self.enter_char(to_insert),
-                        KeyCode::Backspace => self.delete_char(),
-                        KeyCode::Left => self.move_cursor_left(),
-                        KeyCode::Right => self.move_cursor_right(),
-                        KeyCode::Esc => self.input_mode = InputMode::Normal,
-                        _ => {}
-                    },
+                    InputMode::Editing if key.kind == KeyEventKind::Press => {
+                        match key.code {
+                            KeyCode::Enter => self.submit_message(),
+                            KeyCode::Char(to_insert) => self.input.insert_char(to_insert),
+                            KeyCode::Backspace => _ = self.input.delete_backward(),
+                            KeyCode::Delete => _ = self.input.delete_forward(),
+                            KeyCode::Left => self.input.move_cursor(CursorMove::Left),
+                            KeyCode::Right => self.input.move_cursor(CursorMove::Right),
+                            KeyCode::Home => self.input.move_cursor(CursorMove::LineStart),
+                            KeyCode::End => self.input.move_cursor(CursorMove::LineEnd),
+                            KeyCode::Tab => _ = self.input.accept_completion(),
+                            KeyCode::Esc => self.input_mode = InputMode::Normal,
+                            _ => {}
+                        }
+                        self.update_completion();
+                    }
                     InputMode::Editing => {}
                 }
             }
         }
     }
 
-    fn draw(&self, frame: &mut Frame) {
+    fn draw(&mut self, frame: &mut Frame) {
         let vertical = Layout::vertical([
             Constraint::Length(1),
             Constraint::Length(3),
@@ -190,6 +163,8 @@ impl App {
                     "Press ".into(),
                     "Esc".bold(),
                     " to stop editing, ".into(),
+                    "Tab".bold(),
+                    " to complete, ".into(),
                     "Enter".bold(),
                     " to record the message".into(),
                 ],
@@ -200,27 +175,22 @@ impl App {
         let help_message = Paragraph::new(text);
         frame.render_widget(help_message, help_area);
 
-        let input = Paragraph::new(self.input.as_str())
-            .style(match self.input_mode {
-                InputMode::Normal => Style::default(),
-                InputMode::Editing => Style::default().fg(Color::Yellow),
-            })
-            .block(Block::bordered().title("Input"));
-        frame.render_widget(input, input_area);
+        let input = Self::input().style(match self.input_mode {
+            InputMode::Normal => Style::default(),
+            InputMode::Editing => Style::default().fg(Color::Yellow),
+        });
+        frame.render_stateful_widget(input, input_area, &mut self.input);
         match self.input_mode {
             // Hide the cursor. `Frame` does this by default, so we don't need to do anything here
             InputMode::Normal => {}
 
-            // Make the cursor visible and ask ratatui to put it at the specified coordinates after
-            // rendering
-            #[allow(clippy::cast_possible_truncation)]
-            InputMode::Editing => frame.set_cursor_position(Position::new(
-                // Draw the cursor at the current position in the input field.
-                // This position is can be controlled via the left and right arrow key
-                input_area.x + self.character_index as u16 + 1,
-                // Move one line down, from the border to the input line
-                input_area.y + 1,
-            )),
+            // Make the cursor visible and ask ratatui to put it at the position computed by the
+            // input widget after rendering
+            InputMode::Editing => {
+                if let Some(position) = self.input.cursor_position() {
+                    frame.set_cursor_position(position);
+                }
+            }
         }
 
         let messages: Vec<ListItem> = self
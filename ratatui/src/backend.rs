@@ -302,6 +302,40 @@ pub trait Backend {
         }
     }
 
+    /// Copy the given text to the system clipboard.
+    ///
+    /// Backends that write to a terminal implement this with the OSC 52 escape sequence, which asks
+    /// the terminal emulator to set the clipboard. This also works when the application runs on a
+    /// remote machine over SSH, but not all terminal emulators support it, and some require it to
+    /// be enabled in their settings. Terminals silently ignore the sequence if they don't support
+    /// it.
+    ///
+    /// This method is optional and may not be implemented by all backends. The default
+    /// implementation returns an error.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::backend::{TestBackend};
+    /// # let mut backend = TestBackend::new(80, 25);
+    /// use ratatui::backend::Backend;
+    ///
+    /// backend.set_clipboard("Hello, world!")?;
+    /// assert_eq!(backend.clipboard(), Some("Hello, world!"));
+    /// # std::io::Result::Ok(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This method will return an error if the backend does not support setting the clipboard, or
+    /// if the escape sequence could not be written.
+    fn set_clipboard(&mut self, _text: &str) -> io::Result<()> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "setting the clipboard is not supported with this backend",
+        ))
+    }
+
     /// Get the size of the terminal screen in columns/rows as a [`Size`].
     ///
     /// The returned [`Size`] contains the width and height of the terminal screen.
@@ -387,6 +421,35 @@ pub trait Backend {
     ) -> io::Result<()>;
 }
 
+/// Returns the OSC 52 escape sequence that sets the clipboard to `text`.
+///
+/// The text is base64 encoded, as required by the sequence. The `c` parameter selects the
+/// clipboard (as opposed to the primary selection).
+#[cfg(any(feature = "crossterm", all(not(windows), feature = "termion")))]
+fn osc52(text: &str) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut sequence = String::from("\x1b]52;c;");
+    for chunk in text.as_bytes().chunks(3) {
+        let bytes = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or_default(),
+            chunk.get(2).copied().unwrap_or_default(),
+        ];
+        let group = u32::from_be_bytes([0, bytes[0], bytes[1], bytes[2]]);
+        for i in 0..4 {
+            if i <= chunk.len() {
+                let index = (group >> (18 - 6 * i)) & 0x3f;
+                sequence.push(ALPHABET[index as usize] as char);
+            } else {
+                sequence.push('=');
+            }
+        }
+    }
+    sequence.push('\x07');
+    sequence
+}
+
 #[cfg(test)]
 mod tests {
     use strum::ParseError;
@@ -423,4 +486,15 @@ mod tests {
         );
         assert_eq!("".parse::<ClearType>(), Err(ParseError::VariantNotFound));
     }
+
+    #[cfg(any(feature = "crossterm", all(not(windows), feature = "termion")))]
+    #[test]
+    fn osc52() {
+        assert_eq!(super::osc52(""), "\x1b]52;c;\x07");
+        assert_eq!(super::osc52("f"), "\x1b]52;c;Zg==\x07");
+        assert_eq!(super::osc52("fo"), "\x1b]52;c;Zm8=\x07");
+        assert_eq!(super::osc52("foo"), "\x1b]52;c;Zm9v\x07");
+        assert_eq!(super::osc52("foobar"), "\x1b]52;c;Zm9vYmFy\x07");
+        assert_eq!(super::osc52("日本"), "\x1b]52;c;5pel5pys\x07");
+    }
 }
@@ -254,6 +254,10 @@ where
         self.writer.flush()
     }
 
+    fn set_clipboard(&mut self, text: &str) -> io::Result<()> {
+        execute!(self.writer, Print(super::osc52(text)))
+    }
+
     fn size(&self) -> io::Result<Size> {
         let (width, height) = terminal::size()?;
         Ok(Size { width, height })
@@ -224,6 +224,11 @@ where
         )
     }
 
+    fn set_clipboard(&mut self, text: &str) -> io::Result<()> {
+        write!(self.writer, "{}", super::osc52(text))?;
+        self.writer.flush()
+    }
+
     fn size(&self) -> io::Result<Size> {
         let terminal = termion::terminal_size()?;
         Ok(Size::new(terminal.0, terminal.1))
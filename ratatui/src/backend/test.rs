@@ -38,6 +38,7 @@ pub struct TestBackend {
     scrollback: Buffer,
     cursor: bool,
     pos: (u16, u16),
+    clipboard: Option<String>,
 }
 
 /// Returns a string representation of the given buffer for debugging purpose.
@@ -77,6 +78,7 @@ impl TestBackend {
             scrollback: Buffer::empty(Rect::new(0, 0, width, 0)),
             cursor: false,
             pos: (0, 0),
+            clipboard: None,
         }
     }
 
@@ -99,6 +101,7 @@ impl TestBackend {
             scrollback,
             cursor: false,
             pos: (0, 0),
+            clipboard: None,
         }
     }
 
@@ -124,6 +127,13 @@ impl TestBackend {
         &self.scrollback
     }
 
+    /// Returns the text that was last copied to the clipboard with [`Backend::set_clipboard`].
+    ///
+    /// Returns `None` if nothing has been copied yet.
+    pub fn clipboard(&self) -> Option<&str> {
+        self.clipboard.as_deref()
+    }
+
     /// Resizes the `TestBackend` to the specified width and height.
     pub fn resize(&mut self, width: u16, height: u16) {
         self.buffer.resize(Rect::new(0, 0, width, height));
@@ -346,6 +356,11 @@ impl Backend for TestBackend {
         Ok(())
     }
 
+    fn set_clipboard(&mut self, text: &str) -> io::Result<()> {
+        self.clipboard = Some(text.to_string());
+        Ok(())
+    }
+
     fn size(&self) -> io::Result<Size> {
         Ok(self.buffer.area.as_size())
     }
@@ -468,6 +483,7 @@ mod tests {
                 scrollback: Buffer::empty(Rect::new(0, 0, 10, 0)),
                 cursor: false,
                 pos: (0, 0),
+                clipboard: None,
             }
         );
     }
@@ -583,6 +599,14 @@ mod tests {
         backend.assert_buffer_lines(["    ", "    "]);
     }
 
+    #[test]
+    fn set_clipboard() {
+        let mut backend = TestBackend::new(4, 2);
+        assert_eq!(backend.clipboard(), None);
+        backend.set_clipboard("copied").unwrap();
+        assert_eq!(backend.clipboard(), Some("copied"));
+    }
+
     #[test]
     fn clear_region_all() {
         let mut backend = TestBackend::with_lines([
@@ -13,18 +13,29 @@
 //! The available widgets are:
 //! - [`Block`]: a basic widget that draws a block with optional borders, titles and styles.
 //! - [`BarChart`]: displays multiple datasets as bars with optional grouping.
+//! - [`BoxPlot`]: displays the quartiles and outliers of distributions of samples.
 //! - [`calendar::Monthly`]: displays a single month.
 //! - [`Canvas`]: draws arbitrary shapes using drawing characters.
 //! - [`Chart`]: displays multiple datasets as a lines or scatter graph.
 //! - [`Clear`]: clears the area it occupies. Useful to render over previously drawn widgets.
 //! - [`Gauge`]: displays progress percentage using block characters.
+//! - [`Heatmap`]: displays a matrix of values as colored cells.
+//! - [`Histogram`]: displays the distribution of samples as bars.
+//! - [`Input`]: edits a single line of text.
 //! - [`LineGauge`]: display progress as a line.
 //! - [`List`]: displays a list of items and allows selection.
+//! - [`Menu`]: displays a popup menu of actions with nested submenus.
+//! - [`MenuBar`]: displays a bar of menus opening dropdowns.
 //! - [`Paragraph`]: displays a paragraph of optionally styled and wrapped text.
+//! - [`PieChart`]: displays the shares of a total as a pie or donut chart.
+//! - [`Popup`]: displays content in a popup positioned over other widgets.
+//! - [`Dialog`]: displays a modal popup with a row of buttons.
 //! - [`Scrollbar`]: displays a scrollbar.
 //! - [`Sparkline`]: display a single data set as a sparkline.
 //! - [`Table`]: displays multiple rows and columns in a grid and allows selection.
 //! - [`Tabs`]: displays a tab bar and allows selection.
+//! - [`TextArea`]: edits multi-line text.
+//! - [`Tree`]: displays hierarchical data with expandable nodes and allows selection.
 //!
 //! [`Canvas`]: crate::widgets::canvas::Canvas
 
@@ -34,20 +45,37 @@ pub use ratatui_widgets::block;
 #[cfg(feature = "widget-calendar")]
 pub use ratatui_widgets::calendar;
 pub use ratatui_widgets::{
-    barchart::{Bar, BarChart, BarGroup},
+    barchart::{Bar, BarChart, BarGroup, StackMode},
     block::{Block, Padding},
     borders::{BorderType, Borders},
+    boxplot::{BoxPlot, Distribution},
     canvas,
-    chart::{Axis, Chart, Dataset, GraphType, LegendPosition},
+    chart::{
+        Axis, AxisScale, Chart, ChartState, Dataset, GraphType, LabelFormat, LegendPosition,
+        StepPosition,
+    },
     clear::Clear,
     gauge::{Gauge, LineGauge},
-    list::{List, ListDirection, ListItem, ListState},
+    heatmap::{ColorScale, Heatmap},
+    histogram::{Bins, Histogram},
+    input::{Input, InputState},
+    list::{List, ListDirection, ListItem, ListSource, ListState, VirtualList},
     logo::{RatatuiLogo, Size as RatatuiLogoSize},
-    paragraph::{Paragraph, Wrap},
+    menu::{Menu, MenuBar, MenuItem, MenuState},
+    paragraph::{
+        Paragraph, ParagraphLayout, RenderedLine, Selection, TextPosition, Wrap, WrapMode,
+    },
+    piechart::{PieChart, Slice},
+    popup::{Anchor, Dialog, DialogState, Popup},
     scrollbar::{ScrollDirection, Scrollbar, ScrollbarOrientation, ScrollbarState},
-    sparkline::{RenderDirection, Sparkline, SparklineBar},
-    table::{Cell, HighlightSpacing, Row, Table, TableState},
+    sparkline::{RenderDirection, Sparkline, SparklineBar, SparklineMode},
+    table::{
+        AutoWidth, Cell, Comparator, HighlightSpacing, Marks, Row, RowFilter, SortOrder, Table,
+        TableSource, TableState, VirtualTable,
+    },
     tabs::Tabs,
+    text_area::{CursorMove, TextArea, TextAreaState},
+    tree::{NodeSymbols, Tree, TreeItem, TreeState},
 };
 #[instability::unstable(feature = "widget-ref")]
 pub use {stateful_widget_ref::StatefulWidgetRef, widget_ref::WidgetRef};